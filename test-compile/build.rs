@@ -2,17 +2,23 @@ use prettyplease::unparse;
 use std::env::current_dir;
 use std::{env, fs, path};
 
-use tinkerforge_generator::generator::process_directory;
+use tinkerforge_generator::generator::{process_directory_with_options, GeneratorOptions};
 
 fn main() {
     parse_json();
 }
 
 fn parse_json() {
-    let file = process_directory(
+    let file = process_directory_with_options(
         current_dir()
             .expect("Cannot access current directory")
             .join("bindings"),
+        &GeneratorOptions {
+            simulators: true,
+            decode_tables: true,
+            unit_types: true,
+            ..Default::default()
+        },
     );
 
     let out_dir = env::var_os("OUT_DIR").unwrap();
@@ -2,16 +2,10 @@ use anyhow::Result;
 
 use tinkerforge_base::ip_connection::async_io::AsyncIpConnection;
 
-use crate::bindings::lcd_128_x_64::{
-    Lcd128X64Bricklet,
-    SetDisplayConfigurationRequest,
-    TouchLedConfig,
-    WritePixelsRequest,
+use test_compile::bindings::lcd_128_x_64::{
+    Lcd128X64Bricklet, SetDisplayConfigurationRequest, TouchLedConfig, WritePixelsRequest,
 };
-use crate::bindings::master::MasterBrick;
-
-mod bindings;
-
+use test_compile::bindings::master::MasterBrick;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -45,3 +39,462 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio_stream::StreamExt;
+
+    use tinkerforge_base::{
+        byte_converter::ToBytes,
+        decoder::PacketDecoder,
+        error::TinkerforgeError,
+        ip_connection::{
+            async_io::{AsyncIpConnection, PacketData},
+            capture::Direction,
+            ErrorCode, PacketHeader, Version,
+        },
+        mock::{MockBrickd, MockDevice},
+        units::Quantity,
+    };
+
+    use test_compile::bindings::{
+        lcd_128_x_64::{
+            GetDisplayConfigurationResponse, Lcd128X64Bricklet, Lcd128X64BrickletSimulator,
+            ReadPixelsLowLevelRequest, ReadPixelsLowLevelResponse, SetDisplayConfigurationRequest,
+        },
+        master::{MasterBrick, MasterBrickSimulator},
+        stream_test::{
+            ShortWriteExtraOutPrefix1LowLevelRequest, ShortWriteExtraOutPrefix1LowLevelResponse,
+            ShortWriteExtraOutPrefix1Request, ShortWriteLowLevelRequest, ShortWriteRequest,
+            StreamTestBricklet, StreamTestBrickletSimulator,
+        },
+        units::{Second, Volt},
+        AnyDevice, DeviceIdentifier, DEVICE_DESCRIPTIONS,
+    };
+
+    /// Starts a mock Brick Daemon with a single device answered by `simulator` and connects to it.
+    async fn simulated(
+        uid: &str,
+        device_id: u16,
+        mut simulator: impl FnMut(u8, &[u8]) -> Result<Vec<u8>, ErrorCode> + Send + 'static,
+    ) -> (MockBrickd, AsyncIpConnection) {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd
+            .add_device(MockDevice::new(uid, device_id).respond_to_all(
+                move |function_id, payload| simulator(function_id, payload).into(),
+            ));
+        let connection = brickd.connect().await.unwrap();
+        (brickd, connection)
+    }
+
+    #[derive(Default)]
+    struct SimulatedLcd {
+        configuration: Option<SetDisplayConfigurationRequest>,
+        cleared: bool,
+    }
+
+    impl Lcd128X64BrickletSimulator for SimulatedLcd {
+        fn clear_display(&mut self) -> Result<(), ErrorCode> {
+            self.cleared = true;
+            Ok(())
+        }
+        fn set_display_configuration(
+            &mut self,
+            request: SetDisplayConfigurationRequest,
+        ) -> Result<(), ErrorCode> {
+            self.configuration = Some(request);
+            Ok(())
+        }
+        fn get_display_configuration(
+            &mut self,
+        ) -> Result<GetDisplayConfigurationResponse, ErrorCode> {
+            let configuration = self.configuration.ok_or(ErrorCode::InvalidParameter)?;
+            Ok(GetDisplayConfigurationResponse {
+                contrast: configuration.contrast,
+                backlight: configuration.backlight,
+                invert: configuration.invert,
+                automatic_draw: configuration.automatic_draw,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_simulated_lcd() {
+        let mut simulator = SimulatedLcd::default();
+        let (_brickd, connection) = simulated(
+            "R4c",
+            SimulatedLcd::DEVICE_IDENTIFIER,
+            move |function_id, payload| simulator.handle_request(function_id, payload),
+        )
+        .await;
+        let mut bricklet = Lcd128X64Bricklet::new("R4c", connection);
+        bricklet.clear_display().await.unwrap();
+        assert!(bricklet.get_display_configuration().await.is_err());
+        bricklet
+            .set_display_configuration(SetDisplayConfigurationRequest {
+                contrast: 14,
+                backlight: 100,
+                invert: false,
+                automatic_draw: true,
+            })
+            .await
+            .unwrap();
+        let configuration = bricklet.get_display_configuration().await.unwrap();
+        assert_eq!(configuration.contrast, 14);
+        assert_eq!(configuration.backlight, 100);
+        assert!(configuration.automatic_draw);
+        assert!(bricklet.get_touch_led_config().await.is_err());
+    }
+
+    #[test]
+    fn test_decode_generated_packets() {
+        let mut decoder = PacketDecoder::new(DEVICE_DESCRIPTIONS);
+        decoder.add_device("R4c", SimulatedLcd::DEVICE_IDENTIFIER);
+        let mut payload = [0; 4];
+        SetDisplayConfigurationRequest {
+            contrast: 14,
+            backlight: 100,
+            invert: false,
+            automatic_draw: true,
+        }
+        .write_to_slice(&mut payload);
+        let header = PacketHeader::with_payload("R4c".into(), 4, 1, true, payload.len() as u8);
+        let request = PacketData::new(header, payload);
+        assert_eq!(
+            decoder.decode(&request, Direction::Sent).to_string(),
+            "Lcd128X64Bricklet[R4c].set_display_configuration{contrast:14,backlight:100,invert:false,automatic_draw:true}"
+        );
+        let header = PacketHeader::with_payload("R4c".into(), 15, 0, true, 19);
+        let body = [3, 0, 1, 0, 0, 20, 0, 0, 0, 0, 0, 127, 0, 63, 0, 7, 0, 0, 0];
+        let gesture = PacketData::new(header, body);
+        assert_eq!(
+            decoder.decode(&gesture, Direction::Received).to_string(),
+            "Lcd128X64Bricklet[R4c].touch_gesture{gesture:BottomToTop,duration:256,pressure_max:20,\
+             x_start:0,y_start:0,x_end:127,y_end:63,age:7}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_any_device_from_enumeration() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("6DyH5n", 13));
+        brickd.add_device(MockDevice::new("R4c", 298).connected_to("6DyH5n", 'a'));
+        brickd.add_device(MockDevice::new("XYZ", 9999).connected_to("6DyH5n", 'b'));
+        let connection = brickd.connect().await.unwrap();
+        let devices: Vec<_> = connection
+            .enumerate_all(Duration::from_millis(100))
+            .await
+            .unwrap()
+            .iter()
+            .filter_map(|response| AnyDevice::new(response, connection.clone()))
+            .collect();
+        assert_eq!(devices.len(), 2);
+        let Some(AnyDevice::Lcd128X64Bricklet(lcd)) = devices
+            .iter()
+            .find(|device| device.device_identifier() == DeviceIdentifier::Lcd128X64Bricklet)
+        else {
+            panic!("LCD not found");
+        };
+        assert_eq!(lcd.uid(), "R4c".into());
+        assert_eq!(lcd.firmware_version(), Some(Version::new(2, 0, 0)));
+    }
+
+    #[tokio::test]
+    async fn test_firmware_check() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("R4c", 298).firmware_version(Version::new(2, 0, 1)));
+        let mut bricklet = Lcd128X64Bricklet::new("R4c", brickd.connect().await.unwrap());
+        assert_eq!(
+            bricklet.load_firmware_version().await.unwrap(),
+            Version::new(2, 0, 1)
+        );
+        let Err(TinkerforgeError::UnsupportedByFirmware {
+            function_id,
+            required,
+            actual,
+        }) = bricklet.get_touch_led_config().await
+        else {
+            panic!("Expected firmware check to fail");
+        };
+        assert_eq!(
+            (function_id, required, actual),
+            (52, Version::new(2, 0, 2), Version::new(2, 0, 1))
+        );
+        assert!(brickd
+            .received()
+            .iter()
+            .all(|packet| packet.header().function_id() != 52));
+
+        bricklet.set_firmware_version(None);
+        assert!(!matches!(
+            bricklet.get_touch_led_config().await,
+            Err(TinkerforgeError::UnsupportedByFirmware { .. })
+        ));
+    }
+
+    #[derive(Default)]
+    struct SimulatedStack {
+        voltage_callback_period: Quantity<u32, Second, 1, 1000>,
+    }
+
+    impl MasterBrickSimulator for SimulatedStack {
+        fn get_stack_voltage(&mut self) -> Result<Quantity<u16, Volt, 1, 1000>, ErrorCode> {
+            Ok(Quantity::from_value(5.2))
+        }
+        fn set_stack_voltage_callback_period(
+            &mut self,
+            request: Quantity<u32, Second, 1, 1000>,
+        ) -> Result<(), ErrorCode> {
+            self.voltage_callback_period = request;
+            Ok(())
+        }
+        fn get_stack_voltage_callback_period(
+            &mut self,
+        ) -> Result<Quantity<u32, Second, 1, 1000>, ErrorCode> {
+            Ok(self.voltage_callback_period)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unit_types() {
+        let mut simulator = SimulatedStack::default();
+        let (_brickd, connection) = simulated(
+            "6DyH5n",
+            SimulatedStack::DEVICE_IDENTIFIER,
+            move |function_id, payload| simulator.handle_request(function_id, payload),
+        )
+        .await;
+        let mut master = MasterBrick::new("6DyH5n", connection);
+        let voltage = master.get_stack_voltage().await.unwrap();
+        assert_eq!(voltage.raw(), 5200);
+        assert_eq!(voltage.to_string(), "5.2 V");
+
+        master
+            .set_stack_voltage_callback_period(Quantity::from_value(0.25))
+            .await
+            .unwrap();
+        let period = master.get_stack_voltage_callback_period().await.unwrap();
+        assert_eq!(period.raw(), 250);
+        assert_eq!(period.value(), 0.25);
+    }
+
+    /// Serves a framebuffer with every third pixel set, `skip_chunk` makes it lose a chunk once.
+    #[derive(Default)]
+    struct SimulatedFramebuffer {
+        chunk_offset: u16,
+        skip_chunk: bool,
+    }
+
+    impl Lcd128X64BrickletSimulator for SimulatedFramebuffer {
+        fn read_pixels_low_level(
+            &mut self,
+            request: ReadPixelsLowLevelRequest,
+        ) -> Result<ReadPixelsLowLevelResponse, ErrorCode> {
+            let pixels_length = (request.x_end - request.x_start + 1) as u16
+                * (request.y_end - request.y_start + 1) as u16;
+            if self.skip_chunk && self.chunk_offset == 480 {
+                self.skip_chunk = false;
+                self.chunk_offset += 480;
+            }
+            let pixels_chunk_offset = self.chunk_offset;
+            let mut pixels_chunk_data = [false; 480];
+            for (index, pixel) in pixels_chunk_data.iter_mut().enumerate() {
+                *pixel = (pixels_chunk_offset as usize + index) % 3 == 1;
+            }
+            self.chunk_offset += 480;
+            if self.chunk_offset >= pixels_length {
+                self.chunk_offset = 0;
+            }
+            Ok(ReadPixelsLowLevelResponse {
+                pixels_length,
+                pixels_chunk_offset,
+                pixels_chunk_data,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_pixels() {
+        let mut simulator = SimulatedFramebuffer {
+            skip_chunk: true,
+            ..Default::default()
+        };
+        let (_brickd, connection) = simulated(
+            "R4c",
+            SimulatedLcd::DEVICE_IDENTIFIER,
+            move |function_id, payload| simulator.handle_request(function_id, payload),
+        )
+        .await;
+        let mut bricklet = Lcd128X64Bricklet::new("R4c", connection);
+        let request = ReadPixelsLowLevelRequest {
+            x_start: 0,
+            y_start: 0,
+            x_end: 127,
+            y_end: 15,
+        };
+        assert!(matches!(
+            bricklet.read_pixels(request).await,
+            Err(TinkerforgeError::StreamOutOfSync)
+        ));
+        let pixels = bricklet.read_pixels(request).await.unwrap();
+        assert_eq!(pixels.len(), 128 * 16);
+        assert!(pixels
+            .iter()
+            .enumerate()
+            .all(|(index, pixel)| *pixel == (index % 3 == 1)));
+    }
+
+    fn message_chunk(length: u16, offset: u16, message: &str) -> Vec<u8> {
+        let mut payload = [0; 64];
+        payload[0..2].copy_from_slice(&length.to_le_bytes());
+        payload[2..4].copy_from_slice(&offset.to_le_bytes());
+        let chunk = &message.as_bytes()[offset as usize..];
+        let chunk = &chunk[..chunk.len().min(60)];
+        payload[4..4 + chunk.len()].copy_from_slice(chunk);
+        payload.to_vec()
+    }
+
+    #[tokio::test]
+    async fn test_reassemble_callback_stream() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("Str", 21111));
+        let mut bricklet = StreamTestBricklet::new("Str", brickd.connect().await.unwrap());
+        let messages = bricklet.normal_read_stream().await;
+        tokio::pin!(messages);
+        let lost = "x".repeat(100);
+        let message = "0123456789".repeat(7);
+        brickd.emit_callback("Str", 70, &message_chunk(100, 60, &lost));
+        brickd.emit_callback("Str", 70, &message_chunk(100, 0, &lost));
+        brickd.emit_callback("Str", 70, &message_chunk(70, 0, &message));
+        brickd.emit_callback("Str", 70, &message_chunk(70, 60, &message));
+        let received: String = messages.next().await.unwrap().into_iter().collect();
+        assert_eq!(received, message);
+    }
+
+    /// Accepts at most `capacity` characters, like a device with a full send buffer.
+    struct SimulatedSendBuffer {
+        received: Vec<char>,
+        capacity: usize,
+    }
+
+    impl StreamTestBrickletSimulator for SimulatedSendBuffer {
+        fn short_write_low_level(
+            &mut self,
+            request: ShortWriteLowLevelRequest,
+        ) -> Result<u8, ErrorCode> {
+            let chunk_length = (request.message_length - request.message_chunk_offset).min(60);
+            let accepted = (chunk_length as usize).min(self.capacity - self.received.len());
+            self.received
+                .extend_from_slice(&request.message_chunk_data[..accepted]);
+            Ok(accepted as u8)
+        }
+
+        fn short_write_extra_out_prefix_1_low_level(
+            &mut self,
+            request: ShortWriteExtraOutPrefix1LowLevelRequest,
+        ) -> Result<ShortWriteExtraOutPrefix1LowLevelResponse, ErrorCode> {
+            let message_chunk_written = self.short_write_low_level(ShortWriteLowLevelRequest {
+                message_length: request.message_length,
+                message_chunk_offset: request.message_chunk_offset,
+                message_chunk_data: request.message_chunk_data,
+            })?;
+            Ok(ShortWriteExtraOutPrefix1LowLevelResponse {
+                extra: self.received.len() as u8,
+                message_chunk_written,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_short_write() {
+        let mut simulator = SimulatedSendBuffer {
+            received: Vec::new(),
+            capacity: 100,
+        };
+        let (brickd, connection) = simulated("Str", 21111, move |function_id, payload| {
+            simulator.handle_request(function_id, payload)
+        })
+        .await;
+        let mut bricklet = StreamTestBricklet::new("Str", connection);
+        let message: Vec<char> = "0123456789".repeat(15).chars().collect();
+        let written = bricklet
+            .short_write(ShortWriteRequest { data: &message })
+            .await
+            .unwrap();
+        assert_eq!(written, 100);
+        // the write stops after the chunk which was not accepted completely
+        let chunks = brickd
+            .received()
+            .iter()
+            .filter(|packet| packet.header().function_id() == 17)
+            .count();
+        assert_eq!(chunks, 2);
+    }
+
+    #[tokio::test]
+    async fn test_short_write_extra_out() {
+        let mut simulator = SimulatedSendBuffer {
+            received: Vec::new(),
+            capacity: 100,
+        };
+        let (_brickd, connection) = simulated("Str", 21111, move |function_id, payload| {
+            simulator.handle_request(function_id, payload)
+        })
+        .await;
+        let mut bricklet = StreamTestBricklet::new("Str", connection);
+        let message: Vec<char> = "0123456789".repeat(15).chars().collect();
+        let response = bricklet
+            .short_write_extra_out_prefix_1(ShortWriteExtraOutPrefix1Request { data: &message })
+            .await
+            .unwrap();
+        assert_eq!(response.message_written, 100);
+        // the extra field is taken from the response to the last chunk
+        assert_eq!(response.extra, 100);
+    }
+
+    #[tokio::test]
+    async fn test_short_write_extra_out_empty_stream() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("Str", 21111));
+        let mut bricklet = StreamTestBricklet::new("Str", brickd.connect().await.unwrap());
+        let result = bricklet
+            .short_write_extra_out_prefix_1(ShortWriteExtraOutPrefix1Request { data: &[] })
+            .await;
+        assert!(matches!(result, Err(TinkerforgeError::EmptyStream)));
+        assert!(!brickd
+            .received()
+            .iter()
+            .any(|packet| packet.header().function_id() == 22));
+    }
+
+    #[tokio::test]
+    async fn test_write_pads_last_chunk() {
+        let mut simulator = SimulatedSendBuffer {
+            received: Vec::new(),
+            capacity: 100,
+        };
+        let (brickd, connection) = simulated("Str", 21111, move |function_id, payload| {
+            simulator.handle_request(function_id, payload)
+        })
+        .await;
+        let mut bricklet = StreamTestBricklet::new("Str", connection);
+        let message: Vec<char> = "0123456789".repeat(9).chars().collect();
+        let written = bricklet
+            .short_write(ShortWriteRequest { data: &message })
+            .await
+            .unwrap();
+        assert_eq!(written, 90);
+        let chunks: Vec<_> = brickd
+            .received()
+            .into_iter()
+            .filter(|packet| packet.header().function_id() == 17)
+            .collect();
+        assert_eq!(chunks.len(), 2);
+        // the second chunk carries 30 characters, padded with zeros to the full 60
+        let last_chunk = chunks[1].body();
+        assert_eq!(last_chunk.len(), 64);
+        assert!(last_chunk[34..].iter().all(|byte| *byte == 0));
+    }
+}
@@ -17,6 +17,23 @@ pub trait FromByteSlice {
     fn from_le_byte_slice(bytes: &[u8]) -> Self;
     /// Returns how many bytes are expected to deserialize a instance of the implementing type. Currently this method is only used for strings.
     fn bytes_expected() -> usize;
+    /// Returns how many bytes are expected to deserialize an array of `count` instances, bools are packed into bits.
+    fn array_bytes_expected(count: usize) -> usize
+    where
+        Self: Sized,
+    {
+        Self::bytes_expected() * count
+    }
+    /// Deserialize consecutive instances of the implementing type into `target`.
+    fn fill_from_le_byte_slice(target: &mut [Self], bytes: &[u8])
+    where
+        Self: Sized,
+    {
+        let component_size = Self::bytes_expected();
+        for (i, value) in target.iter_mut().enumerate() {
+            *value = Self::from_le_byte_slice(&bytes[i * component_size..(i + 1) * component_size]);
+        }
+    }
 }
 
 impl<const N: usize> FromByteSlice for Box<[u8; N]> {
@@ -36,15 +53,12 @@ impl<const N: usize, T: Default + Copy + FromByteSlice> FromByteSlice for [T; N]
     fn from_le_byte_slice(bytes: &[u8]) -> Self {
         assert_eq!(bytes.len(), Self::bytes_expected());
         let mut ret = [T::default(); N];
-        let component_size = T::bytes_expected();
-        for i in 0..N {
-            ret[i] = T::from_le_byte_slice(&bytes[i * component_size..(i + 1) * component_size]);
-        }
+        T::fill_from_le_byte_slice(&mut ret, bytes);
         ret
     }
 
     fn bytes_expected() -> usize {
-        T::bytes_expected() * N
+        T::array_bytes_expected(N)
     }
 }
 
@@ -209,6 +223,16 @@ impl FromByteSlice for bool {
     fn bytes_expected() -> usize {
         1
     }
+
+    fn array_bytes_expected(count: usize) -> usize {
+        (count + 7) / 8
+    }
+
+    fn fill_from_le_byte_slice(target: &mut [bool], bytes: &[u8]) {
+        for (i, value) in target.iter_mut().enumerate() {
+            *value = bytes[i / 8] & (1 << (i % 8)) != 0;
+        }
+    }
 }
 
 impl ToBytes for u8 {
@@ -408,18 +432,18 @@ impl FromByteSlice for f64 {
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum ParsedOrRaw<P, R>
-    where
-        P: Into<R> + Debug + Clone + Copy,
-        R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
+where
+    P: Into<R> + Debug + Clone + Copy,
+    R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
 {
     Parsed(P),
     Raw(R),
 }
 
 impl<P, R> ParsedOrRaw<P, R>
-    where
-        P: Into<R> + Debug + Clone + Copy,
-        R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
+where
+    P: Into<R> + Debug + Clone + Copy,
+    R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
 {
     pub fn parsed(&self) -> Option<P> {
         match self {
@@ -436,9 +460,9 @@ impl<P, R> ParsedOrRaw<P, R>
 }
 
 impl<P, R> Debug for ParsedOrRaw<P, R>
-    where
-        P: Into<R> + Debug + Clone + Copy,
-        R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
+where
+    P: Into<R> + Debug + Clone + Copy,
+    R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -453,9 +477,9 @@ impl<P, R> Debug for ParsedOrRaw<P, R>
 }
 
 impl<P, R> From<R> for ParsedOrRaw<P, R>
-    where
-        P: Into<R> + Debug + Clone + Copy,
-        R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
+where
+    P: Into<R> + Debug + Clone + Copy,
+    R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
 {
     fn from(value: R) -> Self {
         if let Ok(parsed) = value.try_into() {
@@ -467,9 +491,9 @@ impl<P, R> From<R> for ParsedOrRaw<P, R>
 }
 
 impl<P, R> ToBytes for ParsedOrRaw<P, R>
-    where
-        P: Into<R> + Debug + Clone + Copy,
-        R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
+where
+    P: Into<R> + Debug + Clone + Copy,
+    R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
 {
     fn write_to_slice(&self, target: &mut [u8]) -> usize {
         let raw_value = match self {
@@ -481,9 +505,9 @@ impl<P, R> ToBytes for ParsedOrRaw<P, R>
 }
 
 impl<P, R> FromByteSlice for ParsedOrRaw<P, R>
-    where
-        P: Into<R> + Debug + Clone + Copy,
-        R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
+where
+    P: Into<R> + Debug + Clone + Copy,
+    R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy,
 {
     fn from_le_byte_slice(bytes: &[u8]) -> Self {
         let raw_value = R::from_le_byte_slice(bytes);
@@ -500,11 +524,29 @@ impl<P, R> FromByteSlice for ParsedOrRaw<P, R>
 }
 
 impl<P, R> Default for ParsedOrRaw<P, R>
-    where
-        P: Into<R> + Debug + Clone + Copy,
-        R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy + Default,
+where
+    P: Into<R> + Debug + Clone + Copy,
+    R: TryInto<P> + FromByteSlice + ToBytes + Debug + Clone + Copy + Default,
 {
     fn default() -> Self {
         Self::from(R::default())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::byte_converter::{FromByteSlice, ToBytes};
+
+    #[test]
+    fn test_bool_array_round_trip() {
+        let mut pixels = [false; 13];
+        for (index, pixel) in pixels.iter_mut().enumerate() {
+            *pixel = index % 3 == 1;
+        }
+        let mut buffer = [0xff; 2];
+        assert_eq!(pixels.write_to_slice(&mut buffer), 2);
+        assert_eq!(buffer, [0b1001_0010, 0b0000_0100]);
+        assert_eq!(<[bool; 13]>::bytes_expected(), 2);
+        assert_eq!(<[bool; 13]>::from_le_byte_slice(&buffer), pixels);
+    }
+}
@@ -0,0 +1,258 @@
+//! Restores the configuration of devices which were power-cycled or newly plugged in.
+//!
+//! A device announces itself with [`EnumerationType::Connected`] in that case and has lost
+//! callback periods, thresholds and similar settings. The [`ConfigurationRegistry`] runs the
+//! registered configuration for such a device automatically.
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use log::{debug, info, warn};
+use tokio::task::AbortHandle;
+use tokio_stream::StreamExt;
+
+use crate::{
+    base58::Uid,
+    error::TinkerforgeError,
+    ip_connection::{async_io::AsyncIpConnection, EnumerateResponse, EnumerationType},
+};
+
+pub type ConfigureFuture = Pin<Box<dyn Future<Output = Result<(), TinkerforgeError>> + Send>>;
+type Configure = Arc<dyn Fn(AsyncIpConnection, EnumerateResponse) -> ConfigureFuture + Send + Sync>;
+
+#[derive(Default)]
+struct Configurations {
+    by_uid: HashMap<Uid, Configure>,
+    by_device_identifier: HashMap<u16, Configure>,
+}
+
+impl Configurations {
+    fn find(&self, response: &EnumerateResponse) -> Option<Configure> {
+        self.by_uid
+            .get(&response.uid)
+            .or_else(|| self.by_device_identifier.get(&response.device_identifier))
+            .cloned()
+    }
+}
+
+/// Configurations per device which are run whenever a device reports
+/// [`EnumerationType::Connected`]. A configuration registered for the uid takes precedence over
+/// one registered for the device type.
+pub struct ConfigurationRegistry {
+    configurations: Arc<Mutex<Configurations>>,
+    abort_handle: AbortHandle,
+}
+
+impl Debug for ConfigurationRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let configurations = self.lock();
+        f.debug_struct("ConfigurationRegistry")
+            .field("uids", &configurations.by_uid.keys())
+            .field(
+                "device_identifiers",
+                &configurations.by_device_identifier.keys(),
+            )
+            .finish()
+    }
+}
+
+impl ConfigurationRegistry {
+    /// Starts listening for enumerations on the connection, the registry stops when it is dropped.
+    /// Fails with [`TinkerforgeError::ConnectionClosed`] if the connection is already closed.
+    pub fn new(connection: AsyncIpConnection) -> Result<Self, TinkerforgeError> {
+        let configurations = Arc::new(Mutex::new(Configurations::default()));
+        let configurations_clone = configurations.clone();
+        let mut enumerations = Box::pin(connection.enumeration_stream()?);
+        let abort_handle = tokio::spawn(async move {
+            while let Some(response) = enumerations.next().await {
+                if response.enumeration_type != EnumerationType::Connected {
+                    continue;
+                }
+                let configure = configurations_clone
+                    .lock()
+                    .expect("Configuration lock poisoned")
+                    .find(&response);
+                let Some(configure) = configure else {
+                    debug!("No configuration for {}", response.uid);
+                    continue;
+                };
+                let uid = response.uid;
+                let configuration = configure(connection.clone(), response);
+                // a slow device must not delay the configuration of the others
+                tokio::spawn(async move {
+                    match configuration.await {
+                        Ok(()) => info!("Configured {uid}"),
+                        Err(error) => warn!("Cannot configure {uid}: {error}"),
+                    }
+                });
+            }
+        })
+        .abort_handle();
+        Ok(Self {
+            configurations,
+            abort_handle,
+        })
+    }
+
+    /// Runs `configure` whenever the device with `uid` is connected, replaces an earlier configuration.
+    pub fn register_uid<F, Fut>(&self, uid: impl Into<Uid>, configure: F)
+    where
+        F: Fn(AsyncIpConnection, EnumerateResponse) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), TinkerforgeError>> + Send + 'static,
+    {
+        self.lock().by_uid.insert(uid.into(), boxed(configure));
+    }
+
+    /// Runs `configure` whenever a device of this type is connected, e.g. `DeviceIdentifier::Lcd128X64Bricklet.into()`
+    /// of the generated bindings.
+    pub fn register_device_type<F, Fut>(&self, device_identifier: u16, configure: F)
+    where
+        F: Fn(AsyncIpConnection, EnumerateResponse) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), TinkerforgeError>> + Send + 'static,
+    {
+        self.lock()
+            .by_device_identifier
+            .insert(device_identifier, boxed(configure));
+    }
+
+    /// Returns whether a configuration was registered.
+    pub fn unregister_uid(&self, uid: impl Into<Uid>) -> bool {
+        self.lock().by_uid.remove(&uid.into()).is_some()
+    }
+
+    /// Returns whether a configuration was registered.
+    pub fn unregister_device_type(&self, device_identifier: u16) -> bool {
+        self.lock()
+            .by_device_identifier
+            .remove(&device_identifier)
+            .is_some()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Configurations> {
+        self.configurations
+            .lock()
+            .expect("Configuration lock poisoned")
+    }
+}
+
+impl Drop for ConfigurationRegistry {
+    fn drop(&mut self) {
+        self.abort_handle.abort();
+    }
+}
+
+fn boxed<F, Fut>(configure: F) -> Configure
+where
+    F: Fn(AsyncIpConnection, EnumerateResponse) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), TinkerforgeError>> + Send + 'static,
+{
+    Arc::new(move |connection, response| Box::pin(configure(connection, response)))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::sync::mpsc;
+    use tokio_stream::StreamExt;
+
+    use crate::{
+        base58::Uid,
+        configuration::ConfigurationRegistry,
+        error::TinkerforgeError,
+        ip_connection::EnumerationType,
+        mock::{MockBrickd, MockDevice, MockResponse},
+    };
+
+    #[tokio::test]
+    async fn test_configure_on_connected() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("R4c", 298).respond(4, MockResponse::ok([])));
+        brickd.add_device(MockDevice::new("XYZ", 298));
+        brickd.add_device(MockDevice::new("Tmp", 2113));
+        let registry = ConfigurationRegistry::new(brickd.connect().await.unwrap()).unwrap();
+        let (configured_tx, mut configured) = mpsc::unbounded_channel();
+        let configured_by_uid = configured_tx.clone();
+        registry.register_uid("R4c", move |connection, response| {
+            let configured = configured_by_uid.clone();
+            async move {
+                connection
+                    .set(
+                        response.uid,
+                        4,
+                        &[14, 100, 0, 1],
+                        Some(Duration::from_secs(5)),
+                    )
+                    .await?;
+                configured.send(("uid", response.uid)).unwrap();
+                Ok(())
+            }
+        });
+        registry.register_device_type(298, move |_, response| {
+            let configured = configured_tx.clone();
+            async move {
+                configured.send(("type", response.uid)).unwrap();
+                Ok(())
+            }
+        });
+
+        brickd.emit_enumerate("Tmp", EnumerationType::Connected);
+        brickd.emit_enumerate("XYZ", EnumerationType::Available);
+        brickd.emit_enumerate("R4c", EnumerationType::Connected);
+        assert_eq!(configured.recv().await, Some(("uid", Uid::from("R4c"))));
+        let request = brickd.received().into_iter().last().unwrap();
+        assert_eq!(request.body(), &[14, 100, 0, 1]);
+
+        brickd.emit_enumerate("XYZ", EnumerationType::Connected);
+        assert_eq!(configured.recv().await, Some(("type", Uid::from("XYZ"))));
+        assert!(configured.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_closed_connection() {
+        let brickd = MockBrickd::start().await.unwrap();
+        let connection = brickd.connect().await.unwrap();
+        connection.disconnect().await.unwrap();
+        assert!(matches!(
+            connection.enumerate().await,
+            Err(TinkerforgeError::ConnectionClosed)
+        ));
+        assert!(matches!(
+            ConfigurationRegistry::new(connection),
+            Err(TinkerforgeError::ConnectionClosed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_callback_stream_after_reconfiguration() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("R4c", 298).respond(4, MockResponse::ok([])));
+        let connection = brickd.connect().await.unwrap();
+        let mut callbacks = connection.callback_stream(Uid::from("R4c"), 7).await;
+        let registry = ConfigurationRegistry::new(connection).unwrap();
+        let (configured_tx, mut configured) = mpsc::unbounded_channel();
+        registry.register_uid("R4c", move |connection, response| {
+            let configured = configured_tx.clone();
+            async move {
+                // e.g. restoring the callback period
+                connection
+                    .set(response.uid, 4, &[100], Some(Duration::from_secs(5)))
+                    .await?;
+                configured.send(response.uid).unwrap();
+                Ok(())
+            }
+        });
+
+        brickd.emit_enumerate("R4c", EnumerationType::Connected);
+        assert_eq!(configured.recv().await, Some(Uid::from("R4c")));
+        brickd.emit_callback("R4c", 7, &[1, 2]);
+        let callback = tokio::time::timeout(Duration::from_secs(5), callbacks.next())
+            .await
+            .unwrap();
+        assert_eq!(callback.unwrap().body(), &[1, 2]);
+    }
+}
@@ -0,0 +1,527 @@
+//! Renders packets in a human readable form, e.g. `Lcd128X64Bricklet[R4c].set_display_configuration{contrast:14,backlight:100,...}`.
+//!
+//! The descriptions of the devices are generated together with the bindings, see the `decode_tables`
+//! option of the generator. Packets of the IP connection itself are known without them.
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Write},
+};
+
+use byteorder::{ByteOrder, LittleEndian};
+
+use crate::{
+    base58::Uid,
+    byte_converter::FromByteSlice,
+    ip_connection::{
+        async_io::PacketData, capture::Direction, EnumerateResponse, ErrorCode, PacketHeader,
+    },
+};
+
+/// Wire type of a field.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FieldType {
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Float,
+    Bool,
+    Char,
+    String,
+}
+
+impl FieldType {
+    /// Number of bytes `cardinality` values of this type take on the wire.
+    pub fn size(self, cardinality: usize) -> usize {
+        match self {
+            FieldType::Int8 | FieldType::UInt8 | FieldType::Char | FieldType::String => cardinality,
+            FieldType::Int16 | FieldType::UInt16 => cardinality * 2,
+            FieldType::Int32 | FieldType::UInt32 | FieldType::Float => cardinality * 4,
+            FieldType::Int64 | FieldType::UInt64 => cardinality * 8,
+            FieldType::Bool => (cardinality + 7) / 8,
+        }
+    }
+}
+
+/// Named values of a field, bools and chars are stored by their numeric value.
+#[derive(Debug)]
+pub struct ConstantGroup {
+    pub name: &'static str,
+    pub constants: &'static [(i64, &'static str)],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FieldDescription {
+    pub name: &'static str,
+    pub field_type: FieldType,
+    /// Number of values, for strings the number of characters.
+    pub cardinality: usize,
+    pub constant_group: Option<&'static ConstantGroup>,
+}
+
+#[derive(Debug)]
+pub struct FunctionDescription {
+    pub function_id: u8,
+    pub name: &'static str,
+    /// Callbacks are only sent by the device and have no request.
+    pub callback: bool,
+    pub request: &'static [FieldDescription],
+    pub response: &'static [FieldDescription],
+}
+
+#[derive(Debug)]
+pub struct DeviceDescription {
+    pub name: &'static str,
+    pub device_identifier: u16,
+    pub functions: &'static [FunctionDescription],
+}
+
+impl DeviceDescription {
+    pub fn function(&self, function_id: u8) -> Option<&'static FunctionDescription> {
+        self.functions
+            .iter()
+            .find(|function| function.function_id == function_id)
+    }
+}
+
+const fn field(name: &'static str, field_type: FieldType, cardinality: usize) -> FieldDescription {
+    FieldDescription {
+        name,
+        field_type,
+        cardinality,
+        constant_group: None,
+    }
+}
+
+const IDENTITY_FIELDS: [FieldDescription; 6] = [
+    field("uid", FieldType::String, 8),
+    field("connected_uid", FieldType::String, 8),
+    field("position", FieldType::Char, 1),
+    field("hardware_version", FieldType::UInt8, 3),
+    field("firmware_version", FieldType::UInt8, 3),
+    field("device_identifier", FieldType::UInt16, 1),
+];
+
+/// Functions of the Brick Daemon and the ones every device implements.
+pub static IP_CONNECTION: DeviceDescription = DeviceDescription {
+    name: "IpConnection",
+    device_identifier: 0,
+    functions: &[
+        FunctionDescription {
+            function_id: 1,
+            name: "get_authentication_nonce",
+            callback: false,
+            request: &[],
+            response: &[field("server_nonce", FieldType::UInt8, 4)],
+        },
+        FunctionDescription {
+            function_id: 2,
+            name: "authenticate",
+            callback: false,
+            request: &[
+                field("client_nonce", FieldType::UInt8, 4),
+                field("digest", FieldType::UInt8, 20),
+            ],
+            response: &[],
+        },
+        FunctionDescription {
+            function_id: 128,
+            name: "disconnect_probe",
+            callback: false,
+            request: &[],
+            response: &[],
+        },
+        FunctionDescription {
+            function_id: 253,
+            name: "enumerate_callback",
+            callback: true,
+            request: &[],
+            response: &[
+                IDENTITY_FIELDS[0],
+                IDENTITY_FIELDS[1],
+                IDENTITY_FIELDS[2],
+                IDENTITY_FIELDS[3],
+                IDENTITY_FIELDS[4],
+                IDENTITY_FIELDS[5],
+                FieldDescription {
+                    name: "enumeration_type",
+                    field_type: FieldType::UInt8,
+                    cardinality: 1,
+                    constant_group: Some(&ConstantGroup {
+                        name: "Enumeration Type",
+                        constants: &[(0, "Available"), (1, "Connected"), (2, "Disconnected")],
+                    }),
+                },
+            ],
+        },
+        FunctionDescription {
+            function_id: 254,
+            name: "enumerate",
+            callback: false,
+            request: &[],
+            response: &[],
+        },
+        FunctionDescription {
+            function_id: 255,
+            name: "get_identity",
+            callback: false,
+            request: &[],
+            response: &IDENTITY_FIELDS,
+        },
+    ],
+};
+
+/// Knows which device is behind which uid and renders their packets.
+#[derive(Debug, Clone, Default)]
+pub struct PacketDecoder {
+    descriptions: HashMap<u16, &'static DeviceDescription>,
+    devices: HashMap<Uid, u16>,
+}
+
+impl PacketDecoder {
+    /// Creates a decoder for the given device types, usually the generated `DEVICE_DESCRIPTIONS`.
+    pub fn new(descriptions: &'static [DeviceDescription]) -> Self {
+        Self {
+            descriptions: descriptions
+                .iter()
+                .map(|description| (description.device_identifier, description))
+                .collect(),
+            devices: HashMap::new(),
+        }
+    }
+
+    pub fn add_device(&mut self, uid: impl Into<Uid>, device_identifier: u16) {
+        self.devices.insert(uid.into(), device_identifier);
+    }
+
+    /// Remembers the device type from enumerate callbacks and `get_identity` responses, so the
+    /// following packets of that device can be decoded.
+    pub fn learn(&mut self, packet: &PacketData) {
+        let header = packet.header();
+        let body = packet.body();
+        match header.function_id() {
+            253 if body.len() == 26 => {
+                if let Ok(response) = Result::<EnumerateResponse, _>::from_le_byte_slice(body) {
+                    self.add_device(response.uid, response.device_identifier);
+                }
+            }
+            255 if body.len() == 25 && header.uid() != Uid::zero() => {
+                self.add_device(header.uid(), u16::from_le_byte_slice(&body[23..25]));
+            }
+            _ => {}
+        }
+    }
+
+    pub fn device(&self, uid: Uid) -> Option<&'static DeviceDescription> {
+        self.devices
+            .get(&uid)
+            .and_then(|device_identifier| self.descriptions.get(device_identifier))
+            .copied()
+    }
+
+    /// Looks up the packet, the result implements [`Display`].
+    pub fn decode<'a>(&self, packet: &'a PacketData, direction: Direction) -> DecodedPacket<'a> {
+        let header = packet.header();
+        let device = self.device(header.uid());
+        let function = device
+            .and_then(|device| device.function(header.function_id()))
+            .or_else(|| {
+                IP_CONNECTION
+                    .function(header.function_id())
+                    .filter(|function| header.uid() == Uid::zero() || function.function_id >= 253)
+            });
+        DecodedPacket {
+            device_name: match device {
+                Some(device) => device.name,
+                None if header.uid() == Uid::zero() => IP_CONNECTION.name,
+                None => "Device",
+            },
+            function,
+            header,
+            body: packet.body(),
+            direction,
+        }
+    }
+}
+
+/// A packet together with its description.
+#[derive(Debug, Clone, Copy)]
+pub struct DecodedPacket<'a> {
+    device_name: &'static str,
+    function: Option<&'static FunctionDescription>,
+    header: PacketHeader,
+    body: &'a [u8],
+    direction: Direction,
+}
+
+impl Display for DecodedPacket<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{:?}].", self.device_name, self.header.uid())?;
+        let Some(function) = self.function else {
+            write!(f, "function_{}", self.header.function_id())?;
+            if self.direction == Direction::Received && self.header.sequence_number() != 0 {
+                f.write_str(" -> ")?;
+            }
+            return write_hex(f, self.body);
+        };
+        f.write_str(function.name)?;
+        let fields = match self.direction {
+            Direction::Sent => function.request,
+            Direction::Received if function.callback => function.response,
+            Direction::Received => {
+                f.write_str(" -> ")?;
+                let error_code = self.header.error_code();
+                if error_code != ErrorCode::Success {
+                    return write!(f, "{error_code:?}");
+                }
+                function.response
+            }
+        };
+        write_fields(f, fields, self.body)
+    }
+}
+
+fn write_fields(
+    f: &mut Formatter<'_>,
+    fields: &[FieldDescription],
+    body: &[u8],
+) -> std::fmt::Result {
+    let expected = fields
+        .iter()
+        .map(|field| field.field_type.size(field.cardinality))
+        .sum::<usize>();
+    if body.len() < expected {
+        return write_hex(f, body);
+    }
+    f.write_char('{')?;
+    let mut offset = 0;
+    for (index, field) in fields.iter().enumerate() {
+        if index > 0 {
+            f.write_char(',')?;
+        }
+        let size = field.field_type.size(field.cardinality);
+        write!(f, "{}:", field.name)?;
+        write_value(f, field, &body[offset..offset + size])?;
+        offset += size;
+    }
+    f.write_char('}')
+}
+
+fn write_value(f: &mut Formatter<'_>, field: &FieldDescription, bytes: &[u8]) -> std::fmt::Result {
+    if field.field_type == FieldType::String {
+        let text = String::from_utf8_lossy(bytes);
+        return write!(f, "{:?}", text.trim_end_matches('\0'));
+    }
+    if field.cardinality > 1 {
+        f.write_char('[')?;
+    }
+    let element_size = field.field_type.size(1);
+    for index in 0..field.cardinality {
+        if index > 0 {
+            f.write_char(',')?;
+        }
+        if field.field_type == FieldType::Bool {
+            let value = bytes[index / 8] & (1 << (index % 8)) != 0;
+            write_constant(f, field, value as i64, |f| write!(f, "{value}"))?;
+            continue;
+        }
+        let bytes = &bytes[index * element_size..(index + 1) * element_size];
+        let value = match field.field_type {
+            FieldType::Int8 => bytes[0] as i8 as i64,
+            FieldType::Int16 => LittleEndian::read_i16(bytes) as i64,
+            FieldType::Int32 => LittleEndian::read_i32(bytes) as i64,
+            FieldType::Int64 => LittleEndian::read_i64(bytes),
+            FieldType::UInt8 | FieldType::Char => bytes[0] as i64,
+            FieldType::UInt16 => LittleEndian::read_u16(bytes) as i64,
+            FieldType::UInt32 => LittleEndian::read_u32(bytes) as i64,
+            FieldType::UInt64 => {
+                write!(f, "{}", LittleEndian::read_u64(bytes))?;
+                continue;
+            }
+            FieldType::Float => {
+                write!(f, "{}", LittleEndian::read_f32(bytes))?;
+                continue;
+            }
+            FieldType::Bool | FieldType::String => unreachable!(),
+        };
+        if field.field_type == FieldType::Char {
+            write_constant(f, field, value, |f| write!(f, "{:?}", bytes[0] as char))?;
+        } else {
+            write_constant(f, field, value, |f| write!(f, "{value}"))?;
+        }
+    }
+    if field.cardinality > 1 {
+        f.write_char(']')?;
+    }
+    Ok(())
+}
+
+/// Writes the name of the constant with this value, or the value itself if there is none.
+fn write_constant(
+    f: &mut Formatter<'_>,
+    field: &FieldDescription,
+    value: i64,
+    write_raw: impl FnOnce(&mut Formatter<'_>) -> std::fmt::Result,
+) -> std::fmt::Result {
+    let constant = field.constant_group.and_then(|group| {
+        group
+            .constants
+            .iter()
+            .find(|(constant_value, _)| *constant_value == value)
+    });
+    match constant {
+        Some((_, name)) => f.write_str(name),
+        None => write_raw(f),
+    }
+}
+
+fn write_hex(f: &mut Formatter<'_>, bytes: &[u8]) -> std::fmt::Result {
+    f.write_char('<')?;
+    for (index, byte) in bytes.iter().enumerate() {
+        if index > 0 {
+            f.write_char(' ')?;
+        }
+        write!(f, "{byte:02x}")?;
+    }
+    f.write_char('>')
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        base58::Uid,
+        byte_converter::ToBytes,
+        decoder::{
+            ConstantGroup, DeviceDescription, FieldDescription, FieldType, FunctionDescription,
+            PacketDecoder,
+        },
+        ip_connection::{
+            async_io::PacketData, capture::Direction, EnumerateResponse, EnumerationType,
+            ErrorCode, PacketHeader, Version,
+        },
+    };
+
+    static DESCRIPTIONS: [DeviceDescription; 1] = [DeviceDescription {
+        name: "Lcd128X64Bricklet",
+        device_identifier: 298,
+        functions: &[
+            FunctionDescription {
+                function_id: 4,
+                name: "set_display_configuration",
+                callback: false,
+                request: &[
+                    FieldDescription {
+                        name: "contrast",
+                        field_type: FieldType::UInt8,
+                        cardinality: 1,
+                        constant_group: None,
+                    },
+                    FieldDescription {
+                        name: "invert",
+                        field_type: FieldType::Bool,
+                        cardinality: 1,
+                        constant_group: None,
+                    },
+                ],
+                response: &[],
+            },
+            FunctionDescription {
+                function_id: 15,
+                name: "touch_gesture",
+                callback: true,
+                request: &[],
+                response: &[
+                    FieldDescription {
+                        name: "gesture",
+                        field_type: FieldType::UInt8,
+                        cardinality: 1,
+                        constant_group: Some(&ConstantGroup {
+                            name: "Gesture",
+                            constants: &[(0, "LeftToRight"), (1, "RightToLeft")],
+                        }),
+                    },
+                    FieldDescription {
+                        name: "x",
+                        field_type: FieldType::Int16,
+                        cardinality: 2,
+                        constant_group: None,
+                    },
+                ],
+            },
+        ],
+    }];
+
+    fn packet(function_id: u8, sequence_number: u8, body: &[u8]) -> PacketData {
+        let header = PacketHeader::with_payload(
+            Uid::from("R4c"),
+            function_id,
+            sequence_number,
+            true,
+            body.len() as u8,
+        );
+        PacketData::new(header, body)
+    }
+
+    #[test]
+    fn test_decode_known_device() {
+        let mut decoder = PacketDecoder::new(&DESCRIPTIONS);
+        decoder.add_device("R4c", 298);
+        let request = packet(4, 1, &[14, 1]);
+        assert_eq!(
+            decoder.decode(&request, Direction::Sent).to_string(),
+            "Lcd128X64Bricklet[R4c].set_display_configuration{contrast:14,invert:true}"
+        );
+        let response = PacketData::new(
+            packet(4, 1, &[])
+                .header()
+                .with_error_code(ErrorCode::InvalidParameter),
+            [],
+        );
+        assert_eq!(
+            decoder.decode(&response, Direction::Received).to_string(),
+            "Lcd128X64Bricklet[R4c].set_display_configuration -> InvalidParameter"
+        );
+        let callback = packet(15, 0, &[1, 0xff, 0xff, 3, 0]);
+        assert_eq!(
+            decoder.decode(&callback, Direction::Received).to_string(),
+            "Lcd128X64Bricklet[R4c].touch_gesture{gesture:RightToLeft,x:[-1,3]}"
+        );
+    }
+
+    #[test]
+    fn test_learn_from_enumeration() {
+        let mut decoder = PacketDecoder::new(&DESCRIPTIONS);
+        let unknown = packet(4, 1, &[14, 1]);
+        assert_eq!(
+            decoder.decode(&unknown, Direction::Sent).to_string(),
+            "Device[R4c].function_4<0e 01>"
+        );
+        let enumeration = EnumerateResponse {
+            uid: Uid::from("R4c"),
+            connected_uid: Uid::from("6qzRzc"),
+            position: 'a',
+            hardware_version: Version::new(1, 0, 0),
+            firmware_version: Version::new(2, 0, 3),
+            device_identifier: 298,
+            enumeration_type: EnumerationType::Connected,
+        };
+        let mut body = [0; 26];
+        enumeration.write_to_slice(&mut body);
+        let callback = packet(253, 0, &body);
+        assert_eq!(
+            decoder.decode(&callback, Direction::Received).to_string(),
+            "Device[R4c].enumerate_callback{uid:\"R4c\",connected_uid:\"6qzRzc\",position:'a',\
+             hardware_version:[1,0,0],firmware_version:[2,0,3],device_identifier:298,\
+             enumeration_type:Connected}"
+        );
+        decoder.learn(&callback);
+        assert_eq!(
+            decoder.decode(&unknown, Direction::Sent).to_string(),
+            "Lcd128X64Bricklet[R4c].set_display_configuration{contrast:14,invert:true}"
+        );
+    }
+}
@@ -6,12 +6,15 @@ use futures_core::Stream;
 #[cfg(feature = "prometheus")]
 use lazy_static::lazy_static;
 #[cfg(feature = "prometheus")]
-use prometheus::{HistogramVec, register_histogram_vec};
+use prometheus::{register_histogram_vec, HistogramVec};
 
 use crate::{
     base58::Uid,
     error::TinkerforgeError,
-    ip_connection::async_io::{AsyncIpConnection, PacketData},
+    ip_connection::{
+        async_io::{AsyncIpConnection, PacketData},
+        Version,
+    },
 };
 
 #[cfg(feature = "prometheus")]
@@ -30,6 +33,7 @@ const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
 pub struct Device {
     pub internal_uid: Uid,
     pub connection: AsyncIpConnection,
+    firmware_version: Option<Version>,
     #[cfg(feature = "prometheus")]
     device_display_name: &'static str,
 }
@@ -86,6 +90,7 @@ impl Device {
         Device {
             internal_uid,
             connection,
+            firmware_version: None,
             #[cfg(feature = "prometheus")]
             device_display_name,
         }
@@ -94,6 +99,44 @@ impl Device {
         self.internal_uid
     }
 
+    /// The firmware version functions are checked against, `None` if the check is disabled.
+    pub fn firmware_version(&self) -> Option<Version> {
+        self.firmware_version
+    }
+
+    /// Enables the firmware check with the version reported by an enumeration or `get_identity`,
+    /// `None` disables it again.
+    pub fn set_firmware_version(&mut self, firmware_version: Option<Version>) {
+        self.firmware_version = firmware_version;
+    }
+
+    /// Queries the firmware version with `get_identity` and enables the firmware check with it.
+    pub async fn load_firmware_version(&mut self) -> Result<Version, TinkerforgeError> {
+        let identity = self.get(255, &[]).await?;
+        let [major, minor, patch]: [u8; 3] =
+            identity.body().get(20..23).unwrap_or_default().try_into()?;
+        let firmware_version = Version::new(major, minor, patch);
+        self.firmware_version = Some(firmware_version);
+        Ok(firmware_version)
+    }
+
+    /// Fails with [`TinkerforgeError::UnsupportedByFirmware`] if the firmware version is known and
+    /// older than `required`.
+    pub fn check_firmware(
+        &self,
+        function_id: u8,
+        required: Version,
+    ) -> Result<(), TinkerforgeError> {
+        match self.firmware_version {
+            Some(actual) if actual < required => Err(TinkerforgeError::UnsupportedByFirmware {
+                function_id,
+                required,
+                actual,
+            }),
+            _ => Ok(()),
+        }
+    }
+
     pub async fn set(
         &mut self,
         function_id: u8,
@@ -101,7 +144,7 @@ impl Device {
         timeout: Option<Duration>,
     ) -> Result<Option<PacketData>, TinkerforgeError> {
         #[cfg(feature = "prometheus")]
-            let timer = REQUEST_TIMING
+        let timer = REQUEST_TIMING
             .with_label_values(&[
                 self.device_display_name,
                 function_id.to_string().as_str(),
@@ -109,7 +152,7 @@ impl Device {
             ])
             .start_timer();
         #[allow(clippy::let_and_return)]
-            let result = self
+        let result = self
             .connection
             .set(self.internal_uid, function_id, payload, timeout)
             .await;
@@ -121,7 +164,7 @@ impl Device {
     pub async fn get_callback_receiver(
         &mut self,
         function_id: u8,
-    ) -> impl Stream<Item=PacketData> {
+    ) -> impl Stream<Item = PacketData> {
         self.connection
             .callback_stream(self.internal_uid, function_id)
             .await
@@ -133,7 +176,7 @@ impl Device {
         payload: &[u8],
     ) -> Result<PacketData, TinkerforgeError> {
         #[cfg(feature = "prometheus")]
-            let timer = REQUEST_TIMING
+        let timer = REQUEST_TIMING
             .with_label_values(&[
                 self.device_display_name,
                 function_id.to_string().as_str(),
@@ -141,7 +184,7 @@ impl Device {
             ])
             .start_timer();
         #[allow(clippy::let_and_return)]
-            let result = self
+        let result = self
             .connection
             .get(self.internal_uid, function_id, payload, DEFAULT_TIMEOUT)
             .await;
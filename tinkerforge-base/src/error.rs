@@ -2,6 +2,8 @@ use std::{array::TryFromSliceError, io};
 use thiserror::Error;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 
+use crate::ip_connection::{AuthenticateError, Version};
+
 #[derive(Error, Debug)]
 pub enum TinkerforgeError {
     #[error("IO Error: {0}")]
@@ -12,4 +14,24 @@ pub enum TinkerforgeError {
     BroadcastStreamRecvError(#[from] BroadcastStreamRecvError),
     #[error("Cannot extract slice from Packet")]
     PackedDecodingError(#[from] TryFromSliceError),
+    #[error("Authentication failed: {0}")]
+    AuthenticationError(#[from] AuthenticateError),
+    #[error("Device reported an invalid parameter")]
+    InvalidParameter,
+    #[error("Function is not supported by the device")]
+    FunctionNotSupported,
+    #[error("Function {function_id} requires firmware {required}, the device runs {actual}")]
+    UnsupportedByFirmware {
+        function_id: u8,
+        required: Version,
+        actual: Version,
+    },
+    #[error("Device reported an unknown error")]
+    UnknownError,
+    #[error("Stream got out of sync")]
+    StreamOutOfSync,
+    #[error("Stream is empty, nothing was sent to the device")]
+    EmptyStream,
+    #[error("Connection was closed")]
+    ConnectionClosed,
 }
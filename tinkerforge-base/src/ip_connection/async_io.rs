@@ -1,250 +1,603 @@
 use std::{
-    borrow::BorrowMut,
+    collections::BTreeMap,
     fmt::Debug,
     ops::{Deref, DerefMut},
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
-    },
+    sync::Arc,
     time::Duration,
 };
 
-use log::{debug, error, info, warn};
+use log::{debug, error, info, log_enabled, warn, Level};
 use tokio::{
-    io::{self, AsyncReadExt, AsyncWriteExt, WriteHalf},
-    net::{TcpStream, ToSocketAddrs},
+    io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::ToSocketAddrs,
     sync::{
         broadcast::{self, Receiver},
-        Mutex,
+        watch, Mutex,
     },
     task::AbortHandle,
 };
 use tokio_stream::{
-    empty,
-    wrappers::{errors::BroadcastStreamRecvError, BroadcastStream},
+    wrappers::{errors::BroadcastStreamRecvError, BroadcastStream, WatchStream},
     Stream, StreamExt,
 };
-use tokio_util::either::Either;
+use tokio_util::sync::CancellationToken;
 
 use crate::{
-    base58::{Base58Error, Uid},
+    base58::Uid,
     byte_converter::{FromByteSlice, ToBytes},
+    decoder::PacketDecoder,
     error::TinkerforgeError,
-    ip_connection::{EnumerateResponse, PacketHeader},
+    ip_connection::{
+        authentication_payload,
+        capture::{CaptureFormat, CaptureHandle, CaptureTap, Direction},
+        dispatcher::Dispatcher,
+        transport::{TcpTransport, Transport},
+        AuthenticateError, EnumerateResponse, EnumerationType, ErrorCode, PacketHeader,
+        ServerNonce,
+    },
 };
 
+type BoxedWriteStream = Box<dyn AsyncWrite + Send + Unpin>;
+type SharedDecoder = Arc<std::sync::Mutex<Option<PacketDecoder>>>;
+
+/// Connection to a Brick Daemon or a WIFI/Ethernet Extension. Clones share the same connection,
+/// requests from different clones are pipelined and do not wait for each other.
 #[derive(Debug, Clone)]
 pub struct AsyncIpConnection {
-    inner: Arc<Mutex<InnerAsyncIpConnection>>,
+    inner: Arc<InnerAsyncIpConnection>,
 }
 
 impl AsyncIpConnection {
+    /// Asks all devices to enumerate and returns the stream of their responses. Fails with
+    /// [`TinkerforgeError::ConnectionClosed`] once the connection is closed, earlier versions
+    /// returned an empty stream in that case.
     pub async fn enumerate(
-        &mut self,
+        &self,
     ) -> Result<impl Stream<Item = EnumerateResponse>, TinkerforgeError> {
-        self.inner.borrow_mut().lock().await.enumerate().await
+        self.inner.enumerate().await
     }
-    pub async fn disconnect_probe(&mut self) -> Result<(), TinkerforgeError> {
-        self.inner
-            .borrow_mut()
-            .lock()
-            .await
-            .disconnect_probe()
-            .await
+    /// Stream of the enumerate callbacks from now on, e.g. devices being connected or disconnected,
+    /// without triggering an enumeration. Fails with [`TinkerforgeError::ConnectionClosed`] once
+    /// the connection is closed.
+    pub fn enumeration_stream(
+        &self,
+    ) -> Result<impl Stream<Item = EnumerateResponse>, TinkerforgeError> {
+        self.inner.enumeration_stream()
     }
-    pub async fn get_authentication_nonce(&mut self) -> Result<[u8; 4], TinkerforgeError> {
-        self.inner
-            .borrow_mut()
-            .lock()
-            .await
-            .get_authentication_nonce()
-            .await
+    /// Enumerates the devices and collects the responses until none arrived for `quiet_period`.
+    /// Every device is contained once, ordered by uid.
+    pub async fn enumerate_all(
+        &self,
+        quiet_period: Duration,
+    ) -> Result<Vec<EnumerateResponse>, TinkerforgeError> {
+        self.inner.enumerate_all(quiet_period).await
+    }
+    pub async fn disconnect_probe(&self) -> Result<(), TinkerforgeError> {
+        self.inner.disconnect_probe().await
+    }
+    pub async fn get_authentication_nonce(&self) -> Result<[u8; 4], TinkerforgeError> {
+        self.inner.get_authentication_nonce().await
+    }
+    /// Authenticates the connection against a password protected Brick Daemon or WIFI/Ethernet Extension.
+    /// The secret is kept and the authentication is repeated automatically after every reconnect.
+    pub async fn authenticate(&self, secret: &str) -> Result<(), TinkerforgeError> {
+        self.inner.authenticate(secret).await
     }
     pub async fn set(
-        &mut self,
+        &self,
         uid: Uid,
         function_id: u8,
         payload: &[u8],
         timeout: Option<Duration>,
     ) -> Result<Option<PacketData>, TinkerforgeError> {
-        self.inner
-            .borrow_mut()
-            .lock()
-            .await
-            .set(uid, function_id, payload, timeout)
-            .await
+        self.inner.set(uid, function_id, payload, timeout).await
     }
     pub async fn get(
-        &mut self,
+        &self,
         uid: Uid,
         function_id: u8,
         payload: &[u8],
         timeout: Duration,
     ) -> Result<PacketData, TinkerforgeError> {
-        self.inner
-            .borrow_mut()
-            .lock()
-            .await
-            .get(uid, function_id, payload, timeout)
-            .await
+        self.inner.get(uid, function_id, payload, timeout).await
     }
     pub async fn callback_stream(
-        &mut self,
+        &self,
         uid: Uid,
         function_id: u8,
     ) -> impl Stream<Item = PacketData> {
-        self.inner
-            .borrow_mut()
-            .lock()
-            .await
-            .callback_stream(uid, function_id)
+        self.inner.callback_stream(uid, function_id).await
+    }
+    /// Records every packet sent and received on this connection to `writer` until the returned
+    /// handle is stopped or dropped, see [`capture`](crate::ip_connection::capture) for the format.
+    /// A running capture is replaced by the new one.
+    pub fn start_capture<W: AsyncWrite + Send + Unpin + 'static>(
+        &self,
+        writer: W,
+    ) -> CaptureHandle {
+        self.inner.capture.start(writer, CaptureFormat::Native)
+    }
+    /// Like [`start_capture`](Self::start_capture), but writes a pcap file for Wireshark,
+    /// see [`pcap`](crate::ip_connection::pcap).
+    pub fn start_pcap_capture<W: AsyncWrite + Send + Unpin + 'static>(
+        &self,
+        writer: W,
+    ) -> CaptureHandle {
+        self.inner.capture.start(writer, CaptureFormat::Pcap)
+    }
+    /// Logs the packets of this connection in decoded form instead of raw bytes. The decoder
+    /// learns the device types from enumerations and `get_identity` responses.
+    pub fn set_decoder(&self, decoder: PacketDecoder) {
+        *self.inner.decoder.lock().expect("Decoder lock poisoned") = Some(decoder);
+    }
+    /// Number of responses that arrived after their request had already timed out and were dropped.
+    pub fn stale_responses(&self) -> u64 {
+        self.inner.dispatcher.stale_responses()
+    }
+    /// Current state of the connection.
+    pub fn state(&self) -> ConnectionState {
+        self.inner.state.borrow().clone()
+    }
+    pub fn is_connected(&self) -> bool {
+        *self.inner.state.borrow() == ConnectionState::Connected
+    }
+    /// Stream of state changes, starting with the current state.
+    pub fn state_stream(&self) -> impl Stream<Item = ConnectionState> {
+        WatchStream::new(self.inner.state.clone())
+    }
+    /// Closes the connection: pending requests fail with [`TinkerforgeError::ConnectionClosed`],
+    /// callback streams end and the socket is flushed and shut down.
+    pub async fn disconnect(&self) -> Result<(), TinkerforgeError> {
+        let result = self.inner.disconnect().await;
+        self.closed().await;
+        result
+    }
+    /// Resolves as soon as the connection is finally closed and will not be re-established.
+    pub async fn closed(&self) -> DisconnectReason {
+        let mut state = self.inner.state.clone();
+        let result = state
+            .wait_for(|state| matches!(state, ConnectionState::Disconnected(_)))
             .await
+            .map(|state| state.clone());
+        match result {
+            Ok(ConnectionState::Disconnected(reason)) => reason,
+            _ => DisconnectReason::Closed,
+        }
     }
-    pub async fn new<T: ToSocketAddrs + Debug + Clone + Send + 'static>(
+    pub async fn new<T: ToSocketAddrs + Debug + Clone + Send + Sync + 'static>(
         addr: T,
+    ) -> Result<Self, TinkerforgeError> {
+        Self::with_transport(TcpTransport::new(addr), None).await
+    }
+    /// Connects to `addr` and re-dials the same address according to `policy` whenever the
+    /// connection is lost. Callback streams stay open while the connection is re-established.
+    pub async fn new_with_reconnect<T: ToSocketAddrs + Debug + Clone + Send + Sync + 'static>(
+        addr: T,
+        policy: ReconnectPolicy,
+    ) -> Result<Self, TinkerforgeError> {
+        Self::with_transport(TcpTransport::new(addr), Some(policy)).await
+    }
+    /// Connects via WebSocket, e.g. to `ws://localhost:4280`. Useful where only HTTP upgrades pass the firewall.
+    #[cfg(feature = "websocket")]
+    pub async fn new_websocket(url: impl Into<String>) -> Result<Self, TinkerforgeError> {
+        Self::with_transport(
+            crate::ip_connection::transport::WebSocketTransport::new(url),
+            None,
+        )
+        .await
+    }
+    /// Speaks the protocol over any [`Transport`], e.g. a unix socket or an in-memory stream.
+    /// With a `reconnect_policy` the transport is asked for a new stream whenever the connection is lost.
+    pub async fn with_transport<T: Transport>(
+        transport: T,
+        reconnect_policy: Option<ReconnectPolicy>,
     ) -> Result<Self, TinkerforgeError> {
         Ok(Self {
-            inner: Arc::new(Mutex::new(InnerAsyncIpConnection::new(addr).await?)),
+            inner: Arc::new(InnerAsyncIpConnection::new(transport, reconnect_policy).await?),
         })
     }
 }
 
-#[derive(Debug)]
+/// State of an [`AsyncIpConnection`].
+///
+/// The constructors only return once the initial connect succeeded, so every connection starts out
+/// [`Connected`](ConnectionState::Connected); `Connecting` is only seen while reconnecting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// A socket is being opened and authenticated by a reconnect attempt.
+    Connecting,
+    /// The connection is established and packets are received.
+    Connected,
+    /// The connection was lost and is going to be re-established, waiting for the given attempt.
+    Reconnecting { attempt: u32 },
+    /// The connection is closed and will not be re-established.
+    Disconnected(DisconnectReason),
+}
+
+/// Why a connection was closed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DisconnectReason {
+    /// Reading from the socket failed and no reconnect was configured.
+    ConnectionLost(io::ErrorKind),
+    /// The peer sent a packet which cannot be decoded, the stream cannot be resynchronized.
+    MalformedPacket,
+    /// The reconnect policy gave up after its maximum number of attempts.
+    ReconnectFailed,
+    /// Nobody is listening to the connection anymore.
+    Closed,
+    /// [`AsyncIpConnection::disconnect`] was called.
+    Requested,
+}
+
+/// Controls how a lost connection is re-established.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// Delay before the first reconnect attempt.
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between two attempts, the delay doubles after every failed attempt.
+    pub max_backoff: Duration,
+    /// Give up after this many failed attempts in a row, `None` retries forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(1 << attempt.min(16))
+            .min(self.max_backoff)
+    }
+}
+
 struct InnerAsyncIpConnection {
-    write_stream: WriteHalf<TcpStream>,
+    write_stream: Arc<Mutex<BoxedWriteStream>>,
+    secret: Arc<Mutex<Option<Box<str>>>>,
+    dispatcher: Arc<Dispatcher>,
+    capture: Arc<CaptureTap>,
+    decoder: SharedDecoder,
     receiver: Receiver<Option<PacketData>>,
-    seq_num: u8,
-    running: Arc<AtomicBool>,
+    state: watch::Receiver<ConnectionState>,
+    shutdown: CancellationToken,
     abort_handle: AbortHandle,
 }
 
+impl Debug for InnerAsyncIpConnection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InnerAsyncIpConnection")
+            .field("dispatcher", &self.dispatcher)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
 impl InnerAsyncIpConnection {
-    pub async fn new<T: ToSocketAddrs + Clone + Debug + Send + 'static>(
-        addr: T,
+    pub async fn new<T: Transport>(
+        transport: T,
+        reconnect_policy: Option<ReconnectPolicy>,
     ) -> Result<Self, TinkerforgeError> {
-        let socket = TcpStream::connect(addr.clone()).await?;
-        Self::enable_keepalive(&socket)?;
+        let socket = transport.connect().await?;
 
         let (mut rd, write_stream) = io::split(socket);
+        let write_stream = Arc::new(Mutex::new(Box::new(write_stream) as BoxedWriteStream));
+        let write_stream_clone = write_stream.clone();
+        let secret = Arc::new(Mutex::new(None::<Box<str>>));
+        let secret_clone = secret.clone();
+        let dispatcher = Arc::new(Dispatcher::new());
+        let dispatcher_clone = dispatcher.clone();
+        let capture = Arc::new(CaptureTap::default());
+        let capture_clone = capture.clone();
+        let decoder = SharedDecoder::default();
+        let decoder_clone = decoder.clone();
         let (enum_tx, receiver) = broadcast::channel(512);
-        let running = Arc::new(AtomicBool::new(true));
-        let running_clone = running.clone();
+        let (state_tx, state) = watch::channel(ConnectionState::Connected);
+        let shutdown = CancellationToken::new();
+        let shutdown_clone = shutdown.clone();
         let abort_handle = tokio::spawn(async move {
-            loop {
-                let mut header_buffer = Box::new([0; PacketHeader::SIZE]);
-                match rd.read_exact(header_buffer.deref_mut()).await {
-                    Ok(8) => {
-                        let header = PacketHeader::from_le_byte_slice(header_buffer.deref());
-                        let body_size = header.length as usize - PacketHeader::SIZE;
-                        let mut body = vec![0; body_size].into_boxed_slice();
-                        match rd.read_exact(body.deref_mut()).await {
-                            Ok(l) if l == body_size => {}
-                            Ok(l) => {
-                                panic!("Unexpected body size: {}", l)
-                            }
-                            Err(e) => panic!("Error from socket: {}", e),
-                        }
-                        let packet_data = PacketData { header, body };
-                        debug!("Received: {packet_data:?}");
-                        if let Err(error) = enum_tx.send(Some(packet_data)) {
-                            warn!("Cannot process packet from {addr:?}: {error}");
-                            break;
-                        }
-                    }
-                    Ok(n) => {
-                        error!("Unexpected read count from {addr:?}: {}", n);
-                        if let Err(error) = enum_tx.send(None) {
-                            warn!("Cannot close connection on read error: {error}");
-                        }
-                        break;
-                    }
-                    Err(e) => {
-                        error!("Error from socket {addr:?}: {e}");
-                        if let Err(error) = enum_tx.send(None) {
-                            warn!("Cannot close connection on communication error: {error}");
-                        }
-                        break;
-                    }
+            let reason = loop {
+                let receive = Self::receive_packets(
+                    &mut rd,
+                    &enum_tx,
+                    &dispatcher_clone,
+                    &capture_clone,
+                    &decoder_clone,
+                    &transport,
+                );
+                let reason = tokio::select! {
+                    reason = receive => reason,
+                    _ = shutdown_clone.cancelled() => break DisconnectReason::Requested,
+                };
+                dispatcher_clone.cancel_all();
+                let Some(policy) = reconnect_policy.filter(|_| reason != DisconnectReason::Closed)
+                else {
+                    break reason;
+                };
+                let reconnect = Self::reconnect(&transport, &policy, &secret_clone, &state_tx);
+                let reconnected = tokio::select! {
+                    socket = reconnect => socket,
+                    _ = shutdown_clone.cancelled() => break DisconnectReason::Requested,
                 };
+                let Some(socket) = reconnected else {
+                    break DisconnectReason::ReconnectFailed;
+                };
+                let (new_rd, new_write_stream) = io::split(socket);
+                rd = new_rd;
+                *write_stream_clone.lock().await = Box::new(new_write_stream);
+                state_tx.send_replace(ConnectionState::Connected);
+                info!("Reconnected to {transport:?}");
+            };
+            if let Err(error) = enum_tx.send(None) {
+                warn!("Cannot close connection: {error}");
             }
-            running_clone.store(false, Ordering::Relaxed);
+            state_tx.send_replace(ConnectionState::Disconnected(reason));
             info!("Terminated receiver thread");
         })
         .abort_handle();
         Ok(Self {
             write_stream,
+            secret,
+            dispatcher,
+            capture,
+            decoder,
             abort_handle,
-            seq_num: 1,
             receiver,
-            running,
+            state,
+            shutdown,
         })
     }
 
-    fn enable_keepalive(socket: &TcpStream) -> Result<(), TinkerforgeError> {
-        let mut ka = socket2::TcpKeepalive::new();
-        ka = ka.with_time(Duration::from_secs(20));
-        ka = ka.with_interval(Duration::from_secs(20));
-        socket2::SockRef::from(&socket).set_tcp_keepalive(&ka)?;
+    async fn disconnect(&self) -> Result<(), TinkerforgeError> {
+        self.shutdown.cancel();
+        self.dispatcher.close();
+        let mut write_stream = self.write_stream.lock().await;
+        Self::ignore_closed_by_peer(write_stream.flush().await)?;
+        Self::ignore_closed_by_peer(write_stream.shutdown().await)?;
+        Ok(())
+    }
+
+    /// A socket the peer already closed needs no shutdown anymore.
+    fn ignore_closed_by_peer(result: io::Result<()>) -> io::Result<()> {
+        match result {
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    io::ErrorKind::NotConnected
+                        | io::ErrorKind::BrokenPipe
+                        | io::ErrorKind::ConnectionReset
+                ) =>
+            {
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
+    /// Hands received packets to the waiting requests and forwards them to the broadcast channel
+    /// until the socket fails or there is no receiver left.
+    async fn receive_packets<R: AsyncRead + Unpin, T: Debug>(
+        rd: &mut R,
+        enum_tx: &broadcast::Sender<Option<PacketData>>,
+        dispatcher: &Dispatcher,
+        capture: &CaptureTap,
+        decoder: &std::sync::Mutex<Option<PacketDecoder>>,
+        transport: &T,
+    ) -> DisconnectReason {
+        loop {
+            match PacketData::read_from(rd).await {
+                Ok(packet_data) => {
+                    match decoder.lock().expect("Decoder lock poisoned").as_mut() {
+                        Some(decoder) => {
+                            decoder.learn(&packet_data);
+                            debug!(
+                                "Received: {}",
+                                decoder.decode(&packet_data, Direction::Received)
+                            );
+                        }
+                        None => debug!("Received: {packet_data:?}"),
+                    }
+                    capture.record(Direction::Received, || packet_data.clone());
+                    dispatcher.dispatch(&packet_data);
+                    if let Err(error) = enum_tx.send(Some(packet_data)) {
+                        warn!("Cannot process packet from {transport:?}: {error}");
+                        return DisconnectReason::Closed;
+                    }
+                }
+                Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                    error!("Malformed packet from {transport:?}: {e}");
+                    return DisconnectReason::MalformedPacket;
+                }
+                Err(e) => {
+                    error!("Error from socket {transport:?}: {e}");
+                    return DisconnectReason::ConnectionLost(e.kind());
+                }
+            };
+        }
+    }
+
+    async fn reconnect<T: Transport>(
+        transport: &T,
+        policy: &ReconnectPolicy,
+        secret: &Mutex<Option<Box<str>>>,
+        state: &watch::Sender<ConnectionState>,
+    ) -> Option<T::Stream> {
+        let mut attempt = 0;
+        loop {
+            if policy
+                .max_attempts
+                .map(|max_attempts| attempt >= max_attempts)
+                .unwrap_or(false)
+            {
+                error!("Giving up reconnecting to {transport:?} after {attempt} attempts");
+                return None;
+            }
+            state.send_replace(ConnectionState::Reconnecting {
+                attempt: attempt + 1,
+            });
+            tokio::time::sleep(policy.backoff(attempt)).await;
+            attempt += 1;
+            state.send_replace(ConnectionState::Connecting);
+            match transport.connect().await {
+                Ok(mut socket) => {
+                    if let Some(secret) = secret.lock().await.as_deref() {
+                        if let Err(error) = Self::authenticate_socket(&mut socket, secret).await {
+                            warn!(
+                                "Cannot authenticate reconnected socket to {transport:?}: {error}"
+                            );
+                            continue;
+                        }
+                    }
+                    return Some(socket);
+                }
+                Err(error) => {
+                    warn!("Reconnect attempt {attempt} to {transport:?} failed: {error}");
+                }
+            }
+        }
+    }
+
+    /// Runs the authentication handshake directly on a freshly connected socket,
+    /// before the receiver task takes over reading from it.
+    async fn authenticate_socket<S: AsyncRead + AsyncWrite + Unpin>(
+        socket: &mut S,
+        secret: &str,
+    ) -> Result<(), TinkerforgeError> {
+        if !secret.is_ascii() {
+            return Err(AuthenticateError::SecretInvalid.into());
+        }
+        let nonce_request = Request::Get {
+            uid: Uid::zero(),
+            function_id: 1,
+            payload: &[],
+        };
+        socket.write_all(&nonce_request.to_bytes(true, 1)).await?;
+        socket.flush().await?;
+        let nonce_response = Self::read_response(socket, 1, 1).await?;
+        if nonce_response.body.len() != ServerNonce::bytes_expected() {
+            return Err(AuthenticateError::CouldNotGetServerNonce.into());
+        }
+        let payload = authentication_payload(
+            secret,
+            &ServerNonce::from_le_byte_slice(&nonce_response.body),
+        )?;
+        let authenticate_request = Request::Set {
+            uid: Uid::zero(),
+            function_id: 2,
+            payload: &payload,
+        };
+        socket
+            .write_all(&authenticate_request.to_bytes(true, 2))
+            .await?;
+        socket.flush().await?;
+        Self::read_response(socket, 2, 2).await?;
         Ok(())
     }
+
+    async fn read_response<R: AsyncRead + Unpin>(
+        socket: &mut R,
+        function_id: u8,
+        seq: u8,
+    ) -> Result<PacketData, TinkerforgeError> {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let packet = PacketData::read_from(socket).await?;
+                let header = packet.header;
+                if header.uid == Uid::zero()
+                    && header.function_id == function_id
+                    && header.sequence_number == seq
+                {
+                    return packet.check_error_code();
+                }
+            }
+        })
+        .await
+        .map_err(|_| TinkerforgeError::NoResponseReceived)?
+    }
+
     pub async fn enumerate(
-        &mut self,
+        &self,
     ) -> Result<impl Stream<Item = EnumerateResponse>, TinkerforgeError> {
-        if !self.running.as_ref().load(Ordering::Relaxed) {
-            return Ok(Either::Left(empty()));
-        }
         let request = Request::Set {
             uid: Uid::zero(),
             function_id: 254,
             payload: &[],
         };
-        let stream = BroadcastStream::new(self.receiver.resubscribe())
+        let stream = self.enumeration_stream()?;
+        self.send_packet(&request, self.dispatcher.next_seq(), true)
+            .await?;
+        Ok(stream)
+    }
+
+    fn enumeration_stream(
+        &self,
+    ) -> Result<impl Stream<Item = EnumerateResponse>, TinkerforgeError> {
+        if matches!(*self.state.borrow(), ConnectionState::Disconnected(_)) {
+            return Err(TinkerforgeError::ConnectionClosed);
+        }
+        Ok(BroadcastStream::new(self.receiver.resubscribe())
             .map_while(Self::while_some)
-            .filter_map(EnumerateResponse::extract_enumeration_packet);
-        let seq = self.next_seq();
-        self.send_packet(&request, seq, true).await?;
-        Ok(Either::Right(stream))
+            .filter_map(EnumerateResponse::extract_enumeration_packet))
     }
 
-    pub async fn disconnect_probe(&mut self) -> Result<(), TinkerforgeError> {
+    async fn enumerate_all(
+        &self,
+        quiet_period: Duration,
+    ) -> Result<Vec<EnumerateResponse>, TinkerforgeError> {
+        let enumerations = self.enumerate().await?;
+        tokio::pin!(enumerations);
+        let mut devices = BTreeMap::new();
+        while let Ok(Some(response)) = tokio::time::timeout(quiet_period, enumerations.next()).await
+        {
+            if response.enumeration_type == EnumerationType::Disconnected {
+                devices.remove(&response.uid);
+            } else {
+                devices.insert(response.uid, response);
+            }
+        }
+        Ok(devices.into_values().collect())
+    }
+
+    pub async fn disconnect_probe(&self) -> Result<(), TinkerforgeError> {
         let request = Request::Set {
             uid: Uid::zero(),
             function_id: 128,
             payload: &[],
         };
-        let seq = self.next_seq();
-        self.send_packet(&request, seq, true).await?;
+        self.send_packet(&request, self.dispatcher.next_seq(), true)
+            .await?;
         Ok(())
     }
-    async fn get_authentication_nonce(&mut self) -> Result<[u8; 4], TinkerforgeError> {
-        let request = Request::Set {
-            uid: Uid::zero(),
-            function_id: 1,
-            payload: &[],
-        };
-        let seq = self.next_seq();
-        let stream = BroadcastStream::new(self.receiver.resubscribe())
-            .map_while(Self::while_some)
-            .timeout(Duration::from_secs(5));
-        self.send_packet(&request, seq, true).await?;
-        tokio::pin!(stream);
-        let option = stream.next().await;
-        info!("Paket: {option:?}");
-        if let Some(Ok(Ok(next_paket))) = option {
-            let body = next_paket.body;
-            if body.len() == 4 {
-                let mut ret = [0; 4];
-                ret.copy_from_slice(&body);
-                Ok(ret)
-            } else {
-                todo!()
-            }
+    async fn get_authentication_nonce(&self) -> Result<[u8; 4], TinkerforgeError> {
+        let response = self
+            .get(Uid::zero(), 1, &[], Duration::from_secs(5))
+            .await
+            .map_err(|_| AuthenticateError::CouldNotGetServerNonce)?;
+        if response.body.len() == ServerNonce::bytes_expected() {
+            Ok(ServerNonce::from_le_byte_slice(&response.body).0)
         } else {
-            todo!()
+            Err(AuthenticateError::CouldNotGetServerNonce.into())
+        }
+    }
+    async fn authenticate(&self, secret: &str) -> Result<(), TinkerforgeError> {
+        if !secret.is_ascii() {
+            return Err(AuthenticateError::SecretInvalid.into());
         }
+        let server_nonce = ServerNonce(self.get_authentication_nonce().await?);
+        let payload = authentication_payload(secret, &server_nonce)?;
+        self.set(Uid::zero(), 2, &payload, Some(Duration::from_secs(5)))
+            .await?;
+        *self.secret.lock().await = Some(secret.into());
+        Ok(())
     }
     pub async fn set(
-        &mut self,
+        &self,
         uid: Uid,
         function_id: u8,
         payload: &[u8],
@@ -255,44 +608,19 @@ impl InnerAsyncIpConnection {
             function_id,
             payload,
         };
-        let seq = self.next_seq();
         if let Some(timeout) = timeout {
-            let stream = BroadcastStream::new(self.receiver.resubscribe())
-                .map_while(Self::while_some)
-                .filter(Self::filter_response(uid, function_id, seq))
-                .timeout(timeout);
-            self.send_packet(&request, seq, true).await?;
-            tokio::pin!(stream);
-            if let Some(done) = stream.next().await {
-                Ok(Some(
-                    done.map_err(|_| TinkerforgeError::NoResponseReceived)??,
-                ))
-            } else {
-                Err(TinkerforgeError::NoResponseReceived)
-            }
+            let mut response = self.dispatcher.register(uid, function_id).await?;
+            self.send_packet(&request, response.sequence_number(), true)
+                .await?;
+            Ok(Some(response.receive(timeout).await?.check_error_code()?))
         } else {
-            self.send_packet(&request, seq, false).await?;
+            self.send_packet(&request, self.dispatcher.next_seq(), false)
+                .await?;
             Ok(None)
         }
     }
-
-    fn filter_response(
-        uid: Uid,
-        function_id: u8,
-        seq: u8,
-    ) -> impl Fn(&Result<PacketData, BroadcastStreamRecvError>) -> bool {
-        move |result| {
-            if let Ok(PacketData { header, .. }) = result {
-                header.uid == uid
-                    && header.function_id == function_id
-                    && header.sequence_number == seq
-            } else {
-                false
-            }
-        }
-    }
     pub async fn get(
-        &mut self,
+        &self,
         uid: Uid,
         function_id: u8,
         payload: &[u8],
@@ -303,18 +631,10 @@ impl InnerAsyncIpConnection {
             function_id,
             payload,
         };
-        let seq = self.next_seq();
-        let stream = BroadcastStream::new(self.receiver.resubscribe())
-            .map_while(Self::while_some)
-            .filter(Self::filter_response(uid, function_id, seq))
-            .timeout(timeout);
-        tokio::pin!(stream);
-        self.send_packet(&request, seq, true).await?;
-        Ok(stream
-            .next()
-            .await
-            .ok_or(TinkerforgeError::NoResponseReceived)?
-            .map_err(|_| TinkerforgeError::NoResponseReceived)??)
+        let mut response = self.dispatcher.register(uid, function_id).await?;
+        self.send_packet(&request, response.sequence_number(), true)
+            .await?;
+        response.receive(timeout).await?.check_error_code()
     }
 
     fn while_some(
@@ -327,7 +647,7 @@ impl InnerAsyncIpConnection {
         }
     }
     pub async fn callback_stream(
-        &mut self,
+        &self,
         uid: Uid,
         function_id: u8,
     ) -> impl Stream<Item = PacketData> {
@@ -338,16 +658,14 @@ impl InnerAsyncIpConnection {
 
                     if header.uid == uid && header.function_id == function_id {
                         Some(Some(p))
-                    } else if header.function_id == 253 {
-                        if let Ok(enum_paket) =
-                            Result::<EnumerateResponse, Base58Error>::from_le_byte_slice(p.body())
+                    } else if let Some(enum_paket) =
+                        EnumerateResponse::extract_enumeration_packet(Ok(p))
+                    {
+                        if enum_paket.uid == uid
+                            && enum_paket.enumeration_type == EnumerationType::Disconnected
                         {
-                            if enum_paket.uid == uid {
-                                // device is disconnected -> end stream
-                                None
-                            } else {
-                                Some(None)
-                            }
+                            // device is disconnected -> end stream
+                            None
                         } else {
                             Some(None)
                         }
@@ -364,33 +682,34 @@ impl InnerAsyncIpConnection {
             .filter_map(|f| f)
     }
     async fn send_packet(
-        &mut self,
+        &self,
         request: &Request<'_>,
         seq: u8,
         response_expected: bool,
     ) -> Result<(), TinkerforgeError> {
-        let header = request.get_header(response_expected, seq);
-        assert!(header.length <= 72);
-        let mut result = vec![0; header.length as usize];
-        header.uid.write_to_slice(&mut result[0..4]);
-        result[4] = header.length;
-        result[5] = header.function_id;
-        result[6] = header.sequence_number << 4 | (header.response_expected as u8) << 3;
-        result[7] = header.error_code << 6;
-        let payload = request.get_payload();
-        if !payload.is_empty() {
-            result[8..].copy_from_slice(payload);
+        if self.shutdown.is_cancelled() {
+            return Err(TinkerforgeError::ConnectionClosed);
         }
-        self.write_stream.write_all(&result[..]).await?;
-        debug!("Sent: {request:?}");
-        Ok(())
-    }
-    fn next_seq(&mut self) -> u8 {
-        self.seq_num += 1;
-        if self.seq_num > 15 {
-            self.seq_num = 1;
+        let result = request.to_bytes(response_expected, seq);
+        let packet = || {
+            PacketData::new(
+                PacketHeader::from_le_byte_slice(&result),
+                &result[PacketHeader::SIZE..],
+            )
+        };
+        let mut write_stream = self.write_stream.lock().await;
+        // recorded before writing, otherwise the reader task could record the response first
+        self.capture.record(Direction::Sent, packet);
+        write_stream.write_all(&result[..]).await?;
+        // message based transports only send on flush
+        write_stream.flush().await?;
+        match self.decoder.lock().expect("Decoder lock poisoned").as_ref() {
+            Some(decoder) if log_enabled!(Level::Debug) => {
+                debug!("Sent: {}", decoder.decode(&packet(), Direction::Sent))
+            }
+            _ => debug!("Sent: {request:?}"),
         }
-        self.seq_num
+        Ok(())
     }
 }
 
@@ -407,13 +726,51 @@ pub struct PacketData {
 }
 
 impl PacketData {
-    #[allow(dead_code)]
+    pub fn new(header: PacketHeader, body: impl Into<Box<[u8]>>) -> Self {
+        Self {
+            header,
+            body: body.into(),
+        }
+    }
+    /// Reads one packet, fails with [`io::ErrorKind::InvalidData`] if its length field is invalid.
+    pub(crate) async fn read_from<R: AsyncRead + Unpin>(rd: &mut R) -> io::Result<PacketData> {
+        let mut header_buffer = Box::new([0; PacketHeader::SIZE]);
+        rd.read_exact(header_buffer.deref_mut()).await?;
+        let header = PacketHeader::from_le_byte_slice(header_buffer.deref());
+        let body_size = (header.length as usize)
+            .checked_sub(PacketHeader::SIZE)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Packet length {} is shorter than its header", header.length),
+                )
+            })?;
+        let mut body = vec![0; body_size].into_boxed_slice();
+        rd.read_exact(body.deref_mut()).await?;
+        Ok(PacketData { header, body })
+    }
+    /// Serializes header and body as they are sent over the wire.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0; PacketHeader::SIZE + self.body.len()];
+        self.header.write_to_slice(&mut bytes[..PacketHeader::SIZE]);
+        bytes[PacketHeader::SIZE..].copy_from_slice(&self.body);
+        bytes
+    }
     pub fn header(&self) -> PacketHeader {
         self.header
     }
     pub fn body(&self) -> &[u8] {
         &self.body
     }
+    /// Turns an error code reported by the device into the matching [`TinkerforgeError`].
+    pub fn check_error_code(self) -> Result<Self, TinkerforgeError> {
+        match self.header.error_code() {
+            ErrorCode::Success => Ok(self),
+            ErrorCode::InvalidParameter => Err(TinkerforgeError::InvalidParameter),
+            ErrorCode::FunctionNotSupported => Err(TinkerforgeError::FunctionNotSupported),
+            ErrorCode::Unknown => Err(TinkerforgeError::UnknownError),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -464,4 +821,348 @@ impl Request<'_> {
             Request::Get { payload, .. } => payload,
         }
     }
+    fn to_bytes(&self, response_expected: bool, sequence_number: u8) -> Vec<u8> {
+        let header = self.get_header(response_expected, sequence_number);
+        assert!(header.length <= 72);
+        let mut result = vec![0; header.length as usize];
+        header.write_to_slice(&mut result[0..PacketHeader::SIZE]);
+        let payload = self.get_payload();
+        if !payload.is_empty() {
+            result[PacketHeader::SIZE..].copy_from_slice(payload);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use hmac::{Hmac, Mac};
+    use sha1::Sha1;
+    use tokio::{
+        io::{self, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use tokio_stream::StreamExt;
+
+    use crate::{
+        base58::Uid,
+        byte_converter::ToBytes,
+        error::TinkerforgeError,
+        ip_connection::{
+            async_io::{
+                AsyncIpConnection, ConnectionState, DisconnectReason, InnerAsyncIpConnection,
+                PacketData, ReconnectPolicy,
+            },
+            transport::StreamTransport,
+            AuthenticateError, EnumerationType, PacketHeader,
+        },
+        mock::{MockBrickd, MockDevice},
+    };
+
+    async fn respond<W: AsyncWrite + Unpin>(socket: &mut W, request: PacketHeader, payload: &[u8]) {
+        let mut packet = vec![0; PacketHeader::SIZE + payload.len()];
+        PacketHeader {
+            length: (PacketHeader::SIZE + payload.len()) as u8,
+            ..request
+        }
+        .write_to_slice(&mut packet);
+        packet[PacketHeader::SIZE..].copy_from_slice(payload);
+        socket.write_all(&packet).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_closed_reports_reason() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (connection, socket) = tokio::join!(AsyncIpConnection::new(addr), listener.accept());
+        let connection = connection.unwrap();
+        assert!(connection.is_connected());
+        drop(socket.unwrap());
+        let reason = tokio::time::timeout(Duration::from_secs(5), connection.closed())
+            .await
+            .unwrap();
+        assert_eq!(
+            reason,
+            DisconnectReason::ConnectionLost(io::ErrorKind::UnexpectedEof)
+        );
+        assert!(!connection.is_connected());
+    }
+
+    #[tokio::test]
+    async fn test_disconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (connection, socket) = tokio::join!(AsyncIpConnection::new(addr), listener.accept());
+        let connection = connection.unwrap();
+        let (mut socket, _) = socket.unwrap();
+        let uid = Uid::from(42);
+        let stream = connection.callback_stream(uid, 7).await;
+        let pending = {
+            let connection = connection.clone();
+            tokio::spawn(async move { connection.get(uid, 3, &[], Duration::from_secs(5)).await })
+        };
+        PacketData::read_from(&mut socket).await.unwrap();
+
+        connection.disconnect().await.unwrap();
+        assert!(matches!(
+            pending.await.unwrap(),
+            Err(TinkerforgeError::ConnectionClosed)
+        ));
+        assert_eq!(stream.collect::<Vec<_>>().await.len(), 0);
+        assert_eq!(
+            connection.state(),
+            ConnectionState::Disconnected(DisconnectReason::Requested)
+        );
+        assert!(matches!(
+            connection.get(uid, 3, &[], Duration::from_secs(5)).await,
+            Err(TinkerforgeError::ConnectionClosed)
+        ));
+        assert!(PacketData::read_from(&mut socket).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_after_connection_lost() {
+        let brickd = MockBrickd::start().await.unwrap();
+        let connection = brickd.connect().await.unwrap();
+        brickd.disconnect_clients();
+        let reason = tokio::time::timeout(Duration::from_secs(5), connection.closed())
+            .await
+            .unwrap();
+        assert!(matches!(reason, DisconnectReason::ConnectionLost(_)));
+        // the mock has closed the whole socket, the write is answered with a reset
+        connection.set(Uid::from(42), 3, &[], None).await.unwrap();
+        connection.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_malformed_packet_closes_connection() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (connection, socket) = tokio::join!(AsyncIpConnection::new(addr), listener.accept());
+        let connection = connection.unwrap();
+        let (mut socket, _) = socket.unwrap();
+        let mut packet = [0; PacketHeader::SIZE];
+        PacketHeader::with_payload(Uid::from(42), 7, 0, false, 0).write_to_slice(&mut packet);
+        // length smaller than the header itself
+        packet[4] = 3;
+        socket.write_all(&packet).await.unwrap();
+        let reason = tokio::time::timeout(Duration::from_secs(5), connection.closed())
+            .await
+            .unwrap();
+        assert_eq!(reason, DisconnectReason::MalformedPacket);
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_pipelined() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let first = PacketData::read_from(&mut socket).await.unwrap();
+            let second = PacketData::read_from(&mut socket).await.unwrap();
+            assert_ne!(first.header.sequence_number, second.header.sequence_number);
+            // answer in reverse order, each request has to get its own response
+            respond(
+                &mut socket,
+                second.header,
+                &[u32::from(second.header.uid) as u8],
+            )
+            .await;
+            respond(
+                &mut socket,
+                first.header,
+                &[u32::from(first.header.uid) as u8],
+            )
+            .await;
+        });
+        let connection = AsyncIpConnection::new(addr).await.unwrap();
+        let (first, second) = tokio::join!(
+            connection.get(Uid::from(1), 3, &[], Duration::from_secs(5)),
+            connection.get(Uid::from(2), 3, &[], Duration::from_secs(5))
+        );
+        assert_eq!(first.unwrap().body(), &[1]);
+        assert_eq!(second.unwrap().body(), &[2]);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_late_response_is_not_reused() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let first = PacketData::read_from(&mut socket).await.unwrap();
+            let second = PacketData::read_from(&mut socket).await.unwrap();
+            assert_ne!(first.header.sequence_number, second.header.sequence_number);
+            respond(&mut socket, first.header, &[1]).await;
+            respond(&mut socket, second.header, &[2]).await;
+        });
+        let connection = AsyncIpConnection::new(addr).await.unwrap();
+        let uid = Uid::from(42);
+        assert!(matches!(
+            connection.get(uid, 3, &[], Duration::from_millis(50)).await,
+            Err(TinkerforgeError::NoResponseReceived)
+        ));
+        let response = connection
+            .get(uid, 3, &[], Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(response.body(), &[2]);
+        assert_eq!(connection.stale_responses(), 1);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_get_reports_error_code() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let request = PacketData::read_from(&mut socket).await.unwrap();
+            let mut header = request.header;
+            header.error_code = 2;
+            respond(&mut socket, header, &[]).await;
+        });
+        let connection = AsyncIpConnection::new(addr).await.unwrap();
+        assert!(matches!(
+            connection
+                .get(Uid::from(42), 3, &[], Duration::from_secs(5))
+                .await,
+            Err(TinkerforgeError::FunctionNotSupported)
+        ));
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_transport() {
+        let (client, mut server) = io::duplex(1024);
+        let connection = AsyncIpConnection::with_transport(StreamTransport::new(client), None)
+            .await
+            .unwrap();
+        let server = tokio::spawn(async move {
+            let request = PacketData::read_from(&mut server).await.unwrap();
+            assert_eq!(request.header.uid, Uid::from(42));
+            assert_eq!(request.header.function_id, 3);
+            respond(&mut server, request.header, &[7, 8]).await;
+        });
+        let response = connection
+            .get(Uid::from(42), 3, &[], Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(response.body(), &[7, 8]);
+        server.await.unwrap();
+        let reason = tokio::time::timeout(Duration::from_secs(5), connection.closed())
+            .await
+            .unwrap();
+        assert_eq!(
+            reason,
+            DisconnectReason::ConnectionLost(io::ErrorKind::UnexpectedEof)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_authenticate() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let server_nonce = [1, 2, 3, 4];
+            // the invalid secret is rejected before anything is sent, a nonce request of its own would be
+            // answered here and make the authenticate request below a second nonce request
+            let nonce_request = PacketData::read_from(&mut socket).await.unwrap();
+            assert_eq!(nonce_request.header.function_id, 1);
+            respond(&mut socket, nonce_request.header, &server_nonce).await;
+            let authenticate_request = PacketData::read_from(&mut socket).await.unwrap();
+            assert_eq!(authenticate_request.header.function_id, 2);
+            let (client_nonce, digest) = authenticate_request.body.split_at(4);
+            let mut mac = Hmac::<Sha1>::new_from_slice(b"secret").unwrap();
+            mac.update(&server_nonce);
+            mac.update(client_nonce);
+            mac.verify_slice(digest).unwrap();
+            respond(&mut socket, authenticate_request.header, &[]).await;
+        });
+        let connection = AsyncIpConnection::new(addr).await.unwrap();
+        assert!(matches!(
+            connection.authenticate("gehéim").await,
+            Err(TinkerforgeError::AuthenticationError(
+                AuthenticateError::SecretInvalid
+            ))
+        ));
+        connection.authenticate("secret").await.unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_socket_invalid_secret() {
+        let (mut client, mut server) = io::duplex(1024);
+        assert!(matches!(
+            InnerAsyncIpConnection::authenticate_socket(&mut client, "gehéim").await,
+            Err(TinkerforgeError::AuthenticationError(
+                AuthenticateError::SecretInvalid
+            ))
+        ));
+        drop(client);
+        let mut sent = Vec::new();
+        server.read_to_end(&mut sent).await.unwrap();
+        assert!(sent.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_enumerate_all() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("6DyH5n", 13));
+        brickd.add_device(MockDevice::new("R4c", 298).connected_to("6DyH5n", 'a'));
+        let connection = brickd.connect().await.unwrap();
+        let (devices, _) = tokio::join!(
+            connection.enumerate_all(Duration::from_millis(200)),
+            async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                brickd.emit_enumerate("R4c", EnumerationType::Connected);
+            }
+        );
+        let devices = devices.unwrap();
+        let mut expected = [Uid::from("6DyH5n"), Uid::from("R4c")];
+        expected.sort();
+        let uids: Vec<_> = devices.iter().map(|device| device.uid).collect();
+        assert_eq!(uids, expected);
+        let bricklet = devices.iter().find(|device| device.uid == Uid::from("R4c"));
+        assert_eq!(
+            bricklet.unwrap().enumeration_type,
+            EnumerationType::Connected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_callback_stream_survives_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(50),
+            max_attempts: Some(10),
+        };
+        let (connection, first) = tokio::join!(
+            AsyncIpConnection::new_with_reconnect(addr, policy),
+            listener.accept()
+        );
+        let connection = connection.unwrap();
+        let uid = Uid::from(42);
+        let stream = connection.callback_stream(uid, 7).await;
+        tokio::pin!(stream);
+        drop(first.unwrap());
+
+        let (mut second, _) = listener.accept().await.unwrap();
+        let mut packet = [0; PacketHeader::SIZE + 1];
+        PacketHeader::with_payload(uid, 7, 0, false, 1).write_to_slice(&mut packet);
+        packet[PacketHeader::SIZE] = 23;
+        second.write_all(&packet).await.unwrap();
+
+        let received = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(received.body(), &[23]);
+    }
 }
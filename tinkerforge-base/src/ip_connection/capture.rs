@@ -0,0 +1,376 @@
+//! Recording of the packets on a connection and replaying them as a fake peer.
+//!
+//! A capture starts with [`MAGIC`], followed by one record per packet: the timestamp in
+//! microseconds since the unix epoch (u64 LE), the [`Direction`] (u8) and the raw packet.
+use std::{
+    collections::HashMap,
+    io,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::{debug, warn};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, DuplexStream},
+    sync::mpsc,
+    task::JoinHandle,
+    time::Instant,
+};
+
+use crate::{
+    base58::Uid,
+    ip_connection::{
+        async_io::PacketData,
+        pcap::PcapWriter,
+        transport::{ConnectFuture, Transport},
+        PacketHeader,
+    },
+};
+
+/// Marks the start of a capture file.
+pub const MAGIC: &[u8; 8] = b"TFCAPT01";
+
+/// Whether a packet was sent by this side of the connection or received from the peer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Sent,
+    Received,
+}
+
+/// A packet seen on the connection.
+#[derive(Clone, Debug)]
+pub struct CapturedPacket {
+    pub timestamp: SystemTime,
+    pub direction: Direction,
+    pub packet: PacketData,
+}
+
+impl CapturedPacket {
+    pub async fn write_to<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> io::Result<()> {
+        let micros = self
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_micros() as u64;
+        writer.write_all(&micros.to_le_bytes()).await?;
+        writer
+            .write_u8(match self.direction {
+                Direction::Sent => 0,
+                Direction::Received => 1,
+            })
+            .await?;
+        writer.write_all(&self.packet.to_bytes()).await
+    }
+
+    /// Reads the next record, returns `None` at the end of the capture.
+    pub async fn read_from<R: AsyncRead + Unpin>(reader: &mut R) -> io::Result<Option<Self>> {
+        let mut micros = [0; 8];
+        match reader.read_exact(&mut micros).await {
+            Ok(_) => {}
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(error) => return Err(error),
+        }
+        let direction = match reader.read_u8().await? {
+            0 => Direction::Sent,
+            1 => Direction::Received,
+            other => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Invalid direction {other} in capture"),
+                ))
+            }
+        };
+        Ok(Some(CapturedPacket {
+            timestamp: UNIX_EPOCH + Duration::from_micros(u64::from_le_bytes(micros)),
+            direction,
+            packet: PacketData::read_from(reader).await?,
+        }))
+    }
+}
+
+/// Reads a whole capture written by [`AsyncIpConnection::start_capture`](crate::ip_connection::async_io::AsyncIpConnection::start_capture).
+pub async fn read_capture<R: AsyncRead + Unpin>(reader: &mut R) -> io::Result<Vec<CapturedPacket>> {
+    let mut magic = [0; MAGIC.len()];
+    reader.read_exact(&mut magic).await?;
+    if &magic != MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "Not a packet capture",
+        ));
+    }
+    let mut packets = Vec::new();
+    while let Some(packet) = CapturedPacket::read_from(reader).await? {
+        packets.push(packet);
+    }
+    Ok(packets)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum CaptureFormat {
+    Native,
+    Pcap,
+}
+
+/// The point where the connection hands its packets to a running capture.
+#[derive(Debug, Default)]
+pub(crate) struct CaptureTap {
+    sender: Mutex<Option<mpsc::UnboundedSender<CapturedPacket>>>,
+}
+
+impl CaptureTap {
+    /// Records the packet if a capture is running, the packet is only built in that case.
+    pub(crate) fn record(&self, direction: Direction, packet: impl FnOnce() -> PacketData) {
+        let mut sender = self.sender.lock().expect("Capture lock poisoned");
+        if let Some(active) = sender.as_ref() {
+            let captured = CapturedPacket {
+                timestamp: SystemTime::now(),
+                direction,
+                packet: packet(),
+            };
+            if active.send(captured).is_err() {
+                *sender = None;
+            }
+        }
+    }
+
+    pub(crate) fn start<W: AsyncWrite + Send + Unpin + 'static>(
+        self: &Arc<Self>,
+        mut writer: W,
+        format: CaptureFormat,
+    ) -> CaptureHandle {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<CapturedPacket>();
+        *self.sender.lock().expect("Capture lock poisoned") = Some(sender.clone());
+        let task = tokio::spawn(async move {
+            match format {
+                CaptureFormat::Native => {
+                    writer.write_all(MAGIC).await?;
+                    while let Some(packet) = receiver.recv().await {
+                        packet.write_to(&mut writer).await?;
+                    }
+                    writer.flush().await
+                }
+                CaptureFormat::Pcap => {
+                    let mut pcap = PcapWriter::new(writer).await?;
+                    while let Some(packet) = receiver.recv().await {
+                        pcap.write_packet(&packet).await?;
+                    }
+                    pcap.flush().await
+                }
+            }
+        });
+        CaptureHandle {
+            tap: self.clone(),
+            sender: Some(sender),
+            task,
+        }
+    }
+}
+
+/// A running capture. Dropping it stops recording, [`stop`](CaptureHandle::stop) also waits
+/// until everything is written.
+#[derive(Debug)]
+pub struct CaptureHandle {
+    tap: Arc<CaptureTap>,
+    /// Identifies the channel of this capture, the tap may hold the one of a newer capture.
+    sender: Option<mpsc::UnboundedSender<CapturedPacket>>,
+    task: JoinHandle<io::Result<()>>,
+}
+
+impl CaptureHandle {
+    pub async fn stop(mut self) -> io::Result<()> {
+        self.detach();
+        (&mut self.task)
+            .await
+            .map_err(|error| io::Error::new(io::ErrorKind::Other, error))?
+    }
+
+    /// Stops recording unless a newer capture replaced this one, the channel closes once the
+    /// sender of this handle is dropped as well.
+    fn detach(&mut self) {
+        let Some(own) = self.sender.take() else {
+            return;
+        };
+        let mut sender = self.tap.sender.lock().expect("Capture lock poisoned");
+        if sender
+            .as_ref()
+            .is_some_and(|active| active.same_channel(&own))
+        {
+            *sender = None;
+        }
+    }
+}
+
+impl Drop for CaptureHandle {
+    fn drop(&mut self) {
+        self.detach();
+    }
+}
+
+/// Plays the peer side of a capture. Every request is matched with the next recorded request to
+/// the same uid and function, the packets received after it are sent back with their original
+/// delays. Sequence numbers of responses are rewritten to those of the new requests.
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    packets: Arc<[CapturedPacket]>,
+}
+
+impl ReplayTransport {
+    pub fn new(packets: impl Into<Arc<[CapturedPacket]>>) -> Self {
+        Self {
+            packets: packets.into(),
+        }
+    }
+}
+
+impl Transport for ReplayTransport {
+    type Stream = DuplexStream;
+
+    fn connect(&self) -> ConnectFuture<'_, DuplexStream> {
+        let (client, peer) = tokio::io::duplex(4096);
+        tokio::spawn(replay(self.packets.clone(), peer));
+        Box::pin(async move { Ok(client) })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+struct RequestKey {
+    uid: Uid,
+    function_id: u8,
+    sequence_number: u8,
+}
+
+impl From<PacketHeader> for RequestKey {
+    fn from(header: PacketHeader) -> Self {
+        RequestKey {
+            uid: header.uid,
+            function_id: header.function_id,
+            sequence_number: header.sequence_number,
+        }
+    }
+}
+
+async fn replay(packets: Arc<[CapturedPacket]>, mut peer: DuplexStream) {
+    // recorded sequence number -> sequence number of the replayed request
+    let mut sequence_numbers = HashMap::<RequestKey, u8>::new();
+    let mut cursor = 0;
+    let mut anchor = (Instant::now(), None::<SystemTime>);
+    loop {
+        while let Some(captured) = packets
+            .get(cursor)
+            .filter(|captured| captured.direction == Direction::Received)
+        {
+            if let Some(recorded_at) = anchor.1 {
+                let delay = captured
+                    .timestamp
+                    .duration_since(recorded_at)
+                    .unwrap_or_default();
+                tokio::time::sleep_until(anchor.0 + delay).await;
+            }
+            let mut header = captured.packet.header();
+            if let Some(sequence_number) = sequence_numbers.remove(&header.into()) {
+                header.sequence_number = sequence_number;
+            }
+            let packet = PacketData::new(header, captured.packet.body());
+            if peer.write_all(&packet.to_bytes()).await.is_err() {
+                return;
+            }
+            cursor += 1;
+        }
+        let Ok(request) = PacketData::read_from(&mut peer).await else {
+            debug!("Replay client disconnected");
+            return;
+        };
+        let header = request.header();
+        let matching = packets[cursor..].iter().position(|captured| {
+            let recorded = captured.packet.header();
+            captured.direction == Direction::Sent
+                && recorded.uid == header.uid
+                && recorded.function_id == header.function_id
+        });
+        let Some(offset) = matching else {
+            warn!("Request {header:?} is not part of the capture");
+            continue;
+        };
+        let recorded = &packets[cursor + offset];
+        let recorded_header = recorded.packet.header();
+        if recorded_header.sequence_number != 0 {
+            sequence_numbers.insert(recorded_header.into(), header.sequence_number);
+        }
+        anchor = (Instant::now(), Some(recorded.timestamp));
+        cursor += offset + 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio_stream::StreamExt;
+
+    use crate::{
+        base58::Uid,
+        ip_connection::{
+            async_io::AsyncIpConnection,
+            capture::{read_capture, Direction, ReplayTransport},
+        },
+        mock::{MockBrickd, MockDevice, MockResponse},
+    };
+
+    #[tokio::test]
+    async fn test_capture_and_replay() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("R4c", 298).respond(3, MockResponse::ok([1, 2, 3])));
+        let uid = Uid::from("R4c");
+        let timeout = Duration::from_secs(5);
+        let connection = brickd.connect().await.unwrap();
+        let (writer, mut reader) = tokio::io::duplex(4096);
+        let capture = connection.start_capture(writer);
+        let mut callbacks = connection.callback_stream(uid, 7).await;
+        connection.get(uid, 3, &[], timeout).await.unwrap();
+        brickd.emit_callback(uid, 7, &[4, 5]);
+        callbacks.next().await.unwrap();
+        capture.stop().await.unwrap();
+
+        let packets = read_capture(&mut reader).await.unwrap();
+        let directions = packets
+            .iter()
+            .map(|packet| packet.direction)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            directions,
+            [Direction::Sent, Direction::Received, Direction::Received]
+        );
+        assert_eq!(packets[0].packet.header().function_id(), 3);
+        assert_eq!(packets[2].packet.body(), &[4, 5]);
+
+        let replayed = AsyncIpConnection::with_transport(ReplayTransport::new(packets), None)
+            .await
+            .unwrap();
+        let mut callbacks = replayed.callback_stream(uid, 7).await;
+        // not part of the capture, shifts the sequence numbers of the following requests
+        replayed.set(uid, 9, &[], None).await.unwrap();
+        let response = replayed.get(uid, 3, &[], timeout).await.unwrap();
+        assert_eq!(response.body(), &[1, 2, 3]);
+        assert_eq!(callbacks.next().await.unwrap().body(), &[4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_replaced_capture_keeps_recording() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("R4c", 298).respond(3, MockResponse::ok([1, 2, 3])));
+        let connection = brickd.connect().await.unwrap();
+        let (first_writer, _first_reader) = tokio::io::duplex(4096);
+        let (second_writer, mut second_reader) = tokio::io::duplex(4096);
+        let first = connection.start_capture(first_writer);
+        let second = connection.start_capture(second_writer);
+        drop(first);
+        connection
+            .get(Uid::from("R4c"), 3, &[], Duration::from_secs(5))
+            .await
+            .unwrap();
+        second.stop().await.unwrap();
+
+        let packets = read_capture(&mut second_reader).await.unwrap();
+        assert_eq!(packets.len(), 2);
+    }
+}
@@ -0,0 +1,245 @@
+//! Routes responses back to the requests waiting for them, so several requests can be in flight on one connection.
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use log::warn;
+use tokio::{
+    sync::{oneshot, OwnedSemaphorePermit, Semaphore},
+    time::Instant,
+};
+
+use crate::{base58::Uid, error::TinkerforgeError, ip_connection::async_io::PacketData};
+
+/// The protocol offers 4 bits for the sequence number and 0 is reserved for callbacks.
+pub(crate) const MAX_REQUESTS_IN_FLIGHT: usize = 15;
+
+/// How long the sequence number of a timed out request is kept out of use, so a late response
+/// cannot be mistaken for the answer to a newer request.
+const STALE_QUARANTINE: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+struct ResponseKey {
+    uid: Uid,
+    function_id: u8,
+    sequence_number: u8,
+}
+
+#[derive(Debug)]
+pub(crate) struct Dispatcher {
+    state: Mutex<DispatcherState>,
+    slots: Arc<Semaphore>,
+    stale_responses: AtomicU64,
+    closed: AtomicBool,
+}
+
+#[derive(Debug, Default)]
+struct DispatcherState {
+    last_seq: u8,
+    last_request_id: u64,
+    pending: HashMap<ResponseKey, (u64, oneshot::Sender<PacketData>)>,
+    timed_out: HashMap<ResponseKey, Instant>,
+}
+
+impl DispatcherState {
+    fn advance_seq(&mut self) -> u8 {
+        self.last_seq = self.last_seq % MAX_REQUESTS_IN_FLIGHT as u8 + 1;
+        self.last_seq
+    }
+    fn is_in_use(&self, seq: u8) -> bool {
+        self.pending.keys().any(|key| key.sequence_number == seq)
+            || self.timed_out.keys().any(|key| key.sequence_number == seq)
+    }
+    /// Picks the next sequence number which is neither pending nor quarantined.
+    fn free_seq(&mut self, now: Instant) -> Option<u8> {
+        self.timed_out.retain(|_, expires| *expires > now);
+        for _ in 0..MAX_REQUESTS_IN_FLIGHT {
+            let seq = self.advance_seq();
+            if !self.is_in_use(seq) {
+                return Some(seq);
+            }
+        }
+        None
+    }
+}
+
+impl Dispatcher {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Mutex::new(DispatcherState::default()),
+            slots: Arc::new(Semaphore::new(MAX_REQUESTS_IN_FLIGHT)),
+            stale_responses: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
+        }
+    }
+
+    /// Waits for a free slot, reserves an unused sequence number and registers the response receiver.
+    pub(crate) async fn register(
+        self: &Arc<Self>,
+        uid: Uid,
+        function_id: u8,
+    ) -> Result<PendingResponse, TinkerforgeError> {
+        let permit = self
+            .slots
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|_| TinkerforgeError::ConnectionClosed)?;
+        loop {
+            let retry_at = {
+                let mut state = self.state.lock().expect("Dispatcher lock poisoned");
+                let now = Instant::now();
+                if let Some(sequence_number) = state.free_seq(now) {
+                    let key = ResponseKey {
+                        uid,
+                        function_id,
+                        sequence_number,
+                    };
+                    let (sender, receiver) = oneshot::channel();
+                    state.last_request_id += 1;
+                    let request_id = state.last_request_id;
+                    state.pending.insert(key, (request_id, sender));
+                    return Ok(PendingResponse {
+                        dispatcher: self.clone(),
+                        key,
+                        request_id,
+                        receiver,
+                        _permit: permit,
+                    });
+                }
+                // every free sequence number is quarantined, wait for the first one to expire
+                state
+                    .timed_out
+                    .values()
+                    .min()
+                    .copied()
+                    .unwrap_or(now + STALE_QUARANTINE)
+            };
+            tokio::time::sleep_until(retry_at).await;
+        }
+    }
+
+    /// Sequence number for a request whose response is not awaited, it is not reserved. Sequence
+    /// numbers of pending and quarantined requests are skipped, so an answer to this request cannot
+    /// be taken for theirs. Only if all of them are in use the next one is taken anyway.
+    pub(crate) fn next_seq(&self) -> u8 {
+        let mut state = self.state.lock().expect("Dispatcher lock poisoned");
+        match state.free_seq(Instant::now()) {
+            Some(seq) => seq,
+            None => state.advance_seq(),
+        }
+    }
+
+    /// Hands the packet to the request waiting for it. Returns `false` if nobody is waiting.
+    pub(crate) fn dispatch(&self, packet: &PacketData) -> bool {
+        let header = packet.header();
+        let key = ResponseKey {
+            uid: header.uid,
+            function_id: header.function_id,
+            sequence_number: header.sequence_number,
+        };
+        let mut state = self.state.lock().expect("Dispatcher lock poisoned");
+        if let Some((_, sender)) = state.pending.remove(&key) {
+            return sender.send(packet.clone()).is_ok();
+        }
+        if state.timed_out.remove(&key).is_some() {
+            let count = self.stale_responses.fetch_add(1, Ordering::Relaxed) + 1;
+            warn!("Dropped stale response to timed out request {key:?} ({count} in total)");
+        }
+        false
+    }
+
+    /// Drops all waiting requests, they fail immediately instead of running into their timeout.
+    pub(crate) fn cancel_all(&self) {
+        let mut state = self.state.lock().expect("Dispatcher lock poisoned");
+        state.pending.clear();
+        state.timed_out.clear();
+    }
+
+    /// Fails all waiting requests and all further registrations with [`TinkerforgeError::ConnectionClosed`].
+    pub(crate) fn close(&self) {
+        self.closed.store(true, Ordering::Relaxed);
+        self.slots.close();
+        self.cancel_all();
+    }
+
+    /// Number of responses which arrived after their request had already timed out.
+    pub(crate) fn stale_responses(&self) -> u64 {
+        self.stale_responses.load(Ordering::Relaxed)
+    }
+
+    fn remove(&self, key: &ResponseKey, request_id: u64) {
+        let mut state = self.state.lock().expect("Dispatcher lock poisoned");
+        // the key may already be reused by a newer request
+        if matches!(state.pending.get(key), Some((id, _)) if *id == request_id) {
+            state.pending.remove(key);
+            state
+                .timed_out
+                .insert(*key, Instant::now() + STALE_QUARANTINE);
+        }
+    }
+}
+
+/// A registered request waiting for its response. Dropping it releases the sequence number.
+pub(crate) struct PendingResponse {
+    dispatcher: Arc<Dispatcher>,
+    key: ResponseKey,
+    request_id: u64,
+    receiver: oneshot::Receiver<PacketData>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl PendingResponse {
+    pub(crate) fn sequence_number(&self) -> u8 {
+        self.key.sequence_number
+    }
+
+    pub(crate) async fn receive(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<PacketData, TinkerforgeError> {
+        tokio::time::timeout(timeout, &mut self.receiver)
+            .await
+            .map_err(|_| TinkerforgeError::NoResponseReceived)?
+            .map_err(|_| {
+                if self.dispatcher.closed.load(Ordering::Relaxed) {
+                    TinkerforgeError::ConnectionClosed
+                } else {
+                    TinkerforgeError::NoResponseReceived
+                }
+            })
+    }
+}
+
+impl Drop for PendingResponse {
+    fn drop(&mut self) {
+        self.dispatcher.remove(&self.key, self.request_id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Arc, time::Duration};
+
+    use crate::{base58::Uid, ip_connection::dispatcher::Dispatcher};
+
+    #[tokio::test]
+    async fn test_next_seq_skips_reserved() {
+        let dispatcher = Arc::new(Dispatcher::new());
+        let mut timed_out = dispatcher.register(Uid::from(1), 1).await.unwrap();
+        assert!(timed_out.receive(Duration::from_millis(10)).await.is_err());
+        let quarantined_seq = timed_out.sequence_number();
+        drop(timed_out);
+        let pending = dispatcher.register(Uid::from(1), 2).await.unwrap();
+        for _ in 0..30 {
+            let seq = dispatcher.next_seq();
+            assert_ne!(seq, quarantined_seq);
+            assert_ne!(seq, pending.sequence_number());
+        }
+    }
+}
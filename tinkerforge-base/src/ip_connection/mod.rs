@@ -4,6 +4,10 @@ use std::{
     str::{self, FromStr},
 };
 
+use hmac::{Hmac, Mac};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use sha1::Sha1;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
 
 use crate::ip_connection::async_io::PacketData;
@@ -13,6 +17,10 @@ use crate::{
 };
 
 pub mod async_io;
+pub mod capture;
+mod dispatcher;
+pub mod pcap;
+pub mod transport;
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct PacketHeader {
@@ -42,9 +50,71 @@ impl PacketHeader {
         }
     }
 
+    pub fn uid(&self) -> Uid {
+        self.uid
+    }
+
+    pub fn function_id(&self) -> u8 {
+        self.function_id
+    }
+
+    pub fn sequence_number(&self) -> u8 {
+        self.sequence_number
+    }
+
+    pub fn response_expected(&self) -> bool {
+        self.response_expected
+    }
+
+    pub fn error_code(&self) -> ErrorCode {
+        ErrorCode::from(self.error_code)
+    }
+
+    pub fn with_error_code(self, error_code: ErrorCode) -> PacketHeader {
+        PacketHeader {
+            error_code: error_code.into(),
+            ..self
+        }
+    }
+
     pub const SIZE: usize = 8;
 }
 
+/// Error code reported by a device in the header of a response.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ErrorCode {
+    /// The request was processed successfully.
+    Success,
+    /// The request contained an invalid parameter.
+    InvalidParameter,
+    /// The function is not supported by the device or its firmware.
+    FunctionNotSupported,
+    /// The device reported an unknown error.
+    Unknown,
+}
+
+impl From<u8> for ErrorCode {
+    fn from(code: u8) -> ErrorCode {
+        match code {
+            0 => ErrorCode::Success,
+            1 => ErrorCode::InvalidParameter,
+            2 => ErrorCode::FunctionNotSupported,
+            _ => ErrorCode::Unknown,
+        }
+    }
+}
+
+impl From<ErrorCode> for u8 {
+    fn from(code: ErrorCode) -> u8 {
+        match code {
+            ErrorCode::Success => 0,
+            ErrorCode::InvalidParameter => 1,
+            ErrorCode::FunctionNotSupported => 2,
+            ErrorCode::Unknown => 3,
+        }
+    }
+}
+
 impl FromByteSlice for PacketHeader {
     fn from_le_byte_slice(bytes: &[u8]) -> PacketHeader {
         PacketHeader {
@@ -108,6 +178,16 @@ pub struct Version {
     patch: u8,
 }
 
+impl Version {
+    pub const fn new(major: u8, minor: u8, patch: u8) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
 impl Display for Version {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
@@ -173,7 +253,11 @@ impl EnumerateResponse {
         p: Result<PacketData, BroadcastStreamRecvError>,
     ) -> Option<EnumerateResponse> {
         match p {
-            Ok(p) if p.header().function_id == 253 => {
+            Ok(p)
+                if p.header().function_id == 253
+                    && p.body().len()
+                        == <Result<EnumerateResponse, Base58Error>>::bytes_expected() =>
+            {
                 Result::<EnumerateResponse, Base58Error>::from_le_byte_slice(p.body()).ok()
             }
             _ => None,
@@ -185,13 +269,18 @@ impl FromByteSlice for Result<EnumerateResponse, Base58Error> {
     fn from_le_byte_slice(bytes: &[u8]) -> Result<EnumerateResponse, Base58Error> {
         let uid = Uid::from_str(
             &str::from_utf8(&bytes[0..8])
-                .expect("Could not convert to string. This is a bug in the rust bindings.")
+                .map_err(|_| Base58Error::InvalidCharacter)?
                 .replace('\u{0}', ""),
         )?;
         let string = str::from_utf8(&bytes[8..16])
-            .expect("Could not convert to string. This is a bug in the rust bindings.")
+            .map_err(|_| Base58Error::InvalidCharacter)?
             .replace('\u{0}', "");
-        let connected_uid = Uid::from_str(&string)?;
+        // only uid and enumeration type are set when a device is disconnected
+        let connected_uid = if string.is_empty() {
+            Uid::zero()
+        } else {
+            Uid::from_str(&string)?
+        };
         Ok(EnumerateResponse {
             uid,
             connected_uid,
@@ -208,7 +297,28 @@ impl FromByteSlice for Result<EnumerateResponse, Base58Error> {
     }
 }
 
-struct ServerNonce([u8; 4]);
+impl ToBytes for EnumerateResponse {
+    fn write_to_slice(&self, target: &mut [u8]) -> usize {
+        target[0..16].fill(0);
+        for (offset, uid) in [(0, self.uid), (8, self.connected_uid)] {
+            let uid = format!("{uid:?}");
+            target[offset..offset + uid.len()].copy_from_slice(uid.as_bytes());
+        }
+        target[16] = self.position as u8;
+        self.hardware_version.write_to_slice(&mut target[17..20]);
+        self.firmware_version.write_to_slice(&mut target[20..23]);
+        self.device_identifier.write_to_slice(&mut target[23..25]);
+        target[25] = match self.enumeration_type {
+            EnumerationType::Available => 0,
+            EnumerationType::Connected => 1,
+            EnumerationType::Disconnected => 2,
+            EnumerationType::Unknown => 3,
+        };
+        26
+    }
+}
+
+pub(crate) struct ServerNonce(pub(crate) [u8; 4]);
 
 impl FromByteSlice for ServerNonce {
     fn from_le_byte_slice(bytes: &[u8]) -> ServerNonce {
@@ -220,6 +330,25 @@ impl FromByteSlice for ServerNonce {
     }
 }
 
+/// Builds the payload of the authenticate function: a random client nonce followed by the
+/// HMAC-SHA1 of server and client nonce, keyed with the secret.
+pub(crate) fn authentication_payload(
+    secret: &str,
+    server_nonce: &ServerNonce,
+) -> Result<[u8; 24], AuthenticateError> {
+    let mut client_nonce = [0; 4];
+    ChaCha20Rng::from_entropy().fill_bytes(&mut client_nonce);
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret.as_bytes())
+        .map_err(|_| AuthenticateError::SecretInvalid)?;
+    mac.update(&server_nonce.0);
+    mac.update(&client_nonce);
+    let digest = mac.finalize().into_bytes();
+    let mut payload = [0; 24];
+    payload[0..4].copy_from_slice(&client_nonce);
+    payload[4..24].copy_from_slice(&digest);
+    Ok(payload)
+}
+
 /// This error is returned if the remote's server nonce could not be queried.
 #[derive(Debug, Copy, Clone)]
 pub enum AuthenticateError {
@@ -0,0 +1,234 @@
+//! Export of captured packets as a pcap file, which can be opened with Wireshark and its TFP dissector.
+//!
+//! Every packet is wrapped into synthetic IPv4 and TCP headers. The client is `10.0.0.1:50000`,
+//! the Brick Daemon `10.0.0.2:4223`, the TCP sequence numbers follow the bytes sent in each direction.
+use std::{io, net::Ipv4Addr, time::UNIX_EPOCH};
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::ip_connection::capture::{CapturedPacket, Direction};
+
+const MAGIC: u32 = 0xa1b2c3d4;
+const SNAPLEN: u32 = 65535;
+/// Packets start directly with the IP header.
+const LINKTYPE_RAW: u32 = 101;
+const IPV4_HEADER_SIZE: usize = 20;
+const TCP_HEADER_SIZE: usize = 20;
+const TCP_PSH_ACK: u8 = 0x18;
+
+const CLIENT: (Ipv4Addr, u16) = (Ipv4Addr::new(10, 0, 0, 1), 50000);
+const BRICKD: (Ipv4Addr, u16) = (Ipv4Addr::new(10, 0, 0, 2), 4223);
+
+/// Writes captured packets in the pcap format.
+#[derive(Debug)]
+pub struct PcapWriter<W> {
+    writer: W,
+    client_sequence: u32,
+    brickd_sequence: u32,
+    ip_identification: u16,
+}
+
+impl<W: AsyncWrite + Unpin> PcapWriter<W> {
+    /// Writes the file header.
+    pub async fn new(mut writer: W) -> io::Result<Self> {
+        let mut header = Vec::with_capacity(24);
+        header.extend_from_slice(&MAGIC.to_le_bytes());
+        header.extend_from_slice(&2u16.to_le_bytes());
+        header.extend_from_slice(&4u16.to_le_bytes());
+        // timezone offset and timestamp accuracy
+        header.extend_from_slice(&[0; 8]);
+        header.extend_from_slice(&SNAPLEN.to_le_bytes());
+        header.extend_from_slice(&LINKTYPE_RAW.to_le_bytes());
+        writer.write_all(&header).await?;
+        Ok(Self {
+            writer,
+            client_sequence: 1,
+            brickd_sequence: 1,
+            ip_identification: 0,
+        })
+    }
+
+    pub async fn write_packet(&mut self, captured: &CapturedPacket) -> io::Result<()> {
+        let payload = captured.packet.to_bytes();
+        let (source, destination) = match captured.direction {
+            Direction::Sent => (CLIENT, BRICKD),
+            Direction::Received => (BRICKD, CLIENT),
+        };
+        let (sequence, acknowledgement) = match captured.direction {
+            Direction::Sent => (&mut self.client_sequence, self.brickd_sequence),
+            Direction::Received => (&mut self.brickd_sequence, self.client_sequence),
+        };
+        let tcp_segment = tcp_segment(source, destination, *sequence, acknowledgement, &payload);
+        *sequence = sequence.wrapping_add(payload.len() as u32);
+        self.ip_identification = self.ip_identification.wrapping_add(1);
+        let ip_packet = ipv4_packet(
+            source.0,
+            destination.0,
+            self.ip_identification,
+            &tcp_segment,
+        );
+
+        let since_epoch = captured
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let mut record = Vec::with_capacity(16 + ip_packet.len());
+        record.extend_from_slice(&(since_epoch.as_secs() as u32).to_le_bytes());
+        record.extend_from_slice(&since_epoch.subsec_micros().to_le_bytes());
+        record.extend_from_slice(&(ip_packet.len() as u32).to_le_bytes());
+        record.extend_from_slice(&(ip_packet.len() as u32).to_le_bytes());
+        record.extend_from_slice(&ip_packet);
+        self.writer.write_all(&record).await
+    }
+
+    pub async fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush().await
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Writes a whole capture, e.g. one read with [`read_capture`](crate::ip_connection::capture::read_capture), as pcap file.
+pub async fn write_pcap<W: AsyncWrite + Unpin>(
+    packets: &[CapturedPacket],
+    writer: W,
+) -> io::Result<()> {
+    let mut pcap = PcapWriter::new(writer).await?;
+    for packet in packets {
+        pcap.write_packet(packet).await?;
+    }
+    pcap.flush().await
+}
+
+fn tcp_segment(
+    source: (Ipv4Addr, u16),
+    destination: (Ipv4Addr, u16),
+    sequence: u32,
+    acknowledgement: u32,
+    payload: &[u8],
+) -> Vec<u8> {
+    let mut segment = Vec::with_capacity(TCP_HEADER_SIZE + payload.len());
+    segment.extend_from_slice(&source.1.to_be_bytes());
+    segment.extend_from_slice(&destination.1.to_be_bytes());
+    segment.extend_from_slice(&sequence.to_be_bytes());
+    segment.extend_from_slice(&acknowledgement.to_be_bytes());
+    segment.push((TCP_HEADER_SIZE as u8 / 4) << 4);
+    segment.push(TCP_PSH_ACK);
+    // window, checksum and urgent pointer
+    segment.extend_from_slice(&[0xff, 0xff, 0, 0, 0, 0]);
+    segment.extend_from_slice(payload);
+
+    let mut pseudo_header = Vec::with_capacity(12);
+    pseudo_header.extend_from_slice(&source.0.octets());
+    pseudo_header.extend_from_slice(&destination.0.octets());
+    pseudo_header.extend_from_slice(&[0, 6]);
+    pseudo_header.extend_from_slice(&(segment.len() as u16).to_be_bytes());
+    let checksum = internet_checksum(&[&pseudo_header, &segment]);
+    segment[16..18].copy_from_slice(&checksum.to_be_bytes());
+    segment
+}
+
+fn ipv4_packet(
+    source: Ipv4Addr,
+    destination: Ipv4Addr,
+    identification: u16,
+    segment: &[u8],
+) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(IPV4_HEADER_SIZE + segment.len());
+    packet.push(0x45);
+    packet.push(0);
+    packet.extend_from_slice(&((IPV4_HEADER_SIZE + segment.len()) as u16).to_be_bytes());
+    packet.extend_from_slice(&identification.to_be_bytes());
+    // don't fragment
+    packet.extend_from_slice(&[0x40, 0]);
+    // ttl and protocol tcp
+    packet.extend_from_slice(&[64, 6]);
+    packet.extend_from_slice(&[0, 0]);
+    packet.extend_from_slice(&source.octets());
+    packet.extend_from_slice(&destination.octets());
+    let checksum = internet_checksum(&[&packet]);
+    packet[10..12].copy_from_slice(&checksum.to_be_bytes());
+    packet.extend_from_slice(segment);
+    packet
+}
+
+/// One's complement checksum of RFC 1071 over the concatenated parts, each part has to be of even
+/// length except the last.
+fn internet_checksum(parts: &[&[u8]]) -> u16 {
+    let mut sum = 0u32;
+    for part in parts {
+        for word in part.chunks(2) {
+            let word = match word {
+                [high, low] => u16::from_be_bytes([*high, *low]),
+                [high] => u16::from_be_bytes([*high, 0]),
+                _ => unreachable!(),
+            };
+            sum += word as u32;
+        }
+    }
+    while sum > 0xffff {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{
+        base58::Uid,
+        ip_connection::{
+            async_io::PacketData,
+            capture::{CapturedPacket, Direction},
+            pcap::{internet_checksum, write_pcap},
+            PacketHeader,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_write_pcap() {
+        let timestamp = UNIX_EPOCH + Duration::from_micros(1_700_000_000_123_456);
+        let request = PacketHeader::with_payload(Uid::from(42), 3, 1, true, 0);
+        let response = PacketHeader::with_payload(Uid::from(42), 3, 1, true, 2);
+        let packets = [
+            CapturedPacket {
+                timestamp,
+                direction: Direction::Sent,
+                packet: PacketData::new(request, []),
+            },
+            CapturedPacket {
+                timestamp,
+                direction: Direction::Received,
+                packet: PacketData::new(response, [7, 8]),
+            },
+        ];
+        let mut file = Vec::new();
+        write_pcap(&packets, &mut file).await.unwrap();
+
+        assert_eq!(&file[0..4], &[0xd4, 0xc3, 0xb2, 0xa1]);
+        assert_eq!(&file[20..24], &101u32.to_le_bytes());
+        let first = &file[24..];
+        assert_eq!(&first[0..4], &1_700_000_000u32.to_le_bytes());
+        assert_eq!(&first[4..8], &123_456u32.to_le_bytes());
+        let length = u32::from_le_bytes(first[8..12].try_into().unwrap()) as usize;
+        assert_eq!(length, 20 + 20 + 8);
+        let ip = &first[16..16 + length];
+        assert_eq!(internet_checksum(&[&ip[..20]]), 0);
+        // source port of the client, destination port of the brick daemon
+        assert_eq!(&ip[20..24], &[0xc3, 0x50, 0x10, 0x7f]);
+        assert_eq!(&ip[40..], &packets[0].packet.to_bytes()[..]);
+
+        let second = &first[16 + length..];
+        let ip = &second[16..];
+        assert_eq!(ip.len(), 20 + 20 + 10);
+        assert_eq!(&ip[20..24], &[0x10, 0x7f, 0xc3, 0x50]);
+        // acknowledges the 8 bytes of the request
+        assert_eq!(&ip[28..32], &9u32.to_be_bytes());
+        let mut pseudo_header = ip[12..20].to_vec();
+        pseudo_header.extend_from_slice(&[0, 6, 0, 30]);
+        assert_eq!(internet_checksum(&[&pseudo_header, &ip[20..]]), 0);
+    }
+}
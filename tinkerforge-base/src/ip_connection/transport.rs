@@ -0,0 +1,308 @@
+//! Links which can carry the Tinkerforge protocol, the packet framing itself is handled by the
+//! [`AsyncIpConnection`](crate::ip_connection::async_io::AsyncIpConnection).
+use std::{fmt::Debug, future::Future, io, pin::Pin, time::Duration};
+
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::{TcpStream, ToSocketAddrs},
+    sync::Mutex,
+};
+
+pub type ConnectFuture<'a, S> = Pin<Box<dyn Future<Output = io::Result<S>> + Send + 'a>>;
+
+/// Opens byte streams to a Brick Daemon or something that speaks the same protocol.
+pub trait Transport: Debug + Send + Sync + 'static {
+    type Stream: AsyncRead + AsyncWrite + Send + Unpin + 'static;
+
+    /// Opens a new stream. This is called for the initial connection and again on every reconnect.
+    fn connect(&self) -> ConnectFuture<'_, Self::Stream>;
+}
+
+/// Plain TCP connection, the default transport of the Brick Daemon on port 4223.
+#[derive(Debug, Clone)]
+pub struct TcpTransport<T> {
+    addr: T,
+}
+
+impl<T> TcpTransport<T> {
+    pub fn new(addr: T) -> Self {
+        Self { addr }
+    }
+}
+
+impl<T: ToSocketAddrs + Debug + Clone + Send + Sync + 'static> Transport for TcpTransport<T> {
+    type Stream = TcpStream;
+
+    fn connect(&self) -> ConnectFuture<'_, TcpStream> {
+        Box::pin(async move {
+            let socket = TcpStream::connect(self.addr.clone()).await?;
+            let keepalive = socket2::TcpKeepalive::new()
+                .with_time(Duration::from_secs(20))
+                .with_interval(Duration::from_secs(20));
+            socket2::SockRef::from(&socket).set_tcp_keepalive(&keepalive)?;
+            Ok(socket)
+        })
+    }
+}
+
+/// Connection over a unix domain socket.
+#[cfg(unix)]
+#[derive(Debug, Clone)]
+pub struct UnixTransport {
+    path: std::path::PathBuf,
+}
+
+#[cfg(unix)]
+impl UnixTransport {
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(unix)]
+impl Transport for UnixTransport {
+    type Stream = tokio::net::UnixStream;
+
+    fn connect(&self) -> ConnectFuture<'_, tokio::net::UnixStream> {
+        Box::pin(tokio::net::UnixStream::connect(&self.path))
+    }
+}
+
+/// Hands out an already opened stream once, e.g. one end of a [`tokio::io::duplex`] in tests.
+/// Every further connect attempt fails, so a reconnect is not possible.
+pub struct StreamTransport<S> {
+    stream: Mutex<Option<S>>,
+}
+
+impl<S> StreamTransport<S> {
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream: Mutex::new(Some(stream)),
+        }
+    }
+}
+
+impl<S> Debug for StreamTransport<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StreamTransport")
+    }
+}
+
+impl<S: AsyncRead + AsyncWrite + Send + Unpin + 'static> Transport for StreamTransport<S> {
+    type Stream = S;
+
+    fn connect(&self) -> ConnectFuture<'_, S> {
+        Box::pin(async move {
+            self.stream.lock().await.take().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotConnected, "Stream was already used")
+            })
+        })
+    }
+}
+
+/// Connection over a WebSocket, e.g. `ws://localhost:4280` for the websocket port of the Brick Daemon
+/// or the Ethernet Extension. The packets are carried in binary frames.
+#[cfg(feature = "websocket")]
+#[derive(Debug, Clone)]
+pub struct WebSocketTransport {
+    url: String,
+}
+
+#[cfg(feature = "websocket")]
+impl WebSocketTransport {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into() }
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl Transport for WebSocketTransport {
+    type Stream = websocket::WebSocketIo<tokio_tungstenite::MaybeTlsStream<TcpStream>>;
+
+    fn connect(&self) -> ConnectFuture<'_, Self::Stream> {
+        Box::pin(async move {
+            use tokio_tungstenite::tungstenite::{client::IntoClientRequest, http::HeaderValue};
+
+            let mut request = self
+                .url
+                .as_str()
+                .into_client_request()
+                .map_err(websocket::to_io_error)?;
+            request
+                .headers_mut()
+                .insert("Sec-WebSocket-Protocol", HeaderValue::from_static("tfp"));
+            let (stream, _) = tokio_tungstenite::connect_async(request)
+                .await
+                .map_err(websocket::to_io_error)?;
+            Ok(websocket::WebSocketIo::new(stream))
+        })
+    }
+}
+
+#[cfg(feature = "websocket")]
+pub mod websocket {
+    use std::{
+        io,
+        pin::Pin,
+        task::{ready, Context, Poll},
+    };
+
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+    use tokio_tungstenite::{
+        tungstenite::{self, Message},
+        WebSocketStream,
+    };
+
+    /// Byte stream view on a websocket, every write is sent as one binary frame.
+    pub struct WebSocketIo<S> {
+        inner: WebSocketStream<S>,
+        read_buffer: Vec<u8>,
+        read_position: usize,
+    }
+
+    impl<S> WebSocketIo<S> {
+        pub fn new(inner: WebSocketStream<S>) -> Self {
+            Self {
+                inner,
+                read_buffer: Vec::new(),
+                read_position: 0,
+            }
+        }
+    }
+
+    pub(crate) fn to_io_error(error: tungstenite::Error) -> io::Error {
+        match error {
+            tungstenite::Error::Io(error) => error,
+            tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed => {
+                io::ErrorKind::NotConnected.into()
+            }
+            error => io::Error::new(io::ErrorKind::Other, error),
+        }
+    }
+
+    impl<S: AsyncRead + AsyncWrite + Unpin> AsyncRead for WebSocketIo<S> {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            let this = self.get_mut();
+            loop {
+                let available = &this.read_buffer[this.read_position..];
+                if !available.is_empty() {
+                    let count = available.len().min(buf.remaining());
+                    buf.put_slice(&available[..count]);
+                    this.read_position += count;
+                    return Poll::Ready(Ok(()));
+                }
+                match ready!(this.inner.poll_next_unpin(cx)) {
+                    Some(Ok(Message::Binary(data))) => {
+                        this.read_buffer = data;
+                        this.read_position = 0;
+                    }
+                    // a closed websocket is the end of the stream
+                    Some(Ok(Message::Close(_)))
+                    | Some(Err(tungstenite::Error::ConnectionClosed))
+                    | None => return Poll::Ready(Ok(())),
+                    // pings are answered by tungstenite itself
+                    Some(Ok(_)) => {}
+                    Some(Err(error)) => return Poll::Ready(Err(to_io_error(error))),
+                }
+            }
+        }
+    }
+
+    impl<S: AsyncRead + AsyncWrite + Unpin> AsyncWrite for WebSocketIo<S> {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = self.get_mut();
+            ready!(this.inner.poll_ready_unpin(cx)).map_err(to_io_error)?;
+            this.inner
+                .start_send_unpin(Message::Binary(buf.to_vec()))
+                .map_err(to_io_error)?;
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.get_mut()
+                .inner
+                .poll_flush_unpin(cx)
+                .map_err(to_io_error)
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.get_mut()
+                .inner
+                .poll_close_unpin(cx)
+                .map_err(to_io_error)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "websocket"))]
+mod test {
+    use std::time::Duration;
+
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::{
+        handshake::server::{Request, Response},
+        http::HeaderValue,
+        Message,
+    };
+
+    use crate::{
+        base58::Uid,
+        byte_converter::{FromByteSlice, ToBytes},
+        ip_connection::{async_io::AsyncIpConnection, PacketHeader},
+    };
+
+    #[tokio::test]
+    // the handshake callback signature is given by tungstenite
+    #[allow(clippy::result_large_err)]
+    async fn test_websocket_transport() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_hdr_async(
+                socket,
+                |_: &Request, mut response: Response| {
+                    response
+                        .headers_mut()
+                        .insert("Sec-WebSocket-Protocol", HeaderValue::from_static("tfp"));
+                    Ok(response)
+                },
+            )
+            .await
+            .unwrap();
+            let Some(Ok(Message::Binary(request))) = websocket.next().await else {
+                panic!("Expected a binary frame");
+            };
+            let header = PacketHeader::from_le_byte_slice(&request);
+            assert_eq!(header.uid, Uid::from(42));
+            assert_eq!(header.function_id, 3);
+            let mut response = vec![0; PacketHeader::SIZE + 2];
+            PacketHeader {
+                length: response.len() as u8,
+                ..header
+            }
+            .write_to_slice(&mut response);
+            response[PacketHeader::SIZE..].copy_from_slice(&[7, 8]);
+            websocket.send(Message::Binary(response)).await.unwrap();
+        });
+        let connection = AsyncIpConnection::new_websocket(format!("ws://{addr}"))
+            .await
+            .unwrap();
+        let response = connection
+            .get(Uid::from(42), 3, &[], Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(response.body(), &[7, 8]);
+        server.await.unwrap();
+    }
+}
@@ -17,9 +17,15 @@ extern crate core;
 
 pub mod base58;
 pub mod byte_converter;
+pub mod configuration;
+pub mod decoder;
 pub mod device;
 pub mod error;
 pub mod ip_connection;
 pub mod low_level_traits;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+pub mod topology;
+pub mod units;
 
 //mod generator;
@@ -1,3 +1,11 @@
+//! Reassembly of the streams which are transferred in chunks by the low level functions.
+use std::mem;
+
+use crate::error::TinkerforgeError;
+
+/// Devices answer with this chunk offset if a stream has no data at all.
+const NO_DATA_CHUNK_OFFSET: usize = u16::MAX as usize;
+
 pub trait LowLevelRead<DataT, ResultT> {
     fn ll_message_length(&self) -> usize;
     fn ll_message_chunk_offset(&self) -> usize;
@@ -5,7 +13,213 @@ pub trait LowLevelRead<DataT, ResultT> {
     fn get_result(&self) -> ResultT;
 }
 
+/// Response of a low level write which reports how many elements of the chunk were written.
 pub trait LowLevelWrite<ResultT> {
     fn ll_message_written(&self) -> usize;
     fn get_result(&self) -> ResultT;
 }
+
+/// Collects the chunks returned by consecutive calls of a low level read function.
+#[derive(Debug)]
+pub struct LowLevelReadBuffer<DataT> {
+    data: Vec<DataT>,
+    length: usize,
+    started: bool,
+    out_of_sync: bool,
+}
+
+impl<DataT> Default for LowLevelReadBuffer<DataT> {
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            length: 0,
+            started: false,
+            out_of_sync: false,
+        }
+    }
+}
+
+impl<DataT: Copy> LowLevelReadBuffer<DataT> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the next chunk and returns the whole data once it is complete.
+    ///
+    /// If a chunk does not continue at the expected offset, e.g. because another client read from
+    /// the same stream, the rest of the stream is discarded with the following chunks, then
+    /// [`TinkerforgeError::StreamOutOfSync`] is returned, so the next read starts in sync again.
+    pub fn push<ResultT>(
+        &mut self,
+        chunk: &impl LowLevelRead<DataT, ResultT>,
+    ) -> Result<Option<Vec<DataT>>, TinkerforgeError> {
+        let chunk_offset = chunk.ll_message_chunk_offset();
+        let chunk_data = chunk.ll_message_chunk_data();
+        if !self.started {
+            self.started = true;
+            if chunk_offset == NO_DATA_CHUNK_OFFSET {
+                return Ok(Some(Vec::new()));
+            }
+            self.length = chunk.ll_message_length();
+            self.data.reserve(self.length);
+            self.out_of_sync = chunk_offset != 0;
+        } else if !self.out_of_sync {
+            self.out_of_sync = chunk_offset != self.data.len();
+        }
+        if self.out_of_sync {
+            return if chunk_offset + chunk_data.len() < self.length {
+                Ok(None)
+            } else {
+                Err(TinkerforgeError::StreamOutOfSync)
+            };
+        }
+        let missing = self.length - self.data.len();
+        self.data
+            .extend_from_slice(&chunk_data[..missing.min(chunk_data.len())]);
+        if self.data.len() < self.length {
+            Ok(None)
+        } else {
+            Ok(Some(mem::take(&mut self.data)))
+        }
+    }
+}
+
+/// Reassembles the chunks of a low level callback.
+///
+/// Callbacks can't be requested again, so a message with a missing chunk is discarded and the
+/// buffer waits for the next message starting at offset 0.
+#[derive(Debug)]
+pub struct LowLevelCallbackBuffer<DataT> {
+    data: Vec<DataT>,
+    length: usize,
+    in_sync: bool,
+}
+
+impl<DataT> Default for LowLevelCallbackBuffer<DataT> {
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            length: 0,
+            in_sync: false,
+        }
+    }
+}
+
+impl<DataT: Copy> LowLevelCallbackBuffer<DataT> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the next chunk and returns the whole message once it is complete.
+    pub fn push<ResultT>(
+        &mut self,
+        chunk: &impl LowLevelRead<DataT, ResultT>,
+    ) -> Option<Vec<DataT>> {
+        let chunk_offset = chunk.ll_message_chunk_offset();
+        if chunk_offset == 0 {
+            self.data.clear();
+            self.length = chunk.ll_message_length();
+            self.in_sync = true;
+        } else if !self.in_sync || chunk_offset != self.data.len() {
+            self.data.clear();
+            self.in_sync = false;
+            return None;
+        }
+        let chunk_data = chunk.ll_message_chunk_data();
+        let missing = self.length - self.data.len();
+        self.data
+            .extend_from_slice(&chunk_data[..missing.min(chunk_data.len())]);
+        if self.data.len() < self.length {
+            return None;
+        }
+        self.in_sync = false;
+        Some(mem::take(&mut self.data))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::TinkerforgeError,
+        low_level_traits::{LowLevelCallbackBuffer, LowLevelRead, LowLevelReadBuffer},
+    };
+
+    struct Chunk {
+        length: u16,
+        offset: u16,
+        data: [u8; 4],
+    }
+
+    impl LowLevelRead<u8, ()> for Chunk {
+        fn ll_message_length(&self) -> usize {
+            self.length as usize
+        }
+
+        fn ll_message_chunk_offset(&self) -> usize {
+            self.offset as usize
+        }
+
+        fn ll_message_chunk_data(&self) -> &[u8] {
+            &self.data
+        }
+
+        fn get_result(&self) {}
+    }
+
+    fn chunk(length: u16, offset: u16, data: [u8; 4]) -> Chunk {
+        Chunk {
+            length,
+            offset,
+            data,
+        }
+    }
+
+    #[test]
+    fn test_reassemble_chunks() {
+        let mut buffer = LowLevelReadBuffer::new();
+        assert_eq!(buffer.push(&chunk(6, 0, [1, 2, 3, 4])).unwrap(), None);
+        assert_eq!(
+            buffer.push(&chunk(6, 4, [5, 6, 0, 0])).unwrap(),
+            Some(vec![1, 2, 3, 4, 5, 6])
+        );
+
+        let mut buffer = LowLevelReadBuffer::new();
+        assert_eq!(
+            buffer.push(&chunk(0, u16::MAX, [0; 4])).unwrap(),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_discard_out_of_sync_stream() {
+        let mut buffer = LowLevelReadBuffer::new();
+        assert_eq!(buffer.push(&chunk(10, 4, [5, 6, 7, 8])).unwrap(), None);
+        assert!(matches!(
+            buffer.push(&chunk(10, 8, [9, 10, 0, 0])),
+            Err(TinkerforgeError::StreamOutOfSync)
+        ));
+
+        let mut buffer = LowLevelReadBuffer::new();
+        assert_eq!(buffer.push(&chunk(10, 0, [1, 2, 3, 4])).unwrap(), None);
+        assert!(matches!(
+            buffer.push(&chunk(10, 8, [9, 10, 0, 0])),
+            Err(TinkerforgeError::StreamOutOfSync)
+        ));
+    }
+
+    #[test]
+    fn test_resynchronize_callbacks() {
+        let mut buffer = LowLevelCallbackBuffer::new();
+        assert_eq!(buffer.push(&chunk(6, 4, [5, 6, 0, 0])), None);
+        assert_eq!(buffer.push(&chunk(6, 0, [1, 2, 3, 4])), None);
+        // the second chunk got lost, the partial message is discarded
+        assert_eq!(buffer.push(&chunk(10, 0, [1, 2, 3, 4])), None);
+        assert_eq!(buffer.push(&chunk(10, 8, [9, 10, 0, 0])), None);
+        assert_eq!(buffer.push(&chunk(3, 0, [7, 8, 9, 0])), Some(vec![7, 8, 9]));
+        assert_eq!(buffer.push(&chunk(6, 0, [1, 2, 3, 4])), None);
+        assert_eq!(
+            buffer.push(&chunk(6, 4, [5, 6, 0, 0])),
+            Some(vec![1, 2, 3, 4, 5, 6])
+        );
+    }
+}
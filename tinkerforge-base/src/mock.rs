@@ -0,0 +1,567 @@
+//! In-process stand-in for the Brick Daemon, to test code built on the bindings without hardware.
+//!
+//! Devices are registered by [`Uid`] with scripted responses per function id. Callbacks,
+//! enumerations and disconnects can be triggered by the test at any time.
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    io,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use log::{debug, warn};
+use tokio::{
+    io::AsyncWriteExt,
+    net::{TcpListener, TcpStream},
+    sync::{broadcast, mpsc, watch},
+    task::{AbortHandle, JoinHandle},
+};
+
+use crate::{
+    base58::Uid,
+    byte_converter::ToBytes,
+    error::TinkerforgeError,
+    ip_connection::{
+        async_io::{AsyncIpConnection, PacketData},
+        EnumerateResponse, EnumerationType, ErrorCode, PacketHeader, Version,
+    },
+};
+
+const FUNCTION_ENUMERATE: u8 = 254;
+const FUNCTION_GET_IDENTITY: u8 = 255;
+const CALLBACK_ENUMERATE: u8 = 253;
+
+type Handler = Box<dyn FnMut(&[u8]) -> MockResponse + Send>;
+type FallbackHandler = Box<dyn FnMut(u8, &[u8]) -> MockResponse + Send>;
+
+/// What the mock server does with a request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockResponse {
+    payload: Vec<u8>,
+    error_code: ErrorCode,
+    delay: Duration,
+    action: MockAction,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MockAction {
+    Respond,
+    Ignore,
+    Disconnect,
+}
+
+impl MockResponse {
+    /// Answers successfully with the given payload.
+    pub fn ok(payload: impl Into<Vec<u8>>) -> Self {
+        Self {
+            payload: payload.into(),
+            error_code: ErrorCode::Success,
+            delay: Duration::ZERO,
+            action: MockAction::Respond,
+        }
+    }
+    /// Answers with an empty payload and the given error code in the header.
+    pub fn error(error_code: ErrorCode) -> Self {
+        Self {
+            error_code,
+            ..Self::ok([])
+        }
+    }
+    /// Sends nothing back, the request runs into its timeout.
+    pub fn no_response() -> Self {
+        Self {
+            action: MockAction::Ignore,
+            ..Self::ok([])
+        }
+    }
+    /// Closes the connection of the client instead of answering.
+    pub fn disconnect() -> Self {
+        Self {
+            action: MockAction::Disconnect,
+            ..Self::ok([])
+        }
+    }
+    /// Waits before the response is sent, other requests are answered in the meantime.
+    pub fn delayed(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+}
+
+/// Converts the result of a generated `*Simulator::handle_request`.
+impl From<Result<Vec<u8>, ErrorCode>> for MockResponse {
+    fn from(result: Result<Vec<u8>, ErrorCode>) -> Self {
+        match result {
+            Ok(payload) => MockResponse::ok(payload),
+            Err(error_code) => MockResponse::error(error_code),
+        }
+    }
+}
+
+/// A simulated Brick or Bricklet.
+pub struct MockDevice {
+    identity: EnumerateResponse,
+    handlers: HashMap<u8, Handler>,
+    fallback: Option<FallbackHandler>,
+}
+
+impl Debug for MockDevice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockDevice")
+            .field("identity", &self.identity)
+            .field("functions", &self.handlers.keys())
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+impl MockDevice {
+    pub fn new(uid: impl Into<Uid>, device_identifier: u16) -> Self {
+        Self {
+            identity: EnumerateResponse {
+                uid: uid.into(),
+                connected_uid: Uid::zero(),
+                position: '0',
+                hardware_version: Version::new(1, 0, 0),
+                firmware_version: Version::new(2, 0, 0),
+                device_identifier,
+                enumeration_type: EnumerationType::Available,
+            },
+            handlers: HashMap::new(),
+            fallback: None,
+        }
+    }
+    /// Places the device at `position` of the Brick or Bricklet with `connected_uid`.
+    pub fn connected_to(mut self, connected_uid: impl Into<Uid>, position: char) -> Self {
+        self.identity.connected_uid = connected_uid.into();
+        self.identity.position = position;
+        self
+    }
+    pub fn hardware_version(mut self, version: Version) -> Self {
+        self.identity.hardware_version = version;
+        self
+    }
+    pub fn firmware_version(mut self, version: Version) -> Self {
+        self.identity.firmware_version = version;
+        self
+    }
+    /// Answers every call of `function_id` with the same response.
+    pub fn respond(self, function_id: u8, response: MockResponse) -> Self {
+        self.respond_with(function_id, move |_| response.clone())
+    }
+    /// Computes the response to `function_id` from the request payload.
+    pub fn respond_with(
+        mut self,
+        function_id: u8,
+        handler: impl FnMut(&[u8]) -> MockResponse + Send + 'static,
+    ) -> Self {
+        self.handlers.insert(function_id, Box::new(handler));
+        self
+    }
+    /// Answers all functions without a handler of their own, e.g. with a generated simulator:
+    /// `respond_to_all(move |function_id, payload| simulator.handle_request(function_id, payload).into())`
+    pub fn respond_to_all(
+        mut self,
+        handler: impl FnMut(u8, &[u8]) -> MockResponse + Send + 'static,
+    ) -> Self {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+    pub fn uid(&self) -> Uid {
+        self.identity.uid
+    }
+    fn identity(&self, enumeration_type: EnumerationType) -> [u8; 26] {
+        let mut payload = [0; 26];
+        EnumerateResponse {
+            enumeration_type,
+            ..self.identity
+        }
+        .write_to_slice(&mut payload);
+        payload
+    }
+    fn handle(&mut self, function_id: u8, payload: &[u8]) -> MockResponse {
+        match self.handlers.get_mut(&function_id) {
+            Some(handler) => handler(payload),
+            None if function_id == FUNCTION_GET_IDENTITY => {
+                MockResponse::ok(&self.identity(EnumerationType::Available)[..25])
+            }
+            None => match &mut self.fallback {
+                Some(fallback) => fallback(function_id, payload),
+                None => MockResponse::error(ErrorCode::FunctionNotSupported),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Event {
+    Packet(PacketData),
+    Disconnect,
+}
+
+#[derive(Default)]
+struct MockState {
+    devices: Mutex<HashMap<Uid, MockDevice>>,
+    received: Mutex<Vec<PacketData>>,
+    connections: Mutex<Vec<JoinHandle<()>>>,
+}
+
+/// Mock Brick Daemon listening on a random local port. It stops when dropped.
+pub struct MockBrickd {
+    addr: SocketAddr,
+    state: Arc<MockState>,
+    events: broadcast::Sender<Event>,
+    accepted: watch::Receiver<usize>,
+    abort_handle: AbortHandle,
+}
+
+impl MockBrickd {
+    pub async fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(MockState::default());
+        let (events, _) = broadcast::channel(512);
+        let accept_state = state.clone();
+        let accept_events = events.clone();
+        let (accepted_tx, accepted) = watch::channel(0);
+        let abort_handle = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((socket, peer)) => {
+                        debug!("Mock client connected from {peer}");
+                        let connection = tokio::spawn(Self::serve(
+                            socket,
+                            accept_state.clone(),
+                            accept_events.subscribe(),
+                        ));
+                        let mut connections = accept_state
+                            .connections
+                            .lock()
+                            .expect("Mock state poisoned");
+                        connections.retain(|connection| !connection.is_finished());
+                        connections.push(connection);
+                        drop(connections);
+                        accepted_tx.send_modify(|accepted| *accepted += 1);
+                    }
+                    Err(error) => warn!("Mock server cannot accept: {error}"),
+                }
+            }
+        })
+        .abort_handle();
+        Ok(Self {
+            addr,
+            state,
+            events,
+            accepted,
+            abort_handle,
+        })
+    }
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+    /// Opens a new connection to this server and waits until the server has accepted it,
+    /// so events emitted afterwards reach the new client.
+    pub async fn connect(&self) -> Result<AsyncIpConnection, TinkerforgeError> {
+        let mut accepted = self.accepted.clone();
+        let before = *accepted.borrow_and_update();
+        let connection = AsyncIpConnection::new(self.addr).await?;
+        accepted
+            .wait_for(|accepted| *accepted > before)
+            .await
+            .map_err(|_| TinkerforgeError::ConnectionClosed)?;
+        Ok(connection)
+    }
+    /// Registers the device, it is listed by enumerate and answers requests from now on.
+    pub fn add_device(&self, device: MockDevice) {
+        self.devices().insert(device.uid(), device);
+    }
+    pub fn remove_device(&self, uid: impl Into<Uid>) -> Option<MockDevice> {
+        self.devices().remove(&uid.into())
+    }
+    /// All requests received so far, in order of arrival.
+    pub fn received(&self) -> Vec<PacketData> {
+        self.state
+            .received
+            .lock()
+            .expect("Mock state poisoned")
+            .clone()
+    }
+    /// Sends a callback packet to all connected clients.
+    pub fn emit_callback(&self, uid: impl Into<Uid>, function_id: u8, payload: &[u8]) {
+        let header =
+            PacketHeader::with_payload(uid.into(), function_id, 0, true, payload.len() as u8);
+        self.emit(Event::Packet(PacketData::new(header, payload)));
+    }
+    /// Sends an enumerate callback for a registered device to all connected clients.
+    pub fn emit_enumerate(&self, uid: impl Into<Uid>, enumeration_type: EnumerationType) {
+        let uid = uid.into();
+        let Some(identity) = self
+            .devices()
+            .get(&uid)
+            .map(|device| device.identity(enumeration_type))
+        else {
+            warn!("Cannot enumerate unknown mock device {uid}");
+            return;
+        };
+        self.emit_callback(uid, CALLBACK_ENUMERATE, &identity);
+    }
+    /// Closes the connections of all currently connected clients.
+    pub fn disconnect_clients(&self) {
+        self.emit(Event::Disconnect);
+    }
+
+    fn emit(&self, event: Event) {
+        if self.events.send(event).is_err() {
+            debug!("No mock client connected");
+        }
+    }
+    fn devices(&self) -> std::sync::MutexGuard<'_, HashMap<Uid, MockDevice>> {
+        self.state.devices.lock().expect("Mock state poisoned")
+    }
+
+    async fn serve(
+        socket: TcpStream,
+        state: Arc<MockState>,
+        mut events: broadcast::Receiver<Event>,
+    ) {
+        // both halves stay in this task, returning drops them and closes the whole socket
+        let (mut rd, mut wr) = tokio::io::split(socket);
+        let (responses_tx, mut responses) = mpsc::unbounded_channel::<Event>();
+        loop {
+            // the read is kept across the writes, cancelling it could lose part of a packet
+            let read = PacketData::read_from(&mut rd);
+            tokio::pin!(read);
+            let request = loop {
+                let event = tokio::select! {
+                    request = &mut read => break request,
+                    Some(event) = responses.recv() => event,
+                    event = events.recv() => match event {
+                        Ok(event) => event,
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => return,
+                    },
+                };
+                match event {
+                    Event::Packet(packet) => {
+                        if wr.write_all(&packet.to_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                    Event::Disconnect => return,
+                }
+            };
+            let Ok(request) = request else {
+                return;
+            };
+            state
+                .received
+                .lock()
+                .expect("Mock state poisoned")
+                .push(request.clone());
+            for (response, delay) in Self::process(&state, &request) {
+                let responses_tx = responses_tx.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep(delay).await;
+                    let _ = responses_tx.send(response);
+                });
+            }
+        }
+    }
+
+    /// Works out what to send back for a request and after which delay.
+    fn process(state: &MockState, request: &PacketData) -> Vec<(Event, Duration)> {
+        let header = request.header();
+        let mut devices = state.devices.lock().expect("Mock state poisoned");
+        if header.uid() == Uid::zero() && header.function_id() == FUNCTION_ENUMERATE {
+            return devices
+                .values()
+                .map(|device| {
+                    let identity = device.identity(EnumerationType::Available);
+                    let header = PacketHeader::with_payload(
+                        device.uid(),
+                        CALLBACK_ENUMERATE,
+                        0,
+                        true,
+                        identity.len() as u8,
+                    );
+                    (
+                        Event::Packet(PacketData::new(header, identity)),
+                        Duration::ZERO,
+                    )
+                })
+                .collect();
+        }
+        let Some(device) = devices.get_mut(&header.uid()) else {
+            // the real Brick Daemon drops requests to unknown devices
+            return vec![];
+        };
+        let response = device.handle(header.function_id(), request.body());
+        let event = match response.action {
+            MockAction::Ignore => return vec![],
+            MockAction::Disconnect => Event::Disconnect,
+            MockAction::Respond
+                if !header.response_expected() && response.error_code == ErrorCode::Success =>
+            {
+                return vec![]
+            }
+            MockAction::Respond => {
+                let response_header = PacketHeader::with_payload(
+                    header.uid(),
+                    header.function_id(),
+                    header.sequence_number(),
+                    header.response_expected(),
+                    response.payload.len() as u8,
+                )
+                .with_error_code(response.error_code);
+                Event::Packet(PacketData::new(response_header, response.payload))
+            }
+        };
+        vec![(event, response.delay)]
+    }
+}
+
+impl Drop for MockBrickd {
+    fn drop(&mut self) {
+        self.abort_handle.abort();
+        for connection in self
+            .state
+            .connections
+            .lock()
+            .expect("Mock state poisoned")
+            .drain(..)
+        {
+            connection.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio_stream::StreamExt;
+
+    use crate::{
+        base58::Uid,
+        error::TinkerforgeError,
+        ip_connection::{async_io::DisconnectReason, EnumerationType, ErrorCode},
+        mock::{MockBrickd, MockDevice, MockResponse},
+    };
+
+    #[tokio::test]
+    async fn test_scripted_responses() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(
+            MockDevice::new("R4c", 298)
+                .respond(3, MockResponse::ok([1, 2, 3]))
+                .respond(4, MockResponse::error(ErrorCode::InvalidParameter))
+                .respond(5, MockResponse::no_response())
+                .respond_with(6, |payload| MockResponse::ok(payload.to_vec())),
+        );
+        let connection = brickd.connect().await.unwrap();
+        let uid = Uid::from("R4c");
+        let timeout = Duration::from_millis(200);
+        let response = connection.get(uid, 3, &[], timeout).await.unwrap();
+        assert_eq!(response.body(), &[1, 2, 3]);
+        assert!(matches!(
+            connection.get(uid, 4, &[], timeout).await,
+            Err(TinkerforgeError::InvalidParameter)
+        ));
+        assert!(matches!(
+            connection.get(uid, 5, &[], timeout).await,
+            Err(TinkerforgeError::NoResponseReceived)
+        ));
+        let response = connection.get(uid, 6, &[9, 8], timeout).await.unwrap();
+        assert_eq!(response.body(), &[9, 8]);
+        assert!(matches!(
+            connection.get(uid, 7, &[], timeout).await,
+            Err(TinkerforgeError::FunctionNotSupported)
+        ));
+        assert_eq!(brickd.received().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_delayed_response_does_not_block_others() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(
+            MockDevice::new("R4c", 298)
+                .respond(3, MockResponse::ok([1]).delayed(Duration::from_millis(300)))
+                .respond(4, MockResponse::ok([2])),
+        );
+        let connection = brickd.connect().await.unwrap();
+        let uid = Uid::from("R4c");
+        let timeout = Duration::from_secs(5);
+        let slow = connection.get(uid, 3, &[], timeout);
+        let fast = async {
+            let response = connection.get(uid, 4, &[], timeout).await.unwrap();
+            assert_eq!(brickd.received().len(), 2);
+            response
+        };
+        let (slow, fast) = tokio::join!(slow, fast);
+        assert_eq!(slow.unwrap().body(), &[1]);
+        assert_eq!(fast.body(), &[2]);
+    }
+
+    #[tokio::test]
+    async fn test_enumerate_and_callbacks() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("6DyH5n", 13));
+        brickd.add_device(MockDevice::new("R4c", 298).connected_to("6DyH5n", 'a'));
+        let connection = brickd.connect().await.unwrap();
+
+        let mut enumeration = connection.enumerate().await.unwrap();
+        let mut found = vec![];
+        for _ in 0..2 {
+            let response = tokio::time::timeout(Duration::from_secs(5), enumeration.next())
+                .await
+                .unwrap()
+                .unwrap();
+            found.push((response.uid, response.connected_uid, response.position));
+        }
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                (Uid::from("R4c"), Uid::from("6DyH5n"), 'a'),
+                (Uid::from("6DyH5n"), Uid::from("0"), '0'),
+            ]
+        );
+
+        let mut callbacks = connection.callback_stream(Uid::from("R4c"), 10).await;
+        brickd.emit_callback("R4c", 10, &[42]);
+        let callback = tokio::time::timeout(Duration::from_secs(5), callbacks.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(callback.body(), &[42]);
+
+        brickd.emit_enumerate("R4c", EnumerationType::Disconnected);
+        let response = tokio::time::timeout(Duration::from_secs(5), enumeration.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(response.enumeration_type, EnumerationType::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_clients() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("R4c", 298).respond(3, MockResponse::disconnect()));
+        let connection = brickd.connect().await.unwrap();
+        assert!(connection
+            .get(Uid::from("R4c"), 3, &[], Duration::from_secs(5))
+            .await
+            .is_err());
+        let reason = tokio::time::timeout(Duration::from_secs(5), connection.closed())
+            .await
+            .unwrap();
+        assert!(matches!(reason, DisconnectReason::ConnectionLost(_)));
+
+        let connection = brickd.connect().await.unwrap();
+        brickd.disconnect_clients();
+        tokio::time::timeout(Duration::from_secs(5), connection.closed())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,443 @@
+//! Tree of the stacks, Bricks and Bricklets behind a connection, built from the enumerate callbacks.
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    time::Duration,
+};
+
+use log::{info, warn};
+use tokio::{
+    sync::{broadcast, watch},
+    task::{AbortHandle, JoinSet},
+};
+use tokio_stream::{
+    wrappers::{BroadcastStream, WatchStream},
+    Stream, StreamExt,
+};
+
+use crate::{
+    base58::Uid,
+    ip_connection::{
+        async_io::{AsyncIpConnection, ConnectionState},
+        EnumerateResponse, EnumerationType, Version,
+    },
+};
+
+/// A device as reported by its last enumeration.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DeviceNode {
+    pub uid: Uid,
+    /// The Brick or Bricklet this device is connected to, zero for the bottommost Brick of a stack.
+    pub connected_uid: Uid,
+    /// For Bricks '0' - '8' (position in stack), for Bricklets 'a' - 'h' (port of the Brick).
+    pub position: char,
+    pub hardware_version: Version,
+    pub firmware_version: Version,
+    pub device_identifier: u16,
+}
+
+impl DeviceNode {
+    pub fn is_brick(&self) -> bool {
+        self.position.is_ascii_digit()
+    }
+}
+
+impl From<&EnumerateResponse> for DeviceNode {
+    fn from(response: &EnumerateResponse) -> Self {
+        DeviceNode {
+            uid: response.uid,
+            connected_uid: response.connected_uid,
+            position: response.position,
+            hardware_version: response.hardware_version,
+            firmware_version: response.firmware_version,
+            device_identifier: response.device_identifier,
+        }
+    }
+}
+
+/// How the topology changed because of an enumeration.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TopologyChange {
+    Added(DeviceNode),
+    /// The device was seen again with a different position, parent or firmware.
+    Updated {
+        previous: DeviceNode,
+        current: DeviceNode,
+    },
+    Removed(DeviceNode),
+}
+
+/// Snapshot of all known devices.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Topology {
+    devices: BTreeMap<Uid, DeviceNode>,
+}
+
+impl Topology {
+    /// Applies an enumeration, returns `None` if nothing changed.
+    pub fn apply(&mut self, response: &EnumerateResponse) -> Option<TopologyChange> {
+        match response.enumeration_type {
+            EnumerationType::Available | EnumerationType::Connected => {
+                let current = DeviceNode::from(response);
+                match self.devices.insert(current.uid, current) {
+                    None => Some(TopologyChange::Added(current)),
+                    Some(previous) if previous != current => {
+                        Some(TopologyChange::Updated { previous, current })
+                    }
+                    Some(_) => None,
+                }
+            }
+            EnumerationType::Disconnected => self
+                .devices
+                .remove(&response.uid)
+                .map(TopologyChange::Removed),
+            EnumerationType::Unknown => None,
+        }
+    }
+
+    pub fn device(&self, uid: impl Into<Uid>) -> Option<&DeviceNode> {
+        self.devices.get(&uid.into())
+    }
+
+    /// All devices ordered by uid.
+    pub fn devices(&self) -> impl Iterator<Item = &DeviceNode> {
+        self.devices.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.devices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.devices.is_empty()
+    }
+
+    /// Devices which are not connected to a known device, usually the bottommost Brick of each stack.
+    pub fn roots(&self) -> impl Iterator<Item = &DeviceNode> {
+        self.devices
+            .values()
+            .filter(|device| !self.devices.contains_key(&device.connected_uid))
+    }
+
+    /// Devices connected to `uid`, ordered by position: Bricks stacked on top of it, then its Bricklets.
+    pub fn children(&self, uid: impl Into<Uid>) -> Vec<&DeviceNode> {
+        let uid = uid.into();
+        let mut children: Vec<_> = self
+            .devices
+            .values()
+            .filter(|device| device.connected_uid == uid && device.uid != uid)
+            .collect();
+        children.sort_by_key(|device| device.position);
+        children
+    }
+
+    /// The Bricks of the stack whose bottommost Brick is `uid`, ordered by position.
+    pub fn stack(&self, uid: impl Into<Uid>) -> Vec<&DeviceNode> {
+        let uid = uid.into();
+        self.device(uid)
+            .into_iter()
+            .chain(
+                self.children(uid)
+                    .into_iter()
+                    .filter(|device| device.is_brick()),
+            )
+            .collect()
+    }
+
+    /// Removes the devices which are not contained in `present`, e.g. because they did not answer
+    /// a complete enumeration.
+    pub fn remove_missing(
+        &mut self,
+        present: impl IntoIterator<Item = Uid>,
+    ) -> Vec<TopologyChange> {
+        let present: BTreeSet<Uid> = present.into_iter().collect();
+        let missing: Vec<Uid> = self
+            .devices
+            .keys()
+            .filter(|uid| !present.contains(uid))
+            .copied()
+            .collect();
+        missing
+            .into_iter()
+            .filter_map(|uid| self.devices.remove(&uid))
+            .map(TopologyChange::Removed)
+            .collect()
+    }
+
+    /// The uids out of `expected` which are not part of the topology, e.g. to detect missing sensors.
+    pub fn missing(&self, expected: impl IntoIterator<Item = Uid>) -> Vec<Uid> {
+        expected
+            .into_iter()
+            .filter(|uid| !self.devices.contains_key(uid))
+            .collect()
+    }
+}
+
+/// How long the enumeration after a reconnect waits for further responses before the devices
+/// which did not answer are removed.
+const RECONNECT_QUIET_PERIOD: Duration = Duration::from_millis(500);
+
+/// Keeps a [`Topology`] up to date with the enumerate callbacks of a connection. The devices are
+/// enumerated on start and again after every reconnect, devices unplugged while the connection was
+/// lost are removed once that enumeration is complete.
+#[derive(Debug)]
+pub struct TopologyWatcher {
+    topology: watch::Receiver<Topology>,
+    changes: broadcast::Sender<TopologyChange>,
+    abort_handle: AbortHandle,
+}
+
+impl TopologyWatcher {
+    pub fn new(connection: AsyncIpConnection) -> Self {
+        let (topology_tx, topology) = watch::channel(Topology::default());
+        let (changes, _) = broadcast::channel(64);
+        let changes_tx = changes.clone();
+        let abort_handle = tokio::spawn(async move {
+            let mut states = connection.state_stream().skip(1);
+            let enumerations = match connection.enumerate().await {
+                Ok(enumerations) => enumerations,
+                Err(error) => {
+                    warn!("Cannot enumerate devices: {error}");
+                    return;
+                }
+            };
+            tokio::pin!(enumerations);
+            let mut reconnects = JoinSet::new();
+            loop {
+                tokio::select! {
+                    response = enumerations.next() => {
+                        let Some(response) = response else {
+                            break;
+                        };
+                        let mut change = None;
+                        topology_tx.send_if_modified(|topology| {
+                            change = topology.apply(&response);
+                            change.is_some()
+                        });
+                        if let Some(change) = change {
+                            // nobody listening for changes is fine
+                            let _ = changes_tx.send(change);
+                        }
+                    }
+                    Some(state) = states.next() => {
+                        if state != ConnectionState::Connected {
+                            continue;
+                        }
+                        // enumerated in a task of its own, the loop keeps applying the enumerate
+                        // callbacks meanwhile, the result of an earlier reconnect is outdated
+                        reconnects.abort_all();
+                        let connection = connection.clone();
+                        reconnects.spawn(async move {
+                            connection.enumerate_all(RECONNECT_QUIET_PERIOD).await
+                        });
+                    }
+                    Some(present) = reconnects.join_next() => {
+                        let present = match present {
+                            Ok(Ok(present)) => present,
+                            Ok(Err(error)) => {
+                                warn!("Cannot enumerate devices after reconnect: {error}");
+                                continue;
+                            }
+                            Err(_) => continue,
+                        };
+                        // applied here as well, the callbacks of the enumeration may still be queued
+                        let mut changes = Vec::new();
+                        topology_tx.send_if_modified(|topology| {
+                            changes.extend(
+                                present.iter().filter_map(|response| topology.apply(response)),
+                            );
+                            changes.extend(
+                                topology.remove_missing(present.iter().map(|response| response.uid)),
+                            );
+                            !changes.is_empty()
+                        });
+                        for change in changes {
+                            let _ = changes_tx.send(change);
+                        }
+                    }
+                }
+            }
+            info!("Topology watcher terminated");
+        })
+        .abort_handle();
+        Self {
+            topology,
+            changes,
+            abort_handle,
+        }
+    }
+
+    /// The current snapshot.
+    pub fn topology(&self) -> Topology {
+        self.topology.borrow().clone()
+    }
+
+    /// Stream of snapshots, starting with the current one.
+    pub fn topology_stream(&self) -> impl Stream<Item = Topology> {
+        WatchStream::new(self.topology.clone())
+    }
+
+    /// Stream of the changes from now on.
+    pub fn changes(&self) -> impl Stream<Item = TopologyChange> {
+        BroadcastStream::new(self.changes.subscribe()).filter_map(|change| change.ok())
+    }
+}
+
+impl Drop for TopologyWatcher {
+    fn drop(&mut self) {
+        self.abort_handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio_stream::StreamExt;
+
+    use crate::{
+        base58::Uid,
+        ip_connection::{
+            async_io::{AsyncIpConnection, ReconnectPolicy},
+            EnumerationType, Version,
+        },
+        mock::{MockBrickd, MockDevice},
+        topology::{TopologyChange, TopologyWatcher},
+    };
+
+    #[tokio::test]
+    async fn test_topology_follows_enumerations() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("6qzRzc", 13));
+        brickd.add_device(MockDevice::new("XYZ", 13).connected_to("6qzRzc", '1'));
+        brickd.add_device(MockDevice::new("R4c", 298).connected_to("6qzRzc", 'b'));
+        brickd.add_device(MockDevice::new("Tmp", 2113).connected_to("6qzRzc", 'a'));
+        let watcher = TopologyWatcher::new(brickd.connect().await.unwrap());
+        let mut snapshots = watcher.topology_stream();
+        let topology = loop {
+            let topology = snapshots.next().await.unwrap();
+            if topology.len() == 4 {
+                break topology;
+            }
+        };
+        let roots: Vec<_> = topology.roots().map(|device| device.uid).collect();
+        assert_eq!(roots, [Uid::from("6qzRzc")]);
+        let children: Vec<_> = topology
+            .children("6qzRzc")
+            .iter()
+            .map(|device| device.position)
+            .collect();
+        assert_eq!(children, ['1', 'a', 'b']);
+        assert_eq!(topology.stack("6qzRzc").len(), 2);
+        assert!(topology.missing([Uid::from("R4c")]).is_empty());
+
+        let mut changes = watcher.changes();
+        brickd.emit_enumerate("R4c", EnumerationType::Disconnected);
+        let Some(TopologyChange::Removed(removed)) = changes.next().await else {
+            panic!("Expected removal");
+        };
+        assert_eq!(removed.uid, Uid::from("R4c"));
+        assert_eq!(
+            watcher.topology().missing([Uid::from("R4c")]),
+            [Uid::from("R4c")]
+        );
+
+        brickd.add_device(
+            MockDevice::new("Tmp", 2113)
+                .connected_to("6qzRzc", 'a')
+                .firmware_version(Version::new(2, 0, 5)),
+        );
+        brickd.emit_enumerate("Tmp", EnumerationType::Connected);
+        let Some(TopologyChange::Updated { previous, current }) = changes.next().await else {
+            panic!("Expected update");
+        };
+        assert_eq!(previous.firmware_version, Version::new(2, 0, 0));
+        assert_eq!(current.firmware_version, Version::new(2, 0, 5));
+    }
+
+    #[tokio::test]
+    async fn test_device_removed_during_reconnect() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("6qzRzc", 13));
+        brickd.add_device(MockDevice::new("R4c", 298).connected_to("6qzRzc", 'b'));
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(10),
+            ..ReconnectPolicy::default()
+        };
+        let connection = AsyncIpConnection::new_with_reconnect(brickd.addr(), policy)
+            .await
+            .unwrap();
+        let watcher = TopologyWatcher::new(connection);
+        let mut snapshots = watcher.topology_stream();
+        while snapshots.next().await.unwrap().len() < 2 {}
+
+        let mut changes = watcher.changes();
+        brickd.remove_device("R4c");
+        brickd.add_device(MockDevice::new("Tmp", 2113).connected_to("6qzRzc", 'a'));
+        brickd.disconnect_clients();
+        // the enumerate callbacks are applied while the enumeration after the reconnect is running
+        let change = tokio::time::timeout(Duration::from_secs(5), changes.next())
+            .await
+            .unwrap();
+        let Some(TopologyChange::Added(added)) = change else {
+            panic!("Expected addition, got {change:?}");
+        };
+        assert_eq!(added.uid, Uid::from("Tmp"));
+        let change = tokio::time::timeout(Duration::from_secs(5), changes.next())
+            .await
+            .unwrap();
+        let Some(TopologyChange::Removed(removed)) = change else {
+            panic!("Expected removal, got {change:?}");
+        };
+        assert_eq!(removed.uid, Uid::from("R4c"));
+        assert_eq!(
+            watcher
+                .topology()
+                .missing([Uid::from("6qzRzc"), Uid::from("R4c")]),
+            [Uid::from("R4c")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_callback_stream_survives_enumerations() {
+        let brickd = MockBrickd::start().await.unwrap();
+        brickd.add_device(MockDevice::new("6qzRzc", 13));
+        brickd.add_device(MockDevice::new("R4c", 298).connected_to("6qzRzc", 'b'));
+        brickd.add_device(MockDevice::new("Tmp", 2113).connected_to("6qzRzc", 'a'));
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(10),
+            ..ReconnectPolicy::default()
+        };
+        let connection = AsyncIpConnection::new_with_reconnect(brickd.addr(), policy)
+            .await
+            .unwrap();
+        let mut callbacks = connection.callback_stream(Uid::from("R4c"), 7).await;
+        let watcher = TopologyWatcher::new(connection);
+        let mut snapshots = watcher.topology_stream();
+        while snapshots.next().await.unwrap().len() < 3 {}
+        brickd.emit_callback("R4c", 7, &[1]);
+        let callback = tokio::time::timeout(Duration::from_secs(5), callbacks.next())
+            .await
+            .unwrap();
+        assert_eq!(callback.unwrap().body(), &[1]);
+
+        let mut changes = watcher.changes();
+        brickd.remove_device("Tmp");
+        brickd.disconnect_clients();
+        // the removal is only reported once the enumeration after the reconnect is complete
+        let change = tokio::time::timeout(Duration::from_secs(5), changes.next())
+            .await
+            .unwrap();
+        assert!(matches!(change, Some(TopologyChange::Removed(_))));
+        brickd.emit_callback("R4c", 7, &[2]);
+        let callback = tokio::time::timeout(Duration::from_secs(5), callbacks.next())
+            .await
+            .unwrap();
+        assert_eq!(callback.unwrap().body(), &[2]);
+
+        brickd.emit_enumerate("R4c", EnumerationType::Disconnected);
+        let end = tokio::time::timeout(Duration::from_secs(5), callbacks.next())
+            .await
+            .unwrap();
+        assert!(end.is_none());
+    }
+}
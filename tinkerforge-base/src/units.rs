@@ -0,0 +1,177 @@
+//! Physical quantities for the fields of bindings generated with unit types.
+use std::{
+    fmt::{Debug, Display, Formatter},
+    marker::PhantomData,
+};
+
+use crate::byte_converter::{FromByteSlice, ToBytes};
+
+/// A physical unit, implemented by the unit types in the `units` module of the generated bindings.
+pub trait Unit {
+    /// Symbol of the unit, e.g. `°C`.
+    const SYMBOL: &'static str;
+}
+
+/// A number type a quantity can be transferred as.
+pub trait RawValue: Copy {
+    fn to_f64(self) -> f64;
+    /// Converts `value` to the nearest raw value, saturating at the bounds of integer types.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_raw_value {
+    ($($integer:ty),*) => {
+        $(
+            impl RawValue for $integer {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value.round() as $integer
+                }
+            }
+        )*
+    };
+}
+
+impl_raw_value!(u8, i8, u16, i16, u32, i32, u64, i64);
+
+impl RawValue for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+/// A value transferred as `Raw` in multiples of `NUMERATOR / DENOMINATOR` of the unit `U`, e.g. a
+/// temperature in tenths of a degree Celsius is a `Quantity<i16, DegreeCelsius, 1, 10>`.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Quantity<Raw, U, const NUMERATOR: u32, const DENOMINATOR: u32> {
+    raw: Raw,
+    unit: PhantomData<U>,
+}
+
+impl<Raw: RawValue, U: Unit, const NUMERATOR: u32, const DENOMINATOR: u32>
+    Quantity<Raw, U, NUMERATOR, DENOMINATOR>
+{
+    pub const fn from_raw(raw: Raw) -> Self {
+        Quantity {
+            raw,
+            unit: PhantomData,
+        }
+    }
+
+    /// Returns the quantity for `value` in `U`, rounded to the resolution of the device.
+    pub fn from_value(value: f64) -> Self {
+        Self::from_raw(Raw::from_f64(value * DENOMINATOR as f64 / NUMERATOR as f64))
+    }
+
+    /// The number as transferred to or from the device.
+    pub fn raw(&self) -> Raw {
+        self.raw
+    }
+
+    /// The value in `U`.
+    pub fn value(&self) -> f64 {
+        self.raw.to_f64() * NUMERATOR as f64 / DENOMINATOR as f64
+    }
+}
+
+impl<Raw: RawValue, U: Unit, const NUMERATOR: u32, const DENOMINATOR: u32> From<Raw>
+    for Quantity<Raw, U, NUMERATOR, DENOMINATOR>
+{
+    fn from(raw: Raw) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl<Raw: RawValue, U: Unit, const NUMERATOR: u32, const DENOMINATOR: u32> Display
+    for Quantity<Raw, U, NUMERATOR, DENOMINATOR>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.value(), U::SYMBOL)
+    }
+}
+
+impl<Raw: RawValue + Debug, U: Unit, const NUMERATOR: u32, const DENOMINATOR: u32> Debug
+    for Quantity<Raw, U, NUMERATOR, DENOMINATOR>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}({:?})", self.value(), U::SYMBOL, self.raw)
+    }
+}
+
+impl<Raw: RawValue + ToBytes, U: Unit, const NUMERATOR: u32, const DENOMINATOR: u32> ToBytes
+    for Quantity<Raw, U, NUMERATOR, DENOMINATOR>
+{
+    fn write_to_slice(&self, target: &mut [u8]) -> usize {
+        self.raw.write_to_slice(target)
+    }
+}
+
+impl<const N: usize, Raw, U, const NUMERATOR: u32, const DENOMINATOR: u32> ToBytes
+    for [Quantity<Raw, U, NUMERATOR, DENOMINATOR>; N]
+where
+    Raw: RawValue + ToBytes + FromByteSlice,
+    U: Unit,
+{
+    fn write_to_slice(&self, target: &mut [u8]) -> usize {
+        let component_size = Raw::bytes_expected();
+        for (i, quantity) in self.iter().enumerate() {
+            quantity.write_to_slice(&mut target[i * component_size..(i + 1) * component_size]);
+        }
+        component_size * N
+    }
+}
+
+impl<Raw: RawValue + FromByteSlice, U: Unit, const NUMERATOR: u32, const DENOMINATOR: u32>
+    FromByteSlice for Quantity<Raw, U, NUMERATOR, DENOMINATOR>
+{
+    fn from_le_byte_slice(bytes: &[u8]) -> Self {
+        Self::from_raw(Raw::from_le_byte_slice(bytes))
+    }
+
+    fn bytes_expected() -> usize {
+        Raw::bytes_expected()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        byte_converter::{FromByteSlice, ToBytes},
+        units::{Quantity, Unit},
+    };
+
+    #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+    struct DegreeCelsius;
+
+    impl Unit for DegreeCelsius {
+        const SYMBOL: &'static str = "°C";
+    }
+
+    type Temperature = Quantity<i16, DegreeCelsius, 1, 10>;
+
+    #[test]
+    fn test_scaling() {
+        let temperature = Temperature::from_le_byte_slice(&(-235i16).to_le_bytes());
+        assert_eq!(temperature.value(), -23.5);
+        assert_eq!(temperature.to_string(), "-23.5 °C");
+        assert_eq!(format!("{temperature:?}"), "-23.5 °C(-235)");
+        assert_eq!(Temperature::from_value(21.04).raw(), 210);
+
+        let mut buffer = [0; 4];
+        assert_eq!(
+            [Temperature::from_raw(1), Temperature::from_raw(-2)].write_to_slice(&mut buffer),
+            4
+        );
+        assert_eq!(
+            <[Temperature; 2]>::from_le_byte_slice(&buffer),
+            [1.into(), (-2).into()]
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Conversion of the reStructuredText-like markup of the Tinkerforge documentation to Markdown for rustdoc.
+use convert_case::{Case, Casing};
+
+use crate::json_model::{JsonElementExtra, JsonLocale, JsonMinMaxEntry, JsonRange, JsonScale};
+
+/// Renders the documentation of a function or callback as Markdown.
+///
+/// References to other functions and callbacks become the names of the generated methods,
+/// `.. note::` blocks get a bold label and `.. csv-table::` blocks become Markdown tables.
+pub fn render(text: &str, locale: JsonLocale) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut output = Vec::<String>::new();
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+        let trimmed = line.trim();
+        if let Some(directive) = trimmed
+            .strip_prefix(".. ")
+            .and_then(|rest| rest.split_once("::"))
+        {
+            let (name, argument) = directive;
+            let mut block = Vec::new();
+            while index < lines.len()
+                && (lines[index].trim().is_empty() || lines[index].starts_with(char::is_whitespace))
+            {
+                block.push(lines[index].trim());
+                index += 1;
+            }
+            while block.last().is_some_and(|line| line.is_empty()) {
+                block.pop();
+            }
+            if name == "csv-table" {
+                output.extend(render_csv_table(&block));
+            } else {
+                output.push(
+                    format!("**{}:** {}", directive_label(name, locale), argument.trim())
+                        .trim_end()
+                        .to_string(),
+                );
+                output.extend(
+                    block
+                        .iter()
+                        .skip_while(|line| line.is_empty())
+                        .map(|line| line.to_string()),
+                );
+            }
+            output.push(String::new());
+        } else if trimmed.len() > 2
+            && trimmed.starts_with('|')
+            && trimmed.ends_with('|')
+            && !trimmed.contains(' ')
+        {
+            // substitutions like |device_identifier_constant| refer to texts of the other bindings
+        } else {
+            output.push(line.to_string());
+        }
+    }
+    let text = render_roles(&output.join("\n")).replace("``", "`");
+    text.trim_matches('\n').to_string()
+}
+
+fn directive_label(name: &str, locale: JsonLocale) -> String {
+    match (name, locale) {
+        ("note", JsonLocale::En) => "Note".to_string(),
+        ("note", JsonLocale::De) => "Hinweis".to_string(),
+        ("warning", JsonLocale::En) => "Warning".to_string(),
+        ("warning", JsonLocale::De) => "Warnung".to_string(),
+        (name, _) => name.to_case(Case::Title),
+    }
+}
+
+/// Replaces `:role:`content`` by Markdown.
+fn render_roles(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(':') {
+        output.push_str(&rest[..start]);
+        let candidate = &rest[start + 1..];
+        let role_end = candidate
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(candidate.len());
+        let role = &candidate[..role_end];
+        let after_role = &candidate[role_end..];
+        let content = (!role.is_empty())
+            .then(|| after_role.strip_prefix(":`"))
+            .flatten()
+            .and_then(|after| {
+                after
+                    .find('`')
+                    .map(|end| (&after[..end], &after[end + 1..]))
+            });
+        match content {
+            Some((content, remaining)) => {
+                output.push_str(&render_role(role, content));
+                rest = remaining;
+            }
+            None => {
+                output.push(':');
+                rest = candidate;
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+fn render_role(role: &str, content: &str) -> String {
+    match role {
+        "func" => format!("`{}`", content.to_case(Case::Snake)),
+        "cb" => format!("`{}_stream`", content.to_case(Case::Snake)),
+        "word" => content.to_string(),
+        // :ref:`text <target>` links to the Tinkerforge website
+        "ref" => content
+            .split_once(" <")
+            .map_or(content, |(text, _)| text)
+            .to_string(),
+        _ => format!("`{content}`"),
+    }
+}
+
+fn render_csv_table(block: &[&str]) -> Vec<String> {
+    let mut header = None;
+    let mut rows = Vec::new();
+    for line in block {
+        if let Some(columns) = line.strip_prefix(":header:") {
+            header = Some(csv_values(columns));
+        } else if !line.is_empty() && !line.starts_with(':') {
+            rows.push(csv_values(line));
+        }
+    }
+    let columns = header.as_ref().or(rows.first()).map_or(0, |row| row.len());
+    let mut table = Vec::with_capacity(rows.len() + 2);
+    table.push(table_row(
+        header.as_deref().unwrap_or(&vec![String::new(); columns]),
+    ));
+    table.push(table_row(&vec!["---".to_string(); columns]));
+    table.extend(rows.iter().map(|row| table_row(row)));
+    table
+}
+
+/// Splits a line of quoted, comma separated values.
+fn csv_values(line: &str) -> Vec<String> {
+    line.split('"')
+        .skip(1)
+        .step_by(2)
+        .map(|value| value.replace('|', "\\|"))
+        .collect()
+}
+
+fn table_row(values: &[String]) -> String {
+    format!("| {} |", values.join(" | "))
+}
+
+/// Documents unit, scale and valid range of a field, `None` if there is nothing to tell.
+pub fn field_doc(extra: &JsonElementExtra, locale: JsonLocale) -> Option<String> {
+    let (unit_label, scale_label, range_label) = match locale {
+        JsonLocale::En => ("Unit", "Scale", "Range"),
+        JsonLocale::De => ("Einheit", "Skalierung", "Wertebereich"),
+    };
+    let mut lines = Vec::new();
+    let scale = match extra.scale {
+        JsonScale::Fraction {
+            numerator,
+            denominator,
+        } if numerator != denominator => Some(if denominator == 1 {
+            numerator.to_string()
+        } else {
+            format!("{numerator}/{denominator}")
+        }),
+        _ => None,
+    };
+    match (&extra.unit, scale) {
+        (Some(unit), scale) => {
+            let title = unit.title.get(locale);
+            let scaled_symbol = match scale {
+                Some(scale) => format!("{scale} {}", unit.symbol),
+                None => unit.symbol.to_string(),
+            };
+            lines.push(format!("{unit_label}: {scaled_symbol} ({title})"));
+        }
+        (None, Some(scale)) => lines.push(format!("{scale_label}: {scale}")),
+        (None, None) => {}
+    }
+    if let Some(JsonRange::Entries(entries)) = &extra.range {
+        let ranges: Vec<String> = entries
+            .iter()
+            .map(|entry| match entry {
+                JsonMinMaxEntry::Integer { minimum, maximum } => format!("{minimum} - {maximum}"),
+                JsonMinMaxEntry::Character { minimum, maximum } => {
+                    format!("'{minimum}' - '{maximum}'")
+                }
+                JsonMinMaxEntry::Float { minimum, maximum } => format!("{minimum} - {maximum}"),
+            })
+            .collect();
+        lines.push(format!("{range_label}: {}", ranges.join(", ")));
+    }
+    (!lines.is_empty()).then(|| lines.join("\n\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{doc::render, json_model::JsonLocale};
+
+    #[test]
+    fn test_render() {
+        let text = "
+Sets the thresholds for the :cb:`Stack Current Reached` callback, see
+:func:`Set Debounce Period` and the :word:`parameter` ``option``.
+
+.. csv-table::
+ :header: \"Option\", \"Description\"
+ :widths: 10, 100
+
+ \"'x'\",    \"Callback is turned off\"
+ \"'<'\",    \"Callback is triggered when the current is smaller, e.g. 1 | 2\"
+
+.. note::
+ The device identifier can be found :ref:`here <device_identifier>`.
+|device_identifier_constant|
+";
+        assert_eq!(
+            render(text, JsonLocale::En),
+            "Sets the thresholds for the `stack_current_reached_stream` callback, see
+`set_debounce_period` and the parameter `option`.
+
+| Option | Description |
+| --- | --- |
+| 'x' | Callback is turned off |
+| '<' | Callback is triggered when the current is smaller, e.g. 1 \\| 2 |
+
+**Note:**
+The device identifier can be found here."
+        );
+    }
+}
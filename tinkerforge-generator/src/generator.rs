@@ -1,27 +1,29 @@
+use std::path::PathBuf;
 use std::{
-    collections::HashSet,
+    collections::{BTreeMap, HashSet},
     default::Default,
     env::{self, current_dir},
     fs, path,
 };
-use std::path::PathBuf;
 
 use convert_case::{Case, Casing};
 use prettyplease::unparse;
-use proc_macro2::Span;
-use quote::ToTokens;
+use proc_macro2::{Literal, Span};
+use quote::{quote, ToTokens};
 use syn::{
-    Arm,
-    Block,
-    Expr,
-    ExprMatch, Field, FieldMutability, FieldValue, File, Ident, ImplItem, ImplItemFn, Item, ItemImpl,
-    ItemMod, Lit, parse_quote, Path, PathArguments, PathSegment, punctuated::Punctuated, Stmt, token::{Comma, PathSep, Pub}, Type,
-    TypePath, Variant, Visibility,
+    parse_quote,
+    punctuated::Punctuated,
+    token::{Comma, PathSep, Pub},
+    Arm, Attribute, Block, Expr, ExprMatch, Field, FieldMutability, FieldValue, File, Ident,
+    ImplItem, ImplItemFn, Item, ItemImpl, ItemMod, Lit, Path, PathArguments, PathSegment, Stmt,
+    TraitItem, Type, TypePath, Variant, Visibility,
 };
 
+use crate::doc;
 use crate::json_model::{
-    JsonCategory, JsonConstantGroup, JsonContent, JsonDirection, JsonElement, JsonElementType,
-    JsonLevel, JsonLocale, JsonPacketDescription, JsonPacketType, JsonRole,
+    JsonAnyDefaultValue, JsonCategory, JsonConstantGroup, JsonContent, JsonDirection, JsonElement,
+    JsonElementExtra, JsonElementType, JsonLevel, JsonLocale, JsonPacketDescription,
+    JsonPacketType, JsonRole, JsonScale, JsonUnit,
 };
 
 pub fn parse_json() {
@@ -36,8 +38,27 @@ pub fn parse_json() {
     fs::write(dest_path, unparse(&file)).expect("Cannot write source file");
 }
 
+/// Switches for optional parts of the generated code.
+#[derive(Clone, Debug, Default)]
+pub struct GeneratorOptions {
+    /// Emit a `*Simulator` trait per device to answer requests in tests, see `tinkerforge_base::mock`.
+    pub simulators: bool,
+    /// Emit a `DEVICE_DESCRIPTION` per device and the list `DEVICE_DESCRIPTIONS` of all of them,
+    /// which allow `tinkerforge_base::decoder` to render packets in readable form.
+    pub decode_tables: bool,
+    /// Language of the generated documentation, English by default.
+    pub locale: JsonLocale,
+    /// Type fields with a unit as `tinkerforge_base::units::Quantity` of the unit types in the module
+    /// `units` instead of raw numbers in the scale of the device.
+    pub unit_types: bool,
+}
+
 pub fn process_directory(bindings_dir: PathBuf) -> File {
-    generate_code(
+    process_directory_with_options(bindings_dir, &GeneratorOptions::default())
+}
+
+pub fn process_directory_with_options(bindings_dir: PathBuf, options: &GeneratorOptions) -> File {
+    generate_code_with_options(
         bindings_dir
             .read_dir()
             .expect("Cannot read directory")
@@ -55,16 +76,30 @@ pub fn process_directory(bindings_dir: PathBuf) -> File {
             .map(|json_file| {
                 serde_json::from_reader::<_, JsonContent>(json_file).expect("Cannot parse json")
             }),
+        options,
     )
 }
 
-pub fn generate_code<IT: Iterator<Item=JsonContent>>(file_contents: IT) -> File {
+pub fn generate_code<IT: Iterator<Item = JsonContent>>(file_contents: IT) -> File {
+    generate_code_with_options(file_contents, &GeneratorOptions::default())
+}
+
+pub fn generate_code_with_options<IT: Iterator<Item = JsonContent>>(
+    file_contents: IT,
+    options: &GeneratorOptions,
+) -> File {
     let mut bindings_content = Vec::new();
 
     let mut device_variants: Punctuated<Variant, Comma> = Default::default();
     let mut device_encode_arms = Vec::new();
     let mut device_parse_arms = Vec::new();
     let mut device_name_arms = Vec::new();
+    let mut device_descriptions = Vec::<Expr>::new();
+    let mut any_device_variants: Punctuated<Variant, Comma> = Default::default();
+    let mut any_device_new_arms = Vec::<Arm>::new();
+    let mut any_device_uid_arms = Vec::<Arm>::new();
+    let mut any_device_identifier_arms = Vec::<Arm>::new();
+    let mut units = BTreeMap::new();
 
     for tf_device in file_contents {
         if tf_device.device_identifier < 1 {
@@ -104,6 +139,19 @@ pub fn generate_code<IT: Iterator<Item=JsonContent>>(file_contents: IT) -> File
             .push(parse_quote!(#device_id => Ok(DeviceIdentifier::#device_struct_name)));
         device_name_arms
             .push(parse_quote!(DeviceIdentifier::#device_struct_name =>#raw_package_name));
+        any_device_variants
+            .push(parse_quote!(#device_struct_name(#package_ident::#device_struct_name)));
+        any_device_new_arms.push(parse_quote!(
+            #device_id => {
+                let mut device = #package_ident::#device_struct_name::new(response.uid, connection);
+                device.set_firmware_version(Some(response.firmware_version));
+                Some(AnyDevice::#device_struct_name(device))
+            }
+        ));
+        any_device_uid_arms
+            .push(parse_quote!(AnyDevice::#device_struct_name(device) => device.uid()));
+        any_device_identifier_arms
+            .push(parse_quote!(AnyDevice::#device_struct_name(_) => DeviceIdentifier::#device_struct_name));
 
         let mut items = Vec::new();
         items.push(parse_quote!(
@@ -138,22 +186,67 @@ pub fn generate_code<IT: Iterator<Item=JsonContent>>(file_contents: IT) -> File
                 pub fn uid(&self)->tinkerforge_base::base58::Uid{
                     self.device.uid()
                 }
+                /// The firmware version functions are checked against before sending, `None` if the check is disabled.
+                pub fn firmware_version(&self)->Option<tinkerforge_base::ip_connection::Version>{
+                    self.device.firmware_version()
+                }
+                /// Enables the firmware check with the version reported by an enumeration, `None` disables it again.
+                pub fn set_firmware_version(&mut self, firmware_version: Option<tinkerforge_base::ip_connection::Version>){
+                    self.device.set_firmware_version(firmware_version)
+                }
+                /// Queries the firmware version of the device and enables the firmware check with it.
+                pub async fn load_firmware_version(&mut self)->Result<tinkerforge_base::ip_connection::Version, tinkerforge_base::error::TinkerforgeError>{
+                    self.device.load_firmware_version().await
+                }
             }
         );
         let mut already_declared_constants = HashSet::new();
+        let mut device_methods = DeviceMethods::default();
         for packet_description in tf_device.packets.iter() {
             if packet_description.level == JsonLevel::High {
                 continue;
             }
+            if options.unit_types {
+                collect_units(packet_description, &mut units);
+            }
+            let high_level_description = tf_device.packets.iter().find(|high_level| {
+                high_level.level == JsonLevel::High
+                    && high_level.r#type == packet_description.r#type
+                    && high_level.function_id == packet_description.function_id
+            });
             let function = generate_packet_element_item(
                 &mut items,
                 packet_description,
+                high_level_description,
                 &package_path,
                 &mut already_declared_constants,
+                &mut device_methods,
+                options,
             );
             device_impl.items.push(ImplItem::Fn(function));
         }
+        device_impl.items.extend(
+            device_methods
+                .high_level_functions
+                .into_iter()
+                .map(ImplItem::Fn),
+        );
         items.push(Item::Impl(device_impl));
+        if options.simulators {
+            items.push(generate_simulator_trait(
+                &device_st,
+                device_id,
+                &device_methods.simulator_methods,
+            ));
+        }
+        if options.decode_tables {
+            items.push(generate_device_description(
+                &device_st,
+                device_id,
+                &tf_device.packets,
+            ));
+            device_descriptions.push(parse_quote!(#package_ident::DEVICE_DESCRIPTION));
+        }
         bindings_content.push(Item::Mod(ItemMod {
             attrs: vec![],
             vis: Visibility::Public(Default::default()),
@@ -165,6 +258,15 @@ pub fn generate_code<IT: Iterator<Item=JsonContent>>(file_contents: IT) -> File
         }));
     }
     device_parse_arms.push(parse_quote!(_ => Err(())));
+    if options.unit_types {
+        bindings_content.push(generate_units_module(&units));
+    }
+    if options.decode_tables {
+        bindings_content.push(parse_quote!(
+            /// Descriptions of all devices, to be passed to `tinkerforge_base::decoder::PacketDecoder::new`.
+            pub static DEVICE_DESCRIPTIONS: &[tinkerforge_base::decoder::DeviceDescription] = &[#(#device_descriptions),*];
+        ));
+    }
 
     bindings_content.push(Item::Enum(parse_quote!(
         #[derive(Copy,Clone,Eq,PartialEq,Debug,Ord, PartialOrd)]
@@ -197,6 +299,38 @@ pub fn generate_code<IT: Iterator<Item=JsonContent>>(file_contents: IT) -> File
             }
         }
     )));
+    bindings_content.push(parse_quote!(
+        /// Binding of any of the generated devices, see [`AnyDevice::new`].
+        #[derive(Clone, Debug)]
+        pub enum AnyDevice {
+            #any_device_variants
+        }
+    ));
+    any_device_new_arms.push(parse_quote!(_ => None));
+    let new_match = ExprMatch {
+        attrs: vec![],
+        match_token: Default::default(),
+        expr: Box::new(parse_quote!(response.device_identifier)),
+        brace_token: Default::default(),
+        arms: any_device_new_arms,
+    };
+    let uid_match = match_self(any_device_uid_arms);
+    let identifier_match = match_self(any_device_identifier_arms);
+    bindings_content.push(Item::Impl(parse_quote!(
+        impl AnyDevice {
+            /// Creates the binding matching the device identifier of an enumeration, `None` for devices without binding.
+            /// The firmware check is enabled with the firmware version of the enumeration.
+            pub fn new(response: &tinkerforge_base::ip_connection::EnumerateResponse, connection: tinkerforge_base::ip_connection::async_io::AsyncIpConnection) -> Option<AnyDevice> {
+                #new_match
+            }
+            pub fn uid(&self) -> tinkerforge_base::base58::Uid {
+                #uid_match
+            }
+            pub fn device_identifier(&self) -> DeviceIdentifier {
+                #identifier_match
+            }
+        }
+    )));
     File {
         shebang: None,
         attrs: vec![],
@@ -217,20 +351,18 @@ fn match_val(device_encode_arms: Vec<Arm>) -> ExprMatch {
 fn generate_packet_element_item(
     items: &mut Vec<Item>,
     packet_description: &JsonPacketDescription,
+    high_level_description: Option<&JsonPacketDescription>,
     base_path: &Path,
     already_declared_constants: &mut HashSet<Box<str>>,
+    device_methods: &mut DeviceMethods,
+    options: &GeneratorOptions,
 ) -> ImplItemFn {
+    let locale = options.locale;
     let packet_name = packet_description.name.as_ref().to_case(Case::UpperCamel);
     let packet_type = &packet_description.r#type;
     let function_id = packet_description.function_id;
-    let doc = &packet_description.doc;
-
-    let doc_de = doc
-        .text
-        .0
-        .get(&JsonLocale::De)
-        .map(|v| v.as_ref())
-        .unwrap_or_default();
+    let doc = doc::render(packet_description.doc.text.get(locale), locale);
+    let doc_attr = doc_attribute(&doc);
     println!("Packet: {packet_name}");
 
     let mut fields = parse_packet_elements(
@@ -238,17 +370,22 @@ fn generate_packet_element_item(
         base_path,
         items,
         already_declared_constants,
+        options,
     );
     match packet_type {
         JsonPacketType::Function => generate_element_function(
             items,
             packet_description,
+            high_level_description,
             base_path,
-            &packet_name,
             &mut fields,
+            device_methods,
+            options,
         ),
         JsonPacketType::Callback => {
             let out_fields = &mut fields.out_fields;
+            let stream_read = high_level_description
+                .and_then(|high_level| StreamRead::parse(high_level, out_fields, locale));
             let function_name = create_ident(&format!(
                 "{}_stream",
                 packet_description.name.as_ref().to_case(Case::Snake)
@@ -259,7 +396,7 @@ fn generate_packet_element_item(
                     .await
                     .map(|_| ())});
                 parse_quote!(
-                    #[doc = #doc_de]
+                    #doc_attr
                     pub async fn #function_name(&mut self) -> impl futures_core::Stream<Item = ()>
                         #function_block
                 )
@@ -280,13 +417,24 @@ fn generate_packet_element_item(
                         }
                 );
                 parse_quote!(
-                    #[doc = #doc_de]
+                    #doc_attr
                     pub async fn #function_name(&mut self) -> impl futures_core::Stream<Item = #struct_name>
                         #function_block
                 )
             } else {
                 let struct_name: Ident = create_ident(&format!("{packet_name}Callback"));
                 append_data_object(items, out_fields, &struct_name);
+                if let Some(stream_read) = stream_read {
+                    device_methods
+                        .high_level_functions
+                        .push(generate_stream_callback_function(
+                            items,
+                            &stream_read,
+                            base_path,
+                            &function_name,
+                            &parse_quote!(#base_path::#struct_name),
+                        ));
+                }
                 let function_block: Block = parse_quote!({
                            self.device
                             .get_callback_receiver(#function_id)
@@ -294,7 +442,7 @@ fn generate_packet_element_item(
                             .map(|p| #struct_name::from_le_byte_slice(p.body()))}
                 );
                 parse_quote!(
-                    #[doc = #doc_de]
+                    #doc_attr
                     pub async fn #function_name(&mut self) -> impl futures_core::Stream<Item = #base_path::#struct_name>
                         #function_block
                 )
@@ -308,18 +456,17 @@ const LOW_LEVEL_SUFFIX: &str = " Low Level";
 fn generate_element_function(
     items: &mut Vec<Item>,
     packet_description: &JsonPacketDescription,
+    high_level_description: Option<&JsonPacketDescription>,
     base_path: &Path,
-    packet_name: &str,
     fields: &mut ParsedPacketFields,
+    device_methods: &mut DeviceMethods,
+    options: &GeneratorOptions,
 ) -> ImplItemFn {
+    let locale = options.locale;
+    let packet_name = &packet_description.name.as_ref().to_case(Case::UpperCamel);
     let function_id = packet_description.function_id;
-    let doc = &packet_description.doc;
-    let doc_de = doc
-        .text
-        .0
-        .get(&JsonLocale::De)
-        .map(|v| v.as_ref())
-        .unwrap_or_default();
+    let doc = doc::render(packet_description.doc.text.get(locale), locale);
+    let doc_attr = doc_attribute(&doc);
 
     let in_fields = &mut fields.in_fields;
     let out_fields = &mut fields.out_fields;
@@ -354,8 +501,11 @@ fn generate_element_function(
                             struct_fields.push(field.0.clone());
                         }
                         Some(JsonRole::StreamChunkData) => {
-                            writer_statements
-                                .push(parse_quote!(i+=self.data.write_to_slice(&mut target[i..]);));
+                            // the last chunk is padded, the device expects chunks of the full size
+                            let increment = field.size();
+                            writer_statements.push(parse_quote!(target[i..i+#increment].fill(0);));
+                            writer_statements.push(parse_quote!(self.data.write_to_slice(&mut target[i..i+#increment]);));
+                            writer_statements.push(parse_quote!(i+=#increment;));
                             data_field = Some(field.1);
                         }
                         Some(JsonRole::StreamLength) => {
@@ -404,6 +554,15 @@ fn generate_element_function(
                             }
                         ));
                     }
+                    // the slice only needs the request to write its extra fields
+                    let (slice_request_field, slice_request_value) = if struct_fields.is_empty() {
+                        (None, None)
+                    } else {
+                        (
+                            Some(quote!(request: &'r #high_level_struct_name<'r>,)),
+                            Some(quote!(request: self.request,)),
+                        )
+                    };
                     items.push(parse_quote!(
                         pub struct #high_level_iterator_name<'r> {
                             request: &'r #high_level_struct_name<'r>,
@@ -434,7 +593,7 @@ fn generate_element_function(
                                     self.offset += packet_length;
                                     let data = &self.request.data[slice_offset as usize..slice_offset as usize + packet_length as usize];
                                     Some(#high_level_slice_name {
-                                        request: self.request,
+                                        #slice_request_value
                                         offset: slice_offset,
                                         length,
                                         data,
@@ -445,7 +604,7 @@ fn generate_element_function(
                     ));
                     items.push(parse_quote!(
                         pub struct #high_level_slice_name<'r> {
-                            request: &'r #high_level_struct_name<'r>,
+                            #slice_request_field
                             offset: #offset_type,
                             length: #length_type,
                             data: &'r [#element_type],
@@ -461,10 +620,71 @@ fn generate_element_function(
                                 #write_fields
                         }
                     ));
+                    let chunk_written = out_fields
+                        .iter()
+                        .find(|(_, element)| element.role == Some(JsonRole::StreamChunkWritten))
+                        .and_then(|(field, _)| field.ident.clone());
+                    let extra_fields: Vec<Field> = out_fields
+                        .iter()
+                        .filter(|(_, element)| element.role.is_none())
+                        .map(|(field, _)| field.clone())
+                        .collect();
+                    let (response_type, response_size, _) =
+                        generate_response_type(items, out_fields, base_path, packet_name);
+                    device_methods.simulator_methods.push(SimulatorMethod {
+                        function_id,
+                        name: create_ident(&packet_description.name.as_ref().to_case(Case::Snake)),
+                        doc: doc.clone(),
+                        request: Some((parse_quote!(#base_path::#struct_name), size)),
+                        response: (response_size > 0)
+                            .then_some((response_type.clone(), response_size)),
+                    });
                     let function_name = create_ident(&stripped_function_name);
+                    let firmware_check = generate_firmware_check(packet_description);
+                    if let Some(chunk_written) = chunk_written {
+                        let short_write = ShortWrite {
+                            high_level_name: high_level_description
+                                .map(|high_level| high_level.name.as_ref())
+                                .unwrap_or(stripped_raw_name),
+                            written_name: high_level_description
+                                .and_then(|high_level| {
+                                    high_level.elements.iter().find(|element| {
+                                        element.role == Some(JsonRole::StreamWritten)
+                                    })
+                                })
+                                .map(|element| element.name.as_ref())
+                                .unwrap_or("Written"),
+                            chunk_written,
+                            extra_fields,
+                        };
+                        let (result_type, setup_statements, chunk_statements, result_expr) =
+                            short_write.generate(items, base_path, &response_type);
+                        return parse_quote!(
+                            #doc_attr
+                            pub async fn #function_name(&mut self, request:#high_level_struct_name<'_>) -> Result<#result_type, tinkerforge_base::error::TinkerforgeError>{
+                                #firmware_check
+                                let mut buffer = [0; 64];
+                                let mut written = 0;
+                                #(#setup_statements)*
+                                for slice in request.write_to_slices() {
+                                    let length = slice.write_to_slice(&mut buffer);
+                                    let payload = &buffer[0..length];
+                                    let result = self.device.get(#function_id, payload).await?;
+                                    #(#chunk_statements)*
+                                    written += chunk_written;
+                                    // the device did not accept the whole chunk, e.g. because its buffer is full
+                                    if chunk_written < slice.data.len() {
+                                        break;
+                                    }
+                                }
+                                Ok(#result_expr)
+                            }
+                        );
+                    }
                     return parse_quote!(
-                        #[doc = #doc_de]
+                        #doc_attr
                         pub async fn #function_name(&mut self, request:#high_level_struct_name<'_>) -> Result<(), tinkerforge_base::error::TinkerforgeError>{
+                            #firmware_check
                             let mut buffer = [0; 64];
                             for slice in request.write_to_slices() {
                                 let length = slice.write_to_slice(&mut buffer);
@@ -482,34 +702,37 @@ fn generate_element_function(
             (Some(parse_quote!(#base_path::#struct_name)), size)
         }
     };
-    let (response_type, response_line): (Type, Option<Stmt>) = if out_fields.is_empty() {
-        (parse_quote!(()), None)
-    } else if out_fields.len() == 1 {
-        let first_field = out_fields.remove(0);
-        let length = first_field.size();
-        let first_field = first_field.field();
-        let length_literal: Lit = parse_quote!(#length);
-        let method_ident = parse_quote!(from_le_byte_slice);
-        let args = parse_quote!((&result.body()[0..#length_literal]));
-        let read_method_call = static_method_call(&first_field.ty, method_ident, args);
-        (
-            first_field.ty.clone(),
-            Some(Stmt::Expr(parse_quote!(Ok(#read_method_call)), None)),
-        )
-    } else {
-        let name = format!("{packet_name}Response");
-        let struct_name: Ident = create_ident(&name);
-        append_data_object(items, out_fields, &struct_name);
-        (
-            parse_quote!(#base_path::#struct_name),
-            Some(Stmt::Expr(
-                parse_quote!(Ok(#base_path::#struct_name::from_le_byte_slice(result.body()))),
-                None,
-            )),
-        )
-    };
+    let stream_read = high_level_description
+        .and_then(|high_level| StreamRead::parse(high_level, out_fields, locale));
+    let (response_type, response_size, response_line) =
+        generate_response_type(items, out_fields, base_path, packet_name);
     let function_name = create_ident(&packet_description.name.as_ref().to_case(Case::Snake));
-    let mut function_statements = Vec::new();
+    if let Some(stream_read) = stream_read {
+        device_methods
+            .high_level_functions
+            .push(generate_stream_read_function(
+                items,
+                &stream_read,
+                base_path,
+                &function_name,
+                request_type.as_ref(),
+                &response_type,
+            ));
+    }
+    device_methods.simulator_methods.push(SimulatorMethod {
+        function_id,
+        name: function_name.clone(),
+        doc: doc.clone(),
+        request: request_type
+            .clone()
+            .map(|request_type| (request_type, request_size)),
+        response: response_line
+            .is_some()
+            .then(|| (response_type.clone(), response_size)),
+    });
+    let mut function_statements: Vec<Stmt> = generate_firmware_check(packet_description)
+        .into_iter()
+        .collect();
     if request_type.is_some() {
         function_statements.push(parse_quote!(let mut payload = [0; #request_size];));
         function_statements.push(parse_quote!(tinkerforge_base::byte_converter::ToBytes::write_to_slice(&request,&mut payload);))
@@ -532,19 +755,553 @@ fn generate_element_function(
     };
     if let Some(request_type) = request_type {
         parse_quote!(
-            #[doc = #doc_de]
+            #doc_attr
             pub async fn #function_name(&mut self, request: #request_type) -> Result<#response_type, tinkerforge_base::error::TinkerforgeError>
                 #function_block
         )
     } else {
         parse_quote!(
-            #[doc = #doc_de]
+            #doc_attr
             pub async fn #function_name(&mut self) -> Result<#response_type, tinkerforge_base::error::TinkerforgeError>
                 #function_block
         )
     }
 }
 
+/// Returns the doc attribute for `doc`, none for an undocumented packet as empty doc comments are rejected by clippy.
+fn doc_attribute(doc: &str) -> Option<Attribute> {
+    (!doc.trim().is_empty()).then(|| parse_quote!(#[doc = #doc]))
+}
+
+/// A low level function writing a stream in chunks, which reports how many elements of each
+/// chunk were actually written.
+struct ShortWrite<'a> {
+    high_level_name: &'a str,
+    written_name: &'a str,
+    chunk_written: Ident,
+    /// Fields which are not part of the stream, they are returned together with the written count.
+    extra_fields: Vec<Field>,
+}
+
+impl ShortWrite<'_> {
+    /// Returns the high level result type, the statements before the first chunk, the statements
+    /// extracting `chunk_written` from the `result` of each chunk and the expression building the
+    /// result after the last one.
+    fn generate(
+        &self,
+        items: &mut Vec<Item>,
+        base_path: &Path,
+        low_level_response_type: &Type,
+    ) -> (Type, Vec<Stmt>, Vec<Stmt>, Expr) {
+        if self.extra_fields.is_empty() {
+            let statements = vec![parse_quote!(
+                let chunk_written = <#low_level_response_type as tinkerforge_base::byte_converter::FromByteSlice>::from_le_byte_slice(result.body()) as usize;
+            )];
+            return (
+                parse_quote!(usize),
+                vec![],
+                statements,
+                parse_quote!(written),
+            );
+        }
+        let chunk_written = &self.chunk_written;
+        let extra_fields = &self.extra_fields;
+        let extra_idents: Vec<&Ident> = extra_fields
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .collect();
+        let extra_types: Vec<&Type> = extra_fields.iter().map(|field| &field.ty).collect();
+        items.push(parse_quote!(
+            impl tinkerforge_base::low_level_traits::LowLevelWrite<(#(#extra_types,)*)> for #low_level_response_type {
+                fn ll_message_written(&self) -> usize {
+                    self.#chunk_written as usize
+                }
+                fn get_result(&self) -> (#(#extra_types,)*) {
+                    (#(self.#extra_idents,)*)
+                }
+            }
+        ));
+        let struct_name = create_ident(&format!(
+            "{}Response",
+            self.high_level_name.to_case(Case::UpperCamel)
+        ));
+        let written_ident = create_ident(&self.written_name.to_case(Case::Snake));
+        items.push(parse_quote!(
+            #[derive(Copy, Clone, PartialEq, Debug)]
+            pub struct #struct_name {
+                #(#extra_fields,)*
+                pub #written_ident: usize,
+            }
+        ));
+        let statements = vec![
+            parse_quote!(let response = <#low_level_response_type as tinkerforge_base::byte_converter::FromByteSlice>::from_le_byte_slice(result.body());),
+            parse_quote!(let chunk_written = tinkerforge_base::low_level_traits::LowLevelWrite::ll_message_written(&response);),
+            parse_quote!(last_response = Some(response);),
+        ];
+        (
+            parse_quote!(#base_path::#struct_name),
+            vec![parse_quote!(let mut last_response = None;)],
+            statements,
+            parse_quote!({
+                // an empty stream is not sent at all, so there is no response to take the extra fields from
+                let response = last_response.ok_or(tinkerforge_base::error::TinkerforgeError::EmptyStream)?;
+                let (#(#extra_idents,)*) = tinkerforge_base::low_level_traits::LowLevelWrite::get_result(&response);
+                #base_path::#struct_name {
+                    #(#extra_idents,)*
+                    #written_ident: written,
+                }
+            }),
+        )
+    }
+}
+
+/// The roles of the response fields of a low level function reading a stream in chunks.
+struct StreamRead<'a> {
+    high_level: &'a JsonPacketDescription,
+    data_element: &'a JsonElement,
+    length: Option<Ident>,
+    chunk_offset: Option<Ident>,
+    chunk_data: Ident,
+    /// Fields which are not part of the stream, they are returned together with the data.
+    extra_fields: Vec<Field>,
+    doc: String,
+}
+
+impl<'a> StreamRead<'a> {
+    fn parse(
+        high_level: &'a JsonPacketDescription,
+        out_fields: &[(Field, &JsonElement)],
+        locale: JsonLocale,
+    ) -> Option<Self> {
+        let data_element = high_level.elements.iter().find(|element| {
+            element.role == Some(JsonRole::StreamData) && element.direction == JsonDirection::OUT
+        })?;
+        let mut length = None;
+        let mut chunk_offset = None;
+        let mut chunk_data = None;
+        let mut extra_fields = Vec::new();
+        for (field, element) in out_fields {
+            let ident = field.ident.clone();
+            match element.role {
+                Some(JsonRole::StreamLength) => length = ident,
+                Some(JsonRole::StreamChunkOffset) => chunk_offset = ident,
+                Some(JsonRole::StreamChunkData) => chunk_data = ident,
+                None => extra_fields.push(field.clone()),
+                Some(_) => return None,
+            }
+        }
+        // without length field the stream has the fixed length of the high level function
+        if length.is_none() && data_element.cardinality < 0 {
+            return None;
+        }
+        Some(StreamRead {
+            high_level,
+            data_element,
+            length,
+            chunk_offset,
+            chunk_data: chunk_data?,
+            extra_fields,
+            doc: doc::render(high_level.doc.text.get(locale), locale),
+        })
+    }
+}
+
+impl StreamRead<'_> {
+    /// Implements `LowLevelRead` for the low level response or callback.
+    fn implement_low_level_read(&self, items: &mut Vec<Item>, low_level_type: &Type) {
+        let element_type = self.data_element.r#type;
+        let length_expr: Expr = match &self.length {
+            Some(length) => parse_quote!(self.#length as usize),
+            None => {
+                let fixed_length = self.data_element.cardinality as usize;
+                parse_quote!(#fixed_length)
+            }
+        };
+        let chunk_offset_expr: Expr = match &self.chunk_offset {
+            Some(chunk_offset) => parse_quote!(self.#chunk_offset as usize),
+            None => parse_quote!(0),
+        };
+        let chunk_data = &self.chunk_data;
+        let extra_idents = self.extra_idents();
+        let extra_types: Vec<&Type> = self.extra_fields.iter().map(|field| &field.ty).collect();
+        let get_result: ImplItemFn = if self.extra_fields.is_empty() {
+            parse_quote!(
+                fn get_result(&self) {}
+            )
+        } else {
+            parse_quote!(
+                fn get_result(&self) -> (#(#extra_types,)*) {
+                    (#(self.#extra_idents,)*)
+                }
+            )
+        };
+        items.push(parse_quote!(
+            impl tinkerforge_base::low_level_traits::LowLevelRead<#element_type, (#(#extra_types,)*)> for #low_level_type {
+                fn ll_message_length(&self) -> usize {
+                    #length_expr
+                }
+                fn ll_message_chunk_offset(&self) -> usize {
+                    #chunk_offset_expr
+                }
+                fn ll_message_chunk_data(&self) -> &[#element_type] {
+                    &self.#chunk_data
+                }
+                #get_result
+            }
+        ));
+    }
+
+    /// Returns the high level result type and the expression building it from the last `chunk` and
+    /// the reassembled `data`. Without extra fields that's just the data, otherwise a struct named
+    /// after the high level packet with `suffix` is declared.
+    fn result(&self, items: &mut Vec<Item>, base_path: &Path, suffix: &str) -> (Type, Expr) {
+        let element_type = self.data_element.r#type;
+        if self.extra_fields.is_empty() {
+            return (parse_quote!(Vec<#element_type>), parse_quote!(data));
+        }
+        let extra_fields = &self.extra_fields;
+        let extra_idents = self.extra_idents();
+        let data_ident = create_ident(&self.data_element.name.as_ref().to_case(Case::Snake));
+        let struct_name = create_ident(&format!(
+            "{}{suffix}",
+            self.high_level.name.as_ref().to_case(Case::UpperCamel)
+        ));
+        items.push(parse_quote!(
+            #[derive(Clone, PartialEq, Debug)]
+            pub struct #struct_name {
+                #(#extra_fields,)*
+                pub #data_ident: Vec<#element_type>,
+            }
+        ));
+        (
+            parse_quote!(#base_path::#struct_name),
+            parse_quote!({
+                let (#(#extra_idents,)*) = tinkerforge_base::low_level_traits::LowLevelRead::<#element_type, _>::get_result(&chunk);
+                #base_path::#struct_name {
+                    #(#extra_idents,)*
+                    #data_ident: data,
+                }
+            }),
+        )
+    }
+
+    fn extra_idents(&self) -> Vec<&Ident> {
+        self.extra_fields
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .collect()
+    }
+}
+
+/// Returns the high level function which calls the low level function until the stream is complete.
+fn generate_stream_read_function(
+    items: &mut Vec<Item>,
+    stream_read: &StreamRead,
+    base_path: &Path,
+    low_level_function_name: &Ident,
+    request_type: Option<&Type>,
+    low_level_response_type: &Type,
+) -> ImplItemFn {
+    stream_read.implement_low_level_read(items, low_level_response_type);
+    let (response_type, result_expr) = stream_read.result(items, base_path, "Response");
+    let function_name = create_ident(&stream_read.high_level.name.as_ref().to_case(Case::Snake));
+    let doc_attr = doc_attribute(&stream_read.doc);
+    let (parameter, argument) = match request_type {
+        Some(request_type) => (quote!(request: #request_type), quote!(request)),
+        None => (quote!(), quote!()),
+    };
+    parse_quote!(
+        #doc_attr
+        pub async fn #function_name(&mut self, #parameter) -> Result<#response_type, tinkerforge_base::error::TinkerforgeError> {
+            let mut buffer = tinkerforge_base::low_level_traits::LowLevelReadBuffer::new();
+            loop {
+                let chunk = self.#low_level_function_name(#argument).await?;
+                if let Some(data) = buffer.push(&chunk)? {
+                    return Ok(#result_expr);
+                }
+            }
+        }
+    )
+}
+
+/// Returns the high level stream which reassembles the chunks of the low level callback stream.
+fn generate_stream_callback_function(
+    items: &mut Vec<Item>,
+    stream_read: &StreamRead,
+    base_path: &Path,
+    low_level_function_name: &Ident,
+    low_level_callback_type: &Type,
+) -> ImplItemFn {
+    stream_read.implement_low_level_read(items, low_level_callback_type);
+    let (item_type, result_expr) = stream_read.result(items, base_path, "Callback");
+    let function_name = create_ident(&format!(
+        "{}_stream",
+        stream_read.high_level.name.as_ref().to_case(Case::Snake)
+    ));
+    let doc_attr = doc_attribute(&stream_read.doc);
+    let reassemble: Expr = if stream_read.extra_fields.is_empty() {
+        parse_quote!(buffer.push(&chunk))
+    } else {
+        parse_quote!(buffer.push(&chunk).map(|data| #result_expr))
+    };
+    parse_quote!(
+        #doc_attr
+        pub async fn #function_name(&mut self) -> impl futures_core::Stream<Item = #item_type> {
+            let mut buffer = tinkerforge_base::low_level_traits::LowLevelCallbackBuffer::new();
+            self.#low_level_function_name()
+                .await
+                .filter_map(move |chunk| #reassemble)
+        }
+    )
+}
+
+/// Returns the statement rejecting the call on a firmware older than `since_firmware`, nothing for
+/// functions every firmware supports.
+fn generate_firmware_check(packet_description: &JsonPacketDescription) -> Option<Stmt> {
+    let function_id = packet_description.function_id;
+    let [major, minor, patch] = packet_description.since_firmware.0;
+    if [major, minor, patch] == [1, 0, 0] {
+        return None;
+    }
+    Some(parse_quote!(
+        self.device.check_firmware(#function_id, tinkerforge_base::ip_connection::Version::new(#major, #minor, #patch))?;
+    ))
+}
+
+/// Returns the response type, its size on the wire and the statement decoding it from `result`.
+fn generate_response_type(
+    items: &mut Vec<Item>,
+    out_fields: &mut Vec<(Field, &JsonElement)>,
+    base_path: &Path,
+    packet_name: &str,
+) -> (Type, usize, Option<Stmt>) {
+    if out_fields.is_empty() {
+        (parse_quote!(()), 0, None)
+    } else if out_fields.len() == 1 {
+        let first_field = out_fields.remove(0);
+        let length = first_field.size();
+        let first_field = first_field.field();
+        let length_literal: Lit = parse_quote!(#length);
+        let method_ident = parse_quote!(from_le_byte_slice);
+        let args = parse_quote!((&result.body()[0..#length_literal]));
+        let read_method_call = static_method_call(&first_field.ty, method_ident, args);
+        (
+            first_field.ty.clone(),
+            length,
+            Some(Stmt::Expr(parse_quote!(Ok(#read_method_call)), None)),
+        )
+    } else {
+        let name = format!("{packet_name}Response");
+        let struct_name: Ident = create_ident(&name);
+        let size = append_data_object(items, out_fields, &struct_name);
+        (
+            parse_quote!(#base_path::#struct_name),
+            size,
+            Some(Stmt::Expr(
+                parse_quote!(Ok(#base_path::#struct_name::from_le_byte_slice(result.body()))),
+                None,
+            )),
+        )
+    }
+}
+
+/// Methods collected from the packets of a device, which are generated after its low level functions.
+#[derive(Default)]
+struct DeviceMethods {
+    simulator_methods: Vec<SimulatorMethod>,
+    high_level_functions: Vec<ImplItemFn>,
+}
+
+/// A function as seen from the device side, used to generate the simulator trait.
+struct SimulatorMethod {
+    function_id: u8,
+    name: Ident,
+    doc: String,
+    request: Option<(Type, usize)>,
+    response: Option<(Type, usize)>,
+}
+
+fn generate_simulator_trait(
+    device_name: &str,
+    device_id: u16,
+    methods: &[SimulatorMethod],
+) -> Item {
+    let trait_name = create_ident(&format!("{device_name}Simulator"));
+    let trait_doc = format!(
+        "Simulates a {device_name} behind the mock Brick Daemon. Functions which are not implemented answer with `FunctionNotSupported`."
+    );
+    let mut trait_items: Vec<TraitItem> = vec![parse_quote!(
+        const DEVICE_IDENTIFIER: u16 = #device_id;
+    )];
+    let mut arms = Vec::<Arm>::new();
+    for method in methods {
+        let SimulatorMethod {
+            function_id,
+            name,
+            doc,
+            request,
+            response,
+        } = method;
+        let doc_attr = doc_attribute(doc);
+        let response_type: Type = response
+            .as_ref()
+            .map(|(response_type, _)| response_type.clone())
+            .unwrap_or_else(|| parse_quote!(()));
+        let mut statements = Vec::<Stmt>::new();
+        let call: Expr = if let Some((request_type, request_size)) = request {
+            trait_items.push(parse_quote!(
+                #doc_attr
+                #[allow(unused_variables)]
+                fn #name(&mut self, request: #request_type) -> Result<#response_type, tinkerforge_base::ip_connection::ErrorCode> {
+                    Err(tinkerforge_base::ip_connection::ErrorCode::FunctionNotSupported)
+                }
+            ));
+            statements.push(parse_quote!(
+                if payload.len() != #request_size {
+                    return Err(tinkerforge_base::ip_connection::ErrorCode::InvalidParameter);
+                }
+            ));
+            parse_quote!(self.#name(<#request_type as tinkerforge_base::byte_converter::FromByteSlice>::from_le_byte_slice(payload))?)
+        } else {
+            trait_items.push(parse_quote!(
+                #doc_attr
+                fn #name(&mut self) -> Result<#response_type, tinkerforge_base::ip_connection::ErrorCode> {
+                    Err(tinkerforge_base::ip_connection::ErrorCode::FunctionNotSupported)
+                }
+            ));
+            parse_quote!(self.#name()?)
+        };
+        if let Some((_, response_size)) = response {
+            statements.push(parse_quote!(let response = #call;));
+            statements.push(parse_quote!(let mut buffer = vec![0; #response_size];));
+            statements.push(parse_quote!(tinkerforge_base::byte_converter::ToBytes::write_to_slice(&response, &mut buffer);));
+            statements.push(Stmt::Expr(parse_quote!(Ok(buffer)), None));
+        } else {
+            statements.push(parse_quote!(#call;));
+            statements.push(Stmt::Expr(parse_quote!(Ok(Vec::new())), None));
+        }
+        let block = Block {
+            brace_token: Default::default(),
+            stmts: statements,
+        };
+        arms.push(parse_quote!(#function_id => #block));
+    }
+    arms.push(
+        parse_quote!(_ => Err(tinkerforge_base::ip_connection::ErrorCode::FunctionNotSupported)),
+    );
+    let dispatch_match = ExprMatch {
+        attrs: vec![],
+        match_token: Default::default(),
+        expr: Box::new(parse_quote!(function_id)),
+        brace_token: Default::default(),
+        arms,
+    };
+    trait_items.push(parse_quote!(
+        /// Decodes the request payload for `function_id`, calls the matching method and encodes its response.
+        fn handle_request(&mut self, function_id: u8, payload: &[u8]) -> Result<Vec<u8>, tinkerforge_base::ip_connection::ErrorCode> {
+            #dispatch_match
+        }
+    ));
+    parse_quote!(
+        #[doc = #trait_doc]
+        pub trait #trait_name {
+            #(#trait_items)*
+        }
+    )
+}
+
+fn generate_device_description(
+    device_name: &str,
+    device_id: u16,
+    packets: &[JsonPacketDescription],
+) -> Item {
+    let functions = packets
+        .iter()
+        .filter(|packet| packet.level != JsonLevel::High)
+        .map(|packet| {
+            let function_id = packet.function_id;
+            let name = packet.name.as_ref().to_case(Case::Snake);
+            let callback = packet.r#type == JsonPacketType::Callback;
+            let request = generate_field_descriptions(packet, JsonDirection::IN);
+            let response = generate_field_descriptions(packet, JsonDirection::OUT);
+            quote!(tinkerforge_base::decoder::FunctionDescription {
+                function_id: #function_id,
+                name: #name,
+                callback: #callback,
+                request: &[#(#request),*],
+                response: &[#(#response),*],
+            })
+        });
+    parse_quote!(
+        pub const DEVICE_DESCRIPTION: tinkerforge_base::decoder::DeviceDescription = tinkerforge_base::decoder::DeviceDescription {
+            name: #device_name,
+            device_identifier: #device_id,
+            functions: &[#(#functions),*],
+        };
+    )
+}
+
+fn generate_field_descriptions(
+    packet: &JsonPacketDescription,
+    direction: JsonDirection,
+) -> Vec<Expr> {
+    packet
+        .elements
+        .iter()
+        .filter(|element| element.direction == direction && element.cardinality > 0)
+        .map(|element| {
+            let name = element
+                .name
+                .as_ref()
+                .to_case(Case::Camel)
+                .to_case(Case::Snake);
+            let field_type = create_ident(match element.r#type {
+                JsonElementType::Int8 => "Int8",
+                JsonElementType::Int16 => "Int16",
+                JsonElementType::Int32 => "Int32",
+                JsonElementType::Int64 => "Int64",
+                JsonElementType::UInt8 => "UInt8",
+                JsonElementType::UInt16 => "UInt16",
+                JsonElementType::UInt32 => "UInt32",
+                JsonElementType::UInt64 => "UInt64",
+                JsonElementType::Float => "Float",
+                JsonElementType::Bool => "Bool",
+                JsonElementType::Char => "Char",
+                JsonElementType::String => "String",
+            });
+            let cardinality = element.cardinality as usize;
+            let constant_group = element
+                .extra
+                .iter()
+                .find_map(|extra| extra.constant_group.as_ref())
+                .map(|group| {
+                    let group_name = group.name.as_ref();
+                    let constants = group.constants.iter().map(|constant| {
+                        let value = match &constant.value {
+                            JsonAnyDefaultValue::Integer(value) => *value,
+                            JsonAnyDefaultValue::Bool(value) => *value as i64,
+                            JsonAnyDefaultValue::Character(value) => *value as i64,
+                            value => panic!("Unsupported constant value {value:?}"),
+                        };
+                        let constant_name = constant.name.as_ref().to_case(Case::UpperCamel);
+                        quote!((#value, #constant_name))
+                    });
+                    quote!(Some(&tinkerforge_base::decoder::ConstantGroup {
+                        name: #group_name,
+                        constants: &[#(#constants),*],
+                    }))
+                })
+                .unwrap_or_else(|| quote!(None));
+            parse_quote!(tinkerforge_base::decoder::FieldDescription {
+                name: #name,
+                field_type: tinkerforge_base::decoder::FieldType::#field_type,
+                cardinality: #cardinality,
+                constant_group: #constant_group,
+            })
+        })
+        .collect()
+}
+
 struct ParsedPacketFields<'a> {
     in_fields: Vec<(Field, &'a JsonElement)>,
     out_fields: Vec<(Field, &'a JsonElement)>,
@@ -584,6 +1341,7 @@ fn parse_packet_elements<'a>(
     base_path: &Path,
     constant_items: &mut Vec<Item>,
     already_declared_constants: &mut HashSet<Box<str>>,
+    options: &GeneratorOptions,
 ) -> ParsedPacketFields<'a> {
     let mut in_fields = Vec::new();
     let mut out_fields = Vec::new();
@@ -635,6 +1393,12 @@ fn parse_packet_elements<'a>(
                         },
                         parse_quote!(#extra_ident)
                     )
+                } else if let Some(quantity_type) = options
+                    .unit_types
+                    .then(|| quantity_type(element_entry, extra_entry))
+                    .flatten()
+                {
+                    (quantity_type, parse_quote!(#extra_ident))
                 } else {
                     (parse_quote!(#base_type), parse_quote!( #extra_ident))
                 });
@@ -655,10 +1419,17 @@ fn parse_packet_elements<'a>(
                 );
             }
         };
-        for (ty, ident) in create_fields.iter().cloned() {
+        for (index, (ty, ident)) in create_fields.iter().cloned().enumerate() {
+            let attrs = element_entry
+                .extra
+                .get(index)
+                .and_then(|extra| doc::field_doc(extra, options.locale))
+                .map(|field_doc| parse_quote!(#[doc = #field_doc]))
+                .into_iter()
+                .collect();
             fields.push((
                 Field {
-                    attrs: vec![],
+                    attrs,
                     vis: Visibility::Public(Pub::default()),
                     mutability: FieldMutability::None,
                     ident: Some(ident),
@@ -675,6 +1446,85 @@ fn parse_packet_elements<'a>(
     }
 }
 
+/// Returns unit and scale of a field typed as `Quantity`, which are the fields with a unit and a fixed scale
+/// except for the ones of the stream protocol.
+fn quantity_unit<'a>(
+    element: &JsonElement,
+    extra: &'a JsonElementExtra,
+) -> Option<(&'a JsonUnit, u32, u32)> {
+    if element.role.is_some()
+        || extra.constant_group.is_some()
+        || matches!(
+            element.r#type,
+            JsonElementType::Bool | JsonElementType::Char | JsonElementType::String
+        )
+    {
+        return None;
+    }
+    match (&extra.unit, &extra.scale) {
+        (
+            Some(unit),
+            JsonScale::Fraction {
+                numerator,
+                denominator,
+            },
+        ) => Some((unit, *numerator, *denominator)),
+        _ => None,
+    }
+}
+
+fn unit_name(unit: &JsonUnit) -> String {
+    unit.title.get(JsonLocale::En).to_case(Case::UpperCamel)
+}
+
+fn quantity_type(element: &JsonElement, extra: &JsonElementExtra) -> Option<Type> {
+    let (unit, numerator, denominator) = quantity_unit(element, extra)?;
+    let raw_type = element.r#type;
+    let unit_ident = create_ident(&unit_name(unit));
+    let numerator = Literal::u32_unsuffixed(numerator);
+    let denominator = Literal::u32_unsuffixed(denominator);
+    Some(
+        parse_quote!(tinkerforge_base::units::Quantity<#raw_type, crate::bindings::units::#unit_ident, #numerator, #denominator>),
+    )
+}
+
+/// Adds the units of the quantities in `packet` to `units`, which maps the type name to title and symbol.
+fn collect_units(packet: &JsonPacketDescription, units: &mut BTreeMap<String, (String, Box<str>)>) {
+    for element in packet.elements.iter() {
+        for extra in element.extra.iter() {
+            if let Some((unit, _, _)) = quantity_unit(element, extra) {
+                units.entry(unit_name(unit)).or_insert_with(|| {
+                    (
+                        unit.title.get(JsonLocale::En).to_string(),
+                        unit.symbol.clone(),
+                    )
+                });
+            }
+        }
+    }
+}
+
+fn generate_units_module(units: &BTreeMap<String, (String, Box<str>)>) -> Item {
+    let unit_items = units.iter().map(|(name, (title, symbol))| {
+        let unit_ident = create_ident(name);
+        let symbol = symbol.as_ref();
+        quote!(
+            #[doc = #title]
+            #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+            pub struct #unit_ident;
+            impl tinkerforge_base::units::Unit for #unit_ident {
+                const SYMBOL: &'static str = #symbol;
+            }
+        )
+    });
+    parse_quote!(
+        /// Units of the fields typed as `tinkerforge_base::units::Quantity`.
+        pub mod units {
+            #(#unit_items)*
+        }
+    )
+}
+
 fn process_constant_group(items: &mut Vec<Item>, element: &JsonElement, group: &JsonConstantGroup) {
     let camel_name = group.name.as_ref().to_case(Case::UpperCamel);
     println!("Constant group: {}", group.name);
@@ -684,6 +1534,7 @@ fn process_constant_group(items: &mut Vec<Item>, element: &JsonElement, group: &
     let mut variants: Punctuated<Variant, Comma> = Default::default();
     let mut encode_arms = vec![];
     let mut parse_arms = vec![];
+    let mut parsed_values = HashSet::new();
     for constant_entry in group.constants.iter() {
         let name = constant_entry.name.as_ref();
         let value = &constant_entry.value;
@@ -691,7 +1542,10 @@ fn process_constant_group(items: &mut Vec<Item>, element: &JsonElement, group: &
         variants.push(parse_quote!(#variant_ident));
         let value = ty.convert_value(value);
         encode_arms.push(parse_quote!(#enum_name_ident::#variant_ident =>#value));
-        parse_arms.push(parse_quote!(#value => Ok(#enum_name_ident::#variant_ident)))
+        // constants sharing a value, like the bit fields of WifiEapOption, parse to the first one
+        if parsed_values.insert(value.to_string()) {
+            parse_arms.push(parse_quote!(#value => Ok(#enum_name_ident::#variant_ident)))
+        }
     }
     items.push(parse_quote!(
         #[derive(Copy,Clone,Eq,PartialEq,Debug)]
@@ -701,7 +1555,10 @@ fn process_constant_group(items: &mut Vec<Item>, element: &JsonElement, group: &
     ));
     let encode_match = match_val(encode_arms);
 
-    parse_arms.push(parse_quote!(_ => Err(())));
+    // a group of both bool values is exhaustive already
+    if !(ty == JsonElementType::Bool && parsed_values.len() == 2) {
+        parse_arms.push(parse_quote!(_ => Err(())));
+    }
     let parse_match = match_self(parse_arms);
 
     items.push(Item::Impl(parse_quote!(
@@ -830,18 +1687,18 @@ fn append_data_object<F: FieldWithSize>(
 
 fn static_method_call(ty: &Type, method: Ident, args: Punctuated<Expr, Comma>) -> Expr {
     if let Type::Path(TypePath {
-                          qself: None,
-                          path: Path {
-                              leading_colon: _,
-                              segments,
-                          },
-                      }) = &ty
+        qself: None,
+        path: Path {
+            leading_colon: _,
+            segments,
+        },
+    }) = &ty
     {
         let seg = segments.last();
         if let Some(PathSegment {
-                        ident,
-                        arguments: PathArguments::AngleBracketed(bracketed),
-                    }) = seg
+            ident,
+            arguments: PathArguments::AngleBracketed(bracketed),
+        }) = seg
         {
             return if segments.len() > 1 {
                 let mut type_path: Punctuated<PathSegment, PathSep> = Punctuated::new();
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use proc_macro2::TokenStream;
-use quote::{quote, TokenStreamExt, ToTokens};
+use quote::{quote, ToTokens, TokenStreamExt};
 use serde::{Deserialize, Serialize};
 use syn::parse_quote;
 
@@ -24,7 +24,7 @@ pub struct JsonContent {
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(deny_unknown_fields)]
-pub struct JsonApiVersion([u8; 3]);
+pub struct JsonApiVersion(pub [u8; 3]);
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(deny_unknown_fields)]
@@ -45,9 +45,21 @@ pub struct JsonDisplayName {
 #[serde(deny_unknown_fields)]
 pub struct JsonLocalizedText(pub HashMap<JsonLocale, Box<str>>);
 
-#[derive(Serialize, Deserialize, Debug, Hash, Eq, PartialEq)]
+impl JsonLocalizedText {
+    /// Returns the text in `locale`, falling back to English and then to an empty text.
+    pub fn get(&self, locale: JsonLocale) -> &str {
+        self.0
+            .get(&locale)
+            .or_else(|| self.0.get(&JsonLocale::En))
+            .map(|text| text.as_ref())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, Hash, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub enum JsonLocale {
+    #[default]
     #[serde(rename = "en")]
     En,
     #[serde(rename = "de")]
@@ -187,7 +199,7 @@ impl JsonElementType {
             JsonElementType::Int16 => array_length * 2,
             JsonElementType::UInt32 => array_length * 4,
             JsonElementType::Int32 => array_length * 4,
-            JsonElementType::Bool => (array_length + 7) / 8,
+            JsonElementType::Bool => array_length.div_ceil(8),
             JsonElementType::Char => array_length,
             JsonElementType::String => array_length,
             JsonElementType::Float => array_length * 4,
@@ -238,12 +250,11 @@ impl ToTokens for JsonElementType {
                     quote!(i64)
                 }
             }
-                .into_token_stream(),
+            .into_token_stream(),
         )
     }
 }
 
-
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum JsonDirection {
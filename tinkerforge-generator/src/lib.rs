@@ -1,3 +1,5 @@
 pub mod json_model;
 
 pub mod generator;
+
+pub mod doc;
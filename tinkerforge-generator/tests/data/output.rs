@@ -56,6 +56,7 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetExtensionTypeRequest {
+        ///Range: 0 - 1
         pub extension: u8,
         pub exttype: crate::bindings::master::ExtensionType,
     }
@@ -80,6 +81,7 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetChibiSlaveAddressRequest {
+        ///Range: 0 - 254
         pub num: u8,
         pub address: u8,
     }
@@ -240,8 +242,10 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetRs485ConfigurationRequest {
+        ///Unit: Bd (Baud)
         pub speed: u32,
         pub parity: crate::bindings::master::Rs485Parity,
+        ///Range: 1 - 2
         pub stopbits: u8,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -268,11 +272,13 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetRs485ConfigurationResponse {
+        ///Unit: Bd (Baud)
         pub speed: u32,
         pub parity: tinkerforge_base::byte_converter::ParsedOrRaw<
             crate::bindings::master::Rs485Parity,
             char,
         >,
+        ///Range: 1 - 2
         pub stopbits: u8,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -532,9 +538,7 @@ pub mod master {
                 1u8 => Ok(WifiEapOption::OuterAuthEapTls),
                 2u8 => Ok(WifiEapOption::OuterAuthEapTtls),
                 3u8 => Ok(WifiEapOption::OuterAuthEapPeap),
-                0u8 => Ok(WifiEapOption::InnerAuthEapMschap),
                 4u8 => Ok(WifiEapOption::InnerAuthEapGtc),
-                0u8 => Ok(WifiEapOption::CertTypeCaCert),
                 8u8 => Ok(WifiEapOption::CertTypeClientCert),
                 16u8 => Ok(WifiEapOption::CertTypePrivateKey),
                 _ => Err(()),
@@ -545,10 +549,20 @@ pub mod master {
     pub struct SetWifiEncryptionRequest {
         pub encryption: crate::bindings::master::WifiEncryption,
         pub key: [char; 50usize],
+        ///Range: 1 - 4
         pub key_index: u8,
         pub eap_options: crate::bindings::master::WifiEapOption,
+        /**Unit: B (Byte)
+
+Range: 0 - 1312*/
         pub ca_certificate_length: u16,
+        /**Unit: B (Byte)
+
+Range: 0 - 1312*/
         pub client_certificate_length: u16,
+        /**Unit: B (Byte)
+
+Range: 0 - 4320*/
         pub private_key_length: u16,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for SetWifiEncryptionRequest {
@@ -601,6 +615,7 @@ pub mod master {
             u8,
         >,
         pub key: [char; 50usize],
+        ///Range: 1 - 4
         pub key_index: u8,
         pub eap_options: tinkerforge_base::byte_converter::ParsedOrRaw<
             crate::bindings::master::WifiEapOption,
@@ -767,8 +782,12 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetWifiCertificateRequest {
+        ///Range: 0 - 1311, 10000 - 11311, 20000 - 24319, 65534 - 65535
         pub index: u16,
         pub data: [u8; 32usize],
+        /**Unit: B (Byte)
+
+Range: 0 - 32*/
         pub data_length: u8,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for SetWifiCertificateRequest {
@@ -793,6 +812,7 @@ pub mod master {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetWifiCertificateResponse {
         pub data: [u8; 32usize],
+        ///Range: 0 - 32
         pub data_length: u8,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for GetWifiCertificateResponse {
@@ -851,7 +871,13 @@ pub mod master {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetWifiBufferInfoResponse {
         pub overflow: u32,
+        /**Unit: B (Byte)
+
+Range: 0 - 1500*/
         pub low_watermark: u16,
+        /**Unit: B (Byte)
+
+Range: 0 - 1500*/
         pub used: u16,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for GetWifiBufferInfoResponse {
@@ -964,7 +990,9 @@ pub mod master {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetStackCurrentCallbackThresholdRequest {
         pub option: crate::bindings::master::ThresholdOption,
+        ///Unit: 1/1000 A (Ampere)
         pub min: u16,
+        ///Unit: 1/1000 A (Ampere)
         pub max: u16,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -996,7 +1024,9 @@ pub mod master {
             crate::bindings::master::ThresholdOption,
             char,
         >,
+        ///Unit: 1/1000 A (Ampere)
         pub min: u16,
+        ///Unit: 1/1000 A (Ampere)
         pub max: u16,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -1026,7 +1056,9 @@ pub mod master {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetStackVoltageCallbackThresholdRequest {
         pub option: crate::bindings::master::ThresholdOption,
+        ///Unit: 1/1000 V (Volt)
         pub min: u16,
+        ///Unit: 1/1000 V (Volt)
         pub max: u16,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -1058,7 +1090,9 @@ pub mod master {
             crate::bindings::master::ThresholdOption,
             char,
         >,
+        ///Unit: 1/1000 V (Volt)
         pub min: u16,
+        ///Unit: 1/1000 V (Volt)
         pub max: u16,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -1088,7 +1122,9 @@ pub mod master {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetUsbVoltageCallbackThresholdRequest {
         pub option: crate::bindings::master::ThresholdOption,
+        ///Unit: 1/1000 V (Volt)
         pub min: u16,
+        ///Unit: 1/1000 V (Volt)
         pub max: u16,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -1120,7 +1156,9 @@ pub mod master {
             crate::bindings::master::ThresholdOption,
             char,
         >,
+        ///Unit: 1/1000 V (Volt)
         pub min: u16,
+        ///Unit: 1/1000 V (Volt)
         pub max: u16,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -1273,7 +1311,9 @@ pub mod master {
         pub ip: [u8; 4usize],
         pub subnet_mask: [u8; 4usize],
         pub gateway: [u8; 4usize],
+        ///Unit: B (Byte)
         pub rx_count: u32,
+        ///Unit: B (Byte)
         pub tx_count: u32,
         pub hostname: [char; 32usize],
     }
@@ -1318,6 +1358,7 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetEthernetWebsocketConfigurationRequest {
+        ///Range: 0 - 7
         pub sockets: u8,
         pub port: u16,
     }
@@ -1342,6 +1383,7 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetEthernetWebsocketConfigurationResponse {
+        ///Range: 0 - 7
         pub sockets: u8,
         pub port: u16,
     }
@@ -1421,6 +1463,9 @@ pub mod master {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct WriteWifi2SerialPortRequest {
         pub data: [u8; 60usize],
+        /**Unit: B (Byte)
+
+Range: 0 - 60*/
         pub length: u8,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -1443,6 +1488,7 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct ReadWifi2SerialPortResponse {
+        ///Range: 0 - 60
         pub data: [u8; 60usize],
         pub result: u8,
     }
@@ -1657,7 +1703,9 @@ pub mod master {
         pub client_subnet_mask: [u8; 4usize],
         pub client_gateway: [u8; 4usize],
         pub client_mac_address: [u8; 6usize],
+        ///Unit: B (Byte)
         pub client_rx_count: u32,
+        ///Unit: B (Byte)
         pub client_tx_count: u32,
         pub client_rssi: i8,
         pub ap_enabled: bool,
@@ -1665,7 +1713,9 @@ pub mod master {
         pub ap_subnet_mask: [u8; 4usize],
         pub ap_gateway: [u8; 4usize],
         pub ap_mac_address: [u8; 6usize],
+        ///Unit: B (Byte)
         pub ap_rx_count: u32,
+        ///Unit: B (Byte)
         pub ap_tx_count: u32,
         pub ap_connected_count: u8,
     }
@@ -2244,7 +2294,9 @@ pub mod master {
         pub root_node: bool,
         pub root_candidate: bool,
         pub connected_nodes: u16,
+        ///Unit: B (Byte)
         pub rx_count: u32,
+        ///Unit: B (Byte)
         pub tx_count: u32,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -2432,6 +2484,9 @@ pub mod master {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetSpitfpBaudrateConfigRequest {
         pub enable_dynamic_baudrate: bool,
+        /**Unit: Bd (Baud)
+
+Range: 400000 - 2000000*/
         pub minimum_dynamic_baudrate: u32,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -2462,6 +2517,9 @@ pub mod master {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetSpitfpBaudrateConfigResponse {
         pub enable_dynamic_baudrate: bool,
+        /**Unit: Bd (Baud)
+
+Range: 400000 - 2000000*/
         pub minimum_dynamic_baudrate: u32,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice
@@ -2545,7 +2603,11 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetSpitfpBaudrateRequest {
+        ///Range: 'a' - 'd'
         pub bricklet_port: char,
+        /**Unit: Bd (Baud)
+
+Range: 400000 - 2000000*/
         pub baudrate: u32,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for SetSpitfpBaudrateRequest {
@@ -2646,6 +2708,7 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct WriteBrickletPluginRequest {
+        ///Range: 'a' - 'd'
         pub port: char,
         pub offset: u8,
         pub chunk: [u8; 32usize],
@@ -2671,6 +2734,7 @@ pub mod master {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct ReadBrickletPluginRequest {
+        ///Range: 'a' - 'd'
         pub port: char,
         pub offset: u8,
     }
@@ -2695,6 +2759,7 @@ pub mod master {
     pub struct GetIdentityResponse {
         pub uid: [char; 8usize],
         pub connected_uid: [char; 8usize],
+        ///Range: '0' - '8'
         pub position: char,
         pub hardware_version_major: u8,
         pub hardware_version_minor: u8,
@@ -2778,14 +2843,34 @@ pub mod master {
         pub fn uid(&self) -> tinkerforge_base::base58::Uid {
             self.device.uid()
         }
-        /**
-Gibt die Spannung des Stapels zurück. Diese Spannung wird über
-den Stapel verteilt und kann zum Beispiel über eine Step-Down oder
-Step-Up Power Supply eingespeist werden.
+        /// The firmware version functions are checked against before sending, `None` if the check is disabled.
+        pub fn firmware_version(
+            &self,
+        ) -> Option<tinkerforge_base::ip_connection::Version> {
+            self.device.firmware_version()
+        }
+        /// Enables the firmware check with the version reported by an enumeration, `None` disables it again.
+        pub fn set_firmware_version(
+            &mut self,
+            firmware_version: Option<tinkerforge_base::ip_connection::Version>,
+        ) {
+            self.device.set_firmware_version(firmware_version)
+        }
+        /// Queries the firmware version of the device and enables the firmware check with it.
+        pub async fn load_firmware_version(
+            &mut self,
+        ) -> Result<
+            tinkerforge_base::ip_connection::Version,
+            tinkerforge_base::error::TinkerforgeError,
+        > {
+            self.device.load_firmware_version().await
+        }
+        /**Returns the stack voltage. The stack voltage is the
+voltage that is supplied via the stack, i.e. it is given by a
+Step-Down or Step-Up Power Supply.
 
-.. note::
- Es ist mit dieser Funktion nicht möglich, Spannungen, die per PoE oder USB eingespeist werden, zu messen.
-*/
+**Note:**
+It is not possible to measure voltages supplied per PoE or USB with this function.*/
         pub async fn get_stack_voltage(
             &mut self,
         ) -> Result<u16, tinkerforge_base::error::TinkerforgeError> {
@@ -2793,14 +2878,12 @@ Step-Up Power Supply eingespeist werden.
             let result = self.device.get(1u8, &payload).await?;
             Ok(u16::from_le_byte_slice(&result.body()[0..2usize]))
         }
-        /**
-Gibt den Stromverbrauch des Stapels zurück. Der angegebene Strom
-bezieht sich auf den Stromverbrauch der am Stapel angeschlossenen Verbraucher.
-Die Speisung kann z.B. über eine Step-Down oder Step-Up Power Supply erfolgen.
+        /**Returns the stack current. The stack current is the
+current that is drawn via the stack, i.e. it is given by a
+Step-Down or Step-Up Power Supply.
 
-.. note::
- Es ist mit dieser Funktion nicht möglich, den Stromverbrauch über PoE oder USB zu messen.
-*/
+**Note:**
+It is not possible to measure the current drawn via PoE or USB with this function.*/
         pub async fn get_stack_current(
             &mut self,
         ) -> Result<u16, tinkerforge_base::error::TinkerforgeError> {
@@ -2808,27 +2891,22 @@ Die Speisung kann z.B. über eine Step-Down oder Step-Up Power Supply erfolgen.
             let result = self.device.get(2u8, &payload).await?;
             Ok(u16::from_le_byte_slice(&result.body()[0..2usize]))
         }
-        /**
-Schreibt den Typ der Extension in den EEPROM der angegebenen Extension.
-Die Extension kann entweder 0 oder 1 sein (0 ist die untere, 1
-die obere, wenn nur eine Extension verfügbar ist, ist 0 zu verwenden)
-
-Mögliche Extensiontypen:
+        /**Writes the extension type to the EEPROM of a specified extension.
+The extension is either 0 or 1 (0 is the lower one, 1 is the upper one,
+if only one extension is present use 0).
 
-.. csv-table::
- :header: "Typ", "Beschreibung"
- :widths: 10, 100
+Possible extension types:
 
- "1",    "Chibi"
- "2",    "RS485"
- "3",    "WIFI"
- "4",    "Ethernet"
- "5",    "WIFI 2.0"
+| Type | Description |
+| --- | --- |
+| 1 | Chibi |
+| 2 | RS485 |
+| 3 | WIFI |
+| 4 | Ethernet |
+| 5 | WIFI 2.0 |
 
-Der Typ der Extension ist schon gesetzt beim Erwerb der Extension und kann
-über den Brick Viewer gesetzt werden. Daher ist es unwahrscheinlich, dass
-diese Funktion benötigt wird.
-*/
+The extension type is already set when bought and it can be set with the
+Brick Viewer, it is unlikely that you need this function.*/
         pub async fn set_extension_type(
             &mut self,
             request: crate::bindings::master::SetExtensionTypeRequest,
@@ -2843,9 +2921,7 @@ diese Funktion benötigt wird.
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Typ der angegebenen Extension zurück, wie von :func:`Set Extension Type` gesetzt.
-*/
+        ///Returns the type for a given extension as set by `set_extension_type`.
         pub async fn get_extension_type(
             &mut self,
             request: u8,
@@ -2869,28 +2945,34 @@ Gibt den Typ der angegebenen Extension zurück, wie von :func:`Set Extension Typ
                 >::from_le_byte_slice(&result.body()[0..4usize]),
             )
         }
-        /**
-Gibt *true* zurück, wenn der Master Brick an Position 0 im Stapel und eine
-Chibi Extension verfügbar ist.
-*/
+        /**Returns *true* if the Master Brick is at position 0 in the stack and a Chibi
+Extension is available.*/
         pub async fn is_chibi_present(
             &mut self,
         ) -> Result<bool, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    5u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(5u8, &payload).await?;
             Ok(bool::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Setzt die zugehörige Adresse der Chibi Extension.
+        /**Sets the address belonging to the Chibi Extension.
 
-Es ist möglich die Adresse mit dem Brick Viewer zu setzen und diese
-wird im EEPROM der Chibi Extension abgespeichert. Ein Setzen bei
-jedem Start ist daher nicht notwendig.
-*/
+It is possible to set the address with the Brick Viewer and it will be
+saved in the EEPROM of the Chibi Extension, it does not
+have to be set on every startup.*/
         pub async fn set_chibi_address(
             &mut self,
             request: u8,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    6u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -2901,29 +2983,34 @@ jedem Start ist daher nicht notwendig.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Adresse zurück, wie von :func:`Set Chibi Address` gesetzt.
-*/
+        ///Returns the address as set by `set_chibi_address`.
         pub async fn get_chibi_address(
             &mut self,
         ) -> Result<u8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    7u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(7u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Setzt die Adresse des Chibi Master. Diese Adresse wird verwendet
-wenn die Chibi Extension als Slave verwendet wird (z.B. wenn keine USB-Verbindung
-besteht).
+        /**Sets the address of the Chibi Master. This address is used if the
+Chibi Extension is used as slave (i.e. it does not have a USB connection).
 
-Es ist möglich die Adresse mit dem Brick Viewer zu setzen und diese wird im
-EEPROM der Chibi Extension abgespeichert. Ein Setzen bei
-jedem Start ist daher nicht notwendig.
-*/
+It is possible to set the address with the Brick Viewer and it will be
+saved in the EEPROM of the Chibi Extension, it does not
+have to be set on every startup.*/
         pub async fn set_chibi_master_address(
             &mut self,
             request: u8,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    8u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -2934,38 +3021,43 @@ jedem Start ist daher nicht notwendig.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Adresse zurück, wie von :func:`Set Chibi Master Address` gesetzt.
-*/
+        ///Returns the address as set by `set_chibi_master_address`.
         pub async fn get_chibi_master_address(
             &mut self,
         ) -> Result<u8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    9u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(9u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Setzt bis zu 254 Slave Adressen. 0 hat eine
-besondere Bedeutung, sie wird zur Terminierung der Liste verwendet und ist nicht
-als normale Slave Adresse erlaubt.
-Die Adressnummerierung (mittels :param:`num` Parameter) muss aufsteigend ab
-0 erfolgen. Beispiel: Wenn die Chibi Extension im Master Modus verwendet wird
-(z.B. wenn der Stapel eine USB-Verbindung hat) und es soll mit drei weiteren
-Chibi Stapeln kommuniziert werden, mit den Adressen 17, 23 und 42, sollten die
-Aufrufe ``(0, 17)``, ``(1, 23)``, ``(2, 42)`` und ``(3, 0)`` sein. Der letzte
-Aufruf mit ``(3, 0)`` dient der Terminierung der Liste und zeigt an, dass die
-Chibi Slave Adressliste in diesem Fall 3 Einträge beinhaltet.
-
-Es ist möglich die Adressen mit dem Brick Viewer zu setzen, dieser kümmert sich
-dann um korrekte Adressnummerierung und Terminierung der Liste.
-
-Die Slave Adresse werden im EEPROM der Chibi Extension abgespeichert. Ein
-Setzen bei jedem Start ist daher nicht notwendig.
-*/
+        /**Sets up to 254 slave addresses. 0 has a
+special meaning, it is used as list terminator and not allowed as normal slave
+address. The address numeration (via `num` parameter) has to be used
+ascending from 0. For example: If you use the Chibi Extension in Master mode
+(i.e. the stack has an USB connection) and you want to talk to three other
+Chibi stacks with the slave addresses 17, 23, and 42, you should call with
+`(0, 17)`, `(1, 23)`, `(2, 42)` and `(3, 0)`. The last call with
+`(3, 0)` is a list terminator and indicates that the Chibi slave address
+list contains 3 addresses in this case.
+
+It is possible to set the addresses with the Brick Viewer, that will take care
+of correct address numeration and list termination.
+
+The slave addresses will be saved in the EEPROM of the Chibi Extension, they
+don't have to be set on every startup.*/
         pub async fn set_chibi_slave_address(
             &mut self,
             request: crate::bindings::master::SetChibiSlaveAddressRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    10u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let mut payload = [0; 2usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -2976,14 +3068,17 @@ Setzen bei jedem Start ist daher nicht notwendig.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Slave Adresse für eine Adressnummerierung (mittels :param:`num` Parameter)
-zurück, wie von :func:`Set Chibi Slave Address` gesetzt.
-*/
+        /**Returns the slave address for a given `num` as set by
+`set_chibi_slave_address`.*/
         pub async fn get_chibi_slave_address(
             &mut self,
             request: u8,
         ) -> Result<u8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    11u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -2992,29 +3087,35 @@ zurück, wie von :func:`Set Chibi Slave Address` gesetzt.
             let result = self.device.get(11u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Gibt die Signalstärke in dBm zurück. Die Aktualisierung der Signalstärke
-wird bei jedem Empfang eines Paketes durchgeführt.
-*/
+        /**Returns the signal strength in dBm. The signal strength updates every time a
+packet is received.*/
         pub async fn get_chibi_signal_strength(
             &mut self,
         ) -> Result<u8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    12u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(12u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Gibt folgende Fehlerzähler der Chibi Kommunikation zurück: Underrun, CRC Fehler,
-kein ACK und Overflow. Bei Anstieg dieser Fehlerzähler ist es wahrscheinlich, dass
-entweder die Entfernung zwischen zwei Chibi Stapeln zu groß wird oder Störungen
-vorliegen.
-*/
+        /**Returns underrun, CRC error, no ACK and overflow error counts of the Chibi
+communication. If these errors start rising, it is likely that either the
+distance between two Chibi stacks is becoming too big or there are
+interferences.*/
         pub async fn get_chibi_error_log(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetChibiErrorLogResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    13u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(13u8, &payload).await?;
             Ok(
@@ -3023,26 +3124,27 @@ vorliegen.
                 ),
             )
         }
-        /**
-Setzt den Chibi Frequenzbereich der Chibi Extension. Mögliche Werte sind:
-
-.. csv-table::
- :header: "Typ", "Beschreibung"
- :widths: 10, 100
+        /**Sets the Chibi frequency range for the Chibi Extension. Possible values are:
 
- "0",    "OQPSK 868MHz (Europe)"
- "1",    "OQPSK 915MHz (US)"
- "2",    "OQPSK 780MHz (China)"
- "3",    "BPSK40 915MHz"
+| Type | Description |
+| --- | --- |
+| 0 | OQPSK 868MHz (Europe) |
+| 1 | OQPSK 915MHz (US) |
+| 2 | OQPSK 780MHz (China) |
+| 3 | BPSK40 915MHz |
 
-Es ist möglich den Frequenzbereich mit dem Brick Viewer zu setzen und dieser wird
-im EEPROM der Chibi Extension abgespeichert. Ein Setzen bei
-jedem Start ist daher nicht notwendig.
-*/
+It is possible to set the frequency with the Brick Viewer and it will be
+saved in the EEPROM of the Chibi Extension, it does not
+have to be set on every startup.*/
         pub async fn set_chibi_frequency(
             &mut self,
             request: crate::bindings::master::ChibiFrequency,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    14u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3053,9 +3155,7 @@ jedem Start ist daher nicht notwendig.
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Frequenzbereich zurück, wie von :func:`Set Chibi Frequency` gesetzt.
-*/
+        ///Returns the frequency value as set by `set_chibi_frequency`.
         pub async fn get_chibi_frequency(
             &mut self,
         ) -> Result<
@@ -3065,6 +3165,11 @@ Gibt den Frequenzbereich zurück, wie von :func:`Set Chibi Frequency` gesetzt.
             >,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    15u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(15u8, &payload).await?;
             Ok(
@@ -3074,27 +3179,28 @@ Gibt den Frequenzbereich zurück, wie von :func:`Set Chibi Frequency` gesetzt.
                 >::from_le_byte_slice(&result.body()[0..1usize]),
             )
         }
-        /**
-Setzt den verwendeten Kanal der Chibi Extension. Die möglichen Kanäle sind
-abhängig vom verwendeten Frequenzbereich:
-
-.. csv-table::
- :header: "Frequenzbereich", "Mögliche Kanäle"
- :widths: 40, 60
+        /**Sets the channel used by the Chibi Extension. Possible channels are
+different for different frequencies:
 
- "OQPSK 868MHz (Europe)", "0"
- "OQPSK 915MHz (US)",     "1, 2, 3, 4, 5, 6, 7, 8, 9, 10"
- "OQPSK 780MHz (China)",  "0, 1, 2, 3"
- "BPSK40 915MHz",         "1, 2, 3, 4, 5, 6, 7, 8, 9, 10"
+| Frequency | Possible Channels |
+| --- | --- |
+| OQPSK 868MHz (Europe) | 0 |
+| OQPSK 915MHz (US) | 1, 2, 3, 4, 5, 6, 7, 8, 9, 10 |
+| OQPSK 780MHz (China) | 0, 1, 2, 3 |
+| BPSK40 915MHz | 1, 2, 3, 4, 5, 6, 7, 8, 9, 10 |
 
-Es ist möglich den Kanal mit dem Brick Viewer zu setzen und dieser wird
-im EEPROM der Chibi Extension abgespeichert. Ein Setzen bei
-jedem Start ist daher nicht notwendig.
-*/
+It is possible to set the channel with the Brick Viewer and it will be
+saved in the EEPROM of the Chibi Extension, it does not
+have to be set on every startup.*/
         pub async fn set_chibi_channel(
             &mut self,
             request: u8,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    16u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3105,41 +3211,50 @@ jedem Start ist daher nicht notwendig.
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Kanal zurück, wie von :func:`Set Chibi Channel` gesetzt.
-*/
+        ///Returns the channel as set by `set_chibi_channel`.
         pub async fn get_chibi_channel(
             &mut self,
         ) -> Result<u8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    17u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(17u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Gibt *true* zurück, wenn der Master Brick an Position 0 im Stapel und eine
-RS485 Extension verfügbar ist.
-*/
+        /**Returns *true* if the Master Brick is at position 0 in the stack and a RS485
+Extension is available.*/
         pub async fn is_rs_485_present(
             &mut self,
         ) -> Result<bool, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    18u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(18u8, &payload).await?;
             Ok(bool::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Setzt die zugehörige Adresse (0-255) der RS485 Extension.
+        /**Sets the address (0-255) belonging to the RS485 Extension.
 
-Um eine RS485 Extension als RS485 Master (z.B. verbunden mit einem PC über
-USB) zu betreiben muss die Adresse auf 0 gesetzt werden.
+Set to 0 if the RS485 Extension should be the RS485 Master (i.e.
+connected to a PC via USB).
 
-Es ist möglich die Adresse mit dem Brick Viewer zu setzen und diese wird im
-EEPROM der RS485 Extension abgespeichert. Ein Setzen bei
-jedem Start ist daher nicht notwendig.
-*/
+It is possible to set the address with the Brick Viewer and it will be
+saved in the EEPROM of the RS485 Extension, it does not
+have to be set on every startup.*/
         pub async fn set_rs_485_address(
             &mut self,
             request: u8,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    19u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3150,38 +3265,43 @@ jedem Start ist daher nicht notwendig.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Adresse zurück, wie von :func:`Set RS485 Address` gesetzt.
-*/
+        ///Returns the address as set by `set_rs_485_address`.
         pub async fn get_rs_485_address(
             &mut self,
         ) -> Result<u8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    20u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(20u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Setzt bis zu 255 Slave Adressen. Gültige Adressen sind 1-255. 0 hat eine
-besondere Bedeutung, sie wird zur Terminierung der Liste verwendet und ist nicht
-als normale Slave Adresse erlaubt.
-Die Adressnummerierung (mittels ``num`` Parameter) muss aufsteigend ab
-0 erfolgen. Beispiel: Wenn die RS485 Extension im Master Modus verwendet wird
-(z.B. wenn der Stapel eine USB-Verbindung hat) und es soll mit drei weiteren
-RS485 Stapeln kommuniziert werden, mit den Adressen 17, 23 und 42, sollten die
-Aufrufe ``(0, 17)``, ``(1, 23)``, ``(2, 42)`` und ``(3, 0)`` sein. Der letzte
-Aufruf mit ``(3, 0)`` dient der Terminierung der Liste und zeigt an, dass die
-RS485 Slave Adressliste in diesem Fall 3 Einträge beinhaltet.
-
-Es ist möglich die Adressen mit dem Brick Viewer zu setzen, dieser kümmert sich
-dann um korrekte Adressnummerierung und Terminierung der Liste.
-
-Die Slave Adresse werden im EEPROM der RS485 Extension abgespeichert. Ein
-Setzen bei jedem Start ist daher nicht notwendig.
-*/
+        /**Sets up to 255 slave addresses. Valid addresses are in range 1-255. 0 has a
+special meaning, it is used as list terminator and not allowed as normal slave
+address. The address numeration (via `num` parameter) has to be used
+ascending from 0. For example: If you use the RS485 Extension in Master mode
+(i.e. the stack has an USB connection) and you want to talk to three other
+RS485 stacks with the addresses 17, 23, and 42, you should call with
+`(0, 17)`, `(1, 23)`, `(2, 42)` and `(3, 0)`. The last call with
+`(3, 0)` is a list terminator and indicates that the RS485 slave address list
+contains 3 addresses in this case.
+
+It is possible to set the addresses with the Brick Viewer, that will take care
+of correct address numeration and list termination.
+
+The slave addresses will be saved in the EEPROM of the Chibi Extension, they
+don't have to be set on every startup.*/
         pub async fn set_rs_485_slave_address(
             &mut self,
             request: crate::bindings::master::SetRs485SlaveAddressRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    21u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 0u8),
+                )?;
             let mut payload = [0; 2usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3192,14 +3312,17 @@ Setzen bei jedem Start ist daher nicht notwendig.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Slave Adresse für eine Adressnummerierung (mittels ``num`` Parameter)
-zurück, wie von :func:`Set RS485 Slave Address` gesetzt.
-*/
+        /**Returns the slave address for a given `num` as set by
+`set_rs_485_slave_address`.*/
         pub async fn get_rs_485_slave_address(
             &mut self,
             request: u8,
         ) -> Result<u8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    22u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3208,36 +3331,42 @@ zurück, wie von :func:`Set RS485 Slave Address` gesetzt.
             let result = self.device.get(22u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Gibt den CRC Fehlerzähler der RS485 Kommunikation zurück. Wenn dieser Zähler
-ansteigt ist es wahrscheinlich, dass der Abstand zwischen zwei RS485-Teilnehmern
-zu groß ist oder es Störungen gibt.
-*/
+        /**Returns CRC error counts of the RS485 communication.
+If this counter starts rising, it is likely that the distance
+between the RS485 nodes is too big or there is some kind of
+interference.*/
         pub async fn get_rs_485_error_log(
             &mut self,
         ) -> Result<u16, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    23u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(23u8, &payload).await?;
             Ok(u16::from_le_byte_slice(&result.body()[0..2usize]))
         }
-        /**
-Setzt die Schnittstellenkonfiguration der RS485 Extension.
-Der Master Brick versucht die vorgegebene Baudrate so
-genau wie möglich zu erreichen. Die maximale empfohlene Baudrate ist 2000000
-(2MBd). Mögliche Werte für die Parität sind 'n' (keine), 'e' (gerade) und
-'o' (ungerade).
+        /**Sets the configuration of the RS485 Extension. The
+Master Brick will try to match the given baud rate as exactly as possible.
+The maximum recommended baud rate is 2000000 (2MBd).
+Possible values for parity are 'n' (none), 'e' (even) and 'o' (odd).
 
-Wenn die RS485 Kommunikation instabil ist (verlorene Nachrichten etc.), sollte
-zuerst die Baudrate verringert werden. Sehr lange Busleitungen (z.B. 1km)
-sollten möglichst Werte im Bereich von 100000 (100kBd) verwenden.
+If your RS485 is unstable (lost messages etc.), the first thing you should
+try is to decrease the speed. On very large bus (e.g. 1km), you probably
+should use a value in the range of 100000 (100kBd).
 
-Die Werte sind im EEPROM gespeichert und werden nur beim Start angewandt. Dass
-bedeutet, der Master Brick muss nach einer Konfiguration neu gestartet werden.
-*/
+The values are stored in the EEPROM and only applied on startup. That means
+you have to restart the Master Brick after configuration.*/
         pub async fn set_rs_485_configuration(
             &mut self,
             request: crate::bindings::master::SetRs485ConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    24u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 0u8),
+                )?;
             let mut payload = [0; 6usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3248,15 +3377,18 @@ bedeutet, der Master Brick muss nach einer Konfiguration neu gestartet werden.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Schnittstellenkonfiguration zurück, wie von :func:`Set RS485 Configuration` gesetzt.
-*/
+        ///Returns the configuration as set by `set_rs_485_configuration`.
         pub async fn get_rs_485_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetRs485ConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    25u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(25u8, &payload).await?;
             Ok(
@@ -3265,51 +3397,53 @@ Gibt die Schnittstellenkonfiguration zurück, wie von :func:`Set RS485 Configura
                 ),
             )
         }
-        /**
-Gibt *true* zurück, wenn der Master Brick an Position 0 im Stapel und eine
-WIFI Extension verfügbar ist.
-*/
+        /**Returns *true* if the Master Brick is at position 0 in the stack and a WIFI
+Extension is available.*/
         pub async fn is_wifi_present(
             &mut self,
         ) -> Result<bool, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    26u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(26u8, &payload).await?;
             Ok(bool::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Setzt die Konfiguration der WIFI Extension. Die ``ssid`` darf eine maximale
-Länge von 32 Zeichen haben. Mögliche Werte für ``connection`` sind:
-
-.. csv-table::
- :header: "Wert", "Beschreibung"
- :widths: 10, 90
+        /**Sets the configuration of the WIFI Extension. The `ssid` can have a max length
+of 32 characters. Possible values for `connection` are:
 
- "0", "DHCP"
- "1", "Statische IP"
- "2", "Access Point: DHCP"
- "3", "Access Point: Statische IP"
- "4", "Ad Hoc: DHCP"
- "5", "Ad Hoc: Statische IP"
+| Value | Description |
+| --- | --- |
+| 0 | DHCP |
+| 1 | Static IP |
+| 2 | Access Point: DHCP |
+| 3 | Access Point: Static IP |
+| 4 | Ad Hoc: DHCP |
+| 5 | Ad Hoc: Static IP |
 
-Wenn ``connection`` auf eine der statische IP Optionen gesetzt wird, dann müssen
-``ip``, ``subnet_mask`` und ``gateway`` als ein Array der Größe 4 angegeben
-werden. Dabei ist das erste Element im Array das niederwertigste Byte. Falls
-``connection`` auf eine der DHCP Optionen gesetzt ist, werden ``ip``,
-``subnet_mask`` und ``gateway`` ignoriert.
+If you set `connection` to one of the static IP options then you have to
+supply `ip`, `subnet_mask` and `gateway` as an array of size 4 (first
+element of the array is the least significant byte of the address). If
+`connection` is set to one of the DHCP options then `ip`, `subnet_mask`
+and `gateway` are ignored, you can set them to 0.
 
-Der letzte Parameter ist der Port auf den das Anwendungsprogramm sich
-verbindet.
+The last parameter is the port that your program will connect to.
 
-Die Werte sind im EEPROM gespeichert und werden nur beim Start angewandt. Dass
-bedeutet, der Master Brick muss nach einer Konfiguration neu gestartet werden.
+The values are stored in the EEPROM and only applied on startup. That means
+you have to restart the Master Brick after configuration.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to set the WIFI configuration.*/
         pub async fn set_wifi_configuration(
             &mut self,
             request: crate::bindings::master::SetWifiConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    27u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let mut payload = [0; 47usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3320,15 +3454,18 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Konfiguration zurück, wie von :func:`Set Wifi Configuration` gesetzt.
-*/
+        ///Returns the configuration as set by `set_wifi_configuration`.
         pub async fn get_wifi_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifiConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    28u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(28u8, &payload).await?;
             Ok(
@@ -3337,57 +3474,54 @@ Gibt die Konfiguration zurück, wie von :func:`Set Wifi Configuration` gesetzt.
                 ),
             )
         }
-        /**
-Setzt die Verschlüsselung der WIFI Extension. Der erste Parameter ist der
-Typ der Verschlüsselung. Mögliche Werte sind:
-
-.. csv-table::
- :header: "Wert", "Beschreibung"
- :widths: 10, 90
+        /**Sets the encryption of the WIFI Extension. The first parameter is the
+type of the encryption. Possible values are:
 
- "0", "WPA/WPA2"
- "1", "WPA Enterprise (EAP-FAST, EAP-TLS, EAP-TTLS, PEAP)"
- "2", "WEP"
- "3", "Keine Verschlüsselung"
+| Value | Description |
+| --- | --- |
+| 0 | WPA/WPA2 |
+| 1 | WPA Enterprise (EAP-FAST, EAP-TLS, EAP-TTLS, PEAP) |
+| 2 | WEP |
+| 3 | No Encryption |
 
-Der ``key`` hat eine maximale Länge von 50 Zeichen und wird benutzt
-falls ``encryption`` auf 0 oder 2 (WPA/WPA2 oder WEP) gesetzt ist. Andernfalls
-wird dieser Parameter ignoriert.
+The `key` has a max length of 50 characters and is used if `encryption`
+is set to 0 or 2 (WPA/WPA2 or WEP). Otherwise the value is ignored.
 
-Für WPA/WPA2 muss der Schlüssel mindestens 8 Zeichen lang sein. Wenn ein
-Schlüssel mit mehr als 50 Zeichen gesetzt werden soll, kann
-:func:`Set Long Wifi Key` genutzt werden.
+For WPA/WPA2 the key has to be at least 8 characters long. If you want to set
+a key with more than 50 characters, see `set_long_wifi_key`.
 
-Für WEP muss der Schlüssel entweder 10 oder 26 hexadezimale Zeichen lang sein.
-Es ist möglich den ``key_index`` zu setzen (1-4). Fall der ``key_index``
-unbekannt ist, ist er wahrscheinlich 1.
+For WEP the key has to be either 10 or 26 hexadecimal digits long. It is
+possible to set the WEP `key_index` (1-4). If you don't know your
+`key_index`, it is likely 1.
 
-Wenn WPA Enterprise als ``encryption`` gewählt wird, müssen ``eap_options`` und
-die Länge der Zertifikate gesetzt werden. Die Zertifikate selbst können mit
-:func:`Set Wifi Certificate` übertragen
-werden. Die ``eap_options`` bestehen aus Outer Authentication (Bits 1-2),
-Inner Authentication (Bit 3) und Certificate Type (Bits 4-5):
+If you choose WPA Enterprise as encryption, you have to set `eap_options` and
+the length of the certificates (for other encryption types these parameters
+are ignored). The certificates
+themselves can be set with `set_wifi_certificate`. `eap_options` consist
+of the outer authentication (bits 1-2), inner authentication (bit 3) and
+certificate type (bits 4-5):
 
-.. csv-table::
- :header: "Option", "Bits", "Beschreibung"
- :widths: 10, 10, 80
+| Option | Bits | Description |
+| --- | --- | --- |
+| outer authentication | 1-2 | 0=EAP-FAST, 1=EAP-TLS, 2=EAP-TTLS, 3=EAP-PEAP |
+| inner authentication | 3 | 0=EAP-MSCHAP, 1=EAP-GTC |
+| certificate type | 4-5 | 0=CA Certificate, 1=Client Certificate, 2=Private Key |
 
- "Outer Authentication", "1-2", "0=EAP-FAST, 1=EAP-TLS, 2=EAP-TTLS, 3=EAP-PEAP"
- "Inner Authentication", "3", "0=EAP-MSCHAP, 1=EAP-GTC"
- "Certificate Type", "4-5", "0=CA Certificate, 1=Client Certificate, 2=Private Key"
+Example for EAP-TTLS + EAP-GTC + Private Key: `option = 2 | (1 << 2) | (2 << 3)`.
 
-Beispiel für EAP-TTLS + EAP-GTC + Private Key: ``option = 2 | (1 << 2) | (2 << 3)``.
+The values are stored in the EEPROM and only applied on startup. That means
+you have to restart the Master Brick after configuration.
 
-Die Werte sind im EEPROM gespeichert und werden nur beim Start angewandt.
-Das bedeutet der Master Brick muss nach einer Konfiguration neu gestartet werden.
-
-Wir empfehlen den Brick Viewer zu verwenden, um die WLAN Verschlüsselung
-zu konfigurieren.
-*/
+It is recommended to use the Brick Viewer to set the Wi-Fi encryption.*/
         pub async fn set_wifi_encryption(
             &mut self,
             request: crate::bindings::master::SetWifiEncryptionRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    29u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let mut payload = [0; 59usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3398,20 +3532,21 @@ zu konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Verschlüsselungseinstellungen zurück, wie von
-:func:`Set Wifi Encryption` gesetzt.
+        /**Returns the encryption as set by `set_wifi_encryption`.
 
-.. note::
- Seit Master Brick Firmware Version 2.4.4 wird der Schlüssel nicht mehr
- zurückgegeben.
-*/
+**Note:**
+Since Master Brick Firmware version 2.4.4 the key is not returned anymore.*/
         pub async fn get_wifi_encryption(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifiEncryptionResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    30u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(30u8, &payload).await?;
             Ok(
@@ -3420,29 +3555,30 @@ Gibt die Verschlüsselungseinstellungen zurück, wie von
                 ),
             )
         }
-        /**
-Gibt den Status der WIFI Extension zurück. ``state`` wird automatisch
-aktualisiert, alle anderen Parameter werden nur beim Starten und nach jedem
-Aufruf von :func:`Refresh Wifi Status` aktualisiert.
-
-Mögliche Werte für *state* sind:
+        /**Returns the status of the WIFI Extension. The `state` is updated automatically,
+all of the other parameters are updated on startup and every time
+`refresh_wifi_status` is called.
 
-.. csv-table::
- :header: "State", "Beschreibung"
- :widths: 10, 90
+Possible states are:
 
- "0", "Getrennt"
- "1", "Verbunden"
- "2", "Verbindung wird aufgebaut"
- "3", "Fehler"
- "255", "Noch nicht initialisiert"
-*/
+| State | Description |
+| --- | --- |
+| 0 | Disassociated |
+| 1 | Associated |
+| 2 | Associating |
+| 3 | Error |
+| 255 | Not initialized yet |*/
         pub async fn get_wifi_status(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifiStatusResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    31u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(31u8, &payload).await?;
             Ok(
@@ -3451,47 +3587,53 @@ Mögliche Werte für *state* sind:
                 ),
             )
         }
-        /**
-Aktualisiert den WLAN Status (siehe :func:`Get Wifi Status`). Um den Status
-vom WLAN Modul zu lesen, muss der Master Brick vom Datenmodus in den
-Kommandomodus und wieder zurück wechseln. Dieser Wechsel und das eigentliche
-Auslesen ist leider zeitaufwändig. Dass heißt, es dauert ein paar ms bis der
-Stapel mit aufgesteckter WIFI Extension wieder reagiert nachdem die
-Funktion aufgerufen wurde.
-*/
+        /**Refreshes the Wi-Fi status (see `get_wifi_status`). To read the status
+of the Wi-Fi module, the Master Brick has to change from data mode to
+command mode and back. This transaction and the readout itself is
+unfortunately time consuming. This means, that it might take some ms
+until the stack with attached WIFI Extension reacts again after this
+function is called.*/
         pub async fn refresh_wifi_status(
             &mut self,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    32u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             self.device
                 .set(32u8, &payload, Some(std::time::Duration::from_secs(20)))
                 .await?;
             Ok(())
         }
-        /**
-Diese Funktion kann benutzt werden um sowohl das Zertifikat als auch
-Benutzername und Passwort für WPA Enterprise zu setzen. Für den Benutzernamen
-muss Index 0xFFFF und für das Password Index 0xFFFE genutzt werden.
-Die maximale Länge für beide ist 32.
+        /**This function is used to set the certificate as well as password and username
+for WPA Enterprise. To set the username use index 0xFFFF,
+to set the password use index 0xFFFE. The max length of username and
+password is 32.
 
-Das Zertifikat wird in Chunks der Größe 32 geschrieben und der Index
-gibt den Index des Chunk an. ``data_length`` sollte fast immer auf 32 gesetzt
-werden. Nur beim letzten Chunk ist eine Länge ungleich 32 möglich.
+The certificate is written in chunks of size 32 and the index is used as
+the index of the chunk. `data_length` should nearly always be 32. Only
+the last chunk can have a length that is not equal to 32.
 
-Der Startindex für CA Certificate ist 0, für Client Certificate 10000 und
-für Private Key 20000. Die Maximalen Dateigrößen sind jeweils 1312, 1312 und
-4320 Byte.
+The starting index of the CA Certificate is 0, of the Client Certificate
+10000 and for the Private Key 20000. Maximum sizes are 1312, 1312 and
+4320 byte respectively.
 
-Die Werte sind im EEPROM gespeichert und werden nur beim Start angewandt.
-Das bedeutet der Master Brick muss nach einer Konfiguration neu gestartet werden.
+The values are stored in the EEPROM and only applied on startup. That means
+you have to restart the Master Brick after uploading the certificate.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die Zertifikate, Benutzernamen
-und Passwort zu konfigurieren.
-*/
+It is recommended to use the Brick Viewer to set the certificate, username
+and password.*/
         pub async fn set_wifi_certificate(
             &mut self,
             request: crate::bindings::master::SetWifiCertificateRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    33u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let mut payload = [0; 35usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3502,10 +3644,7 @@ und Passwort zu konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt das Zertifikat für einen Index zurück, wie von
-:func:`Set Wifi Certificate` gesetzt.
-*/
+        ///Returns the certificate for a given index as set by `set_wifi_certificate`.
         pub async fn get_wifi_certificate(
             &mut self,
             request: u16,
@@ -3513,6 +3652,11 @@ Gibt das Zertifikat für einen Index zurück, wie von
             crate::bindings::master::GetWifiCertificateResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    34u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let mut payload = [0; 2usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3525,20 +3669,21 @@ Gibt das Zertifikat für einen Index zurück, wie von
                 ),
             )
         }
-        /**
-Setzt den Stromsparmodus für die WIFI Extension. Mögliche Werte sind:
-
-.. csv-table::
- :header: "Mode", "Beschreibung"
- :widths: 10, 90
+        /**Sets the power mode of the WIFI Extension. Possible modes are:
 
- "0", "Full Speed (hoher Stromverbrauch, hoher Durchsatz)"
- "1", "Low Power (geringer Stromverbrauch, geringer Durchsatz)"
-*/
+| Mode | Description |
+| --- | --- |
+| 0 | Full Speed (high power consumption, high throughput) |
+| 1 | Low Power (low power consumption, low throughput) |*/
         pub async fn set_wifi_power_mode(
             &mut self,
             request: crate::bindings::master::WifiPowerMode,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    35u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3549,9 +3694,7 @@ Setzt den Stromsparmodus für die WIFI Extension. Mögliche Werte sind:
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Stromsparmodus zurück, wie von :func:`Set Wifi Power Mode` gesetzt.
-*/
+        ///Returns the power mode as set by `set_wifi_power_mode`.
         pub async fn get_wifi_power_mode(
             &mut self,
         ) -> Result<
@@ -3561,6 +3704,11 @@ Gibt den Stromsparmodus zurück, wie von :func:`Set Wifi Power Mode` gesetzt.
             >,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    36u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(36u8, &payload).await?;
             Ok(
@@ -3570,28 +3718,31 @@ Gibt den Stromsparmodus zurück, wie von :func:`Set Wifi Power Mode` gesetzt.
                 >::from_le_byte_slice(&result.body()[0..1usize]),
             )
         }
-        /**
-Gibt Informationen über denn WLAN Empfangsbuffer zurück. Der WLAN
-Empfangsbuffer hat eine maximale Größe von 1500 Byte und falls zu viele
-Daten übertragen werden, kann er überlaufen.
+        /**Returns informations about the Wi-Fi receive buffer. The Wi-Fi
+receive buffer has a max size of 1500 byte and if data is transfered
+too fast, it might overflow.
 
-Die Rückgabewerte sind die Anzahl der Overflows, die Low-Watermark
-(d.h. die kleinste Anzahl an Byte die je noch frei waren im Buffer) und
-die Anzahl der im Moment verwendeten Bytes im Buffer.
+The return values are the number of overflows, the low watermark
+(i.e. the smallest number of bytes that were free in the buffer) and
+the bytes that are currently used.
 
-Es sollte immer versucht werden den Buffer leer zu halten, andernfalls
-ist mit einer permanenten Latenz zu rechnen. Eine gute Daumenregel ist,
-nicht mehr als 1000 Nachrichten pro Sekunde zu verschicken.
+You should always try to keep the buffer empty, otherwise you will
+have a permanent latency. A good rule of thumb is, that you can transfer
+1000 messages per second without problems.
 
-Dabei sollten am besten nie mehr als 50 Nachrichten auf einmal ohne
-Pausen gesendet werden.
-*/
+Try to not send more then 50 messages at a time without any kind of
+break between them.*/
         pub async fn get_wifi_buffer_info(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifiBufferInfoResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    37u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(37u8, &payload).await?;
             Ok(
@@ -3600,21 +3751,22 @@ Pausen gesendet werden.
                 ),
             )
         }
-        /**
-Setzt den Geltungsbereich der WIFI Extension. Mögliche Werte sind:
+        /**Sets the regulatory domain of the WIFI Extension. Possible domains are:
 
-.. csv-table::
- :header: "Geltungsbereich", "Beschreibung"
- :widths: 10, 90
-
- "0", "FCC: Kanal 1-11 (N/S Amerika, Australien, Neuseeland)"
- "1", "ETSI: Kanal 1-13 (Europa, Mittlerer Osten, Afrika)"
- "2", "TELEC: Kanal 1-14 (Japan)"
-*/
+| Domain | Description |
+| --- | --- |
+| 0 | FCC: Channel 1-11 (N/S America, Australia, New Zealand) |
+| 1 | ETSI: Channel 1-13 (Europe, Middle East, Africa) |
+| 2 | TELEC: Channel 1-14 (Japan) |*/
         pub async fn set_wifi_regulatory_domain(
             &mut self,
             request: crate::bindings::master::WifiDomain,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    38u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 4u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3625,9 +3777,7 @@ Setzt den Geltungsbereich der WIFI Extension. Mögliche Werte sind:
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Geltungsbereich zurück, wie von :func:`Set Wifi Regulatory Domain` gesetzt.
-*/
+        ///Returns the regulatory domain as set by `set_wifi_regulatory_domain`.
         pub async fn get_wifi_regulatory_domain(
             &mut self,
         ) -> Result<
@@ -3637,6 +3787,11 @@ Gibt den Geltungsbereich zurück, wie von :func:`Set Wifi Regulatory Domain` ges
             >,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    39u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 4u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(39u8, &payload).await?;
             Ok(
@@ -3646,31 +3801,35 @@ Gibt den Geltungsbereich zurück, wie von :func:`Set Wifi Regulatory Domain` ges
                 >::from_le_byte_slice(&result.body()[0..1usize]),
             )
         }
-        /**
-Gibt die USB Spannung zurück. Funktioniert nicht mit Hardware Version 2.1 oder neuer.
-*/
+        ///Returns the USB voltage. Does not work with hardware version 2.1 or newer.
         pub async fn get_usb_voltage(
             &mut self,
         ) -> Result<u16, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    40u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 3u8, 5u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(40u8, &payload).await?;
             Ok(u16::from_le_byte_slice(&result.body()[0..2usize]))
         }
-        /**
-Setzt einen langen WLAN Schlüssel (bis zu 63 Zeichen, mindestens 8 Zeichen) für
-WPA Verschlüsselung. Dieser Schlüssel wird genutzt, wenn der Schlüssel in
-:func:`Set Wifi Encryption` auf "-" gesetzt wird. Im alten Protokoll war
-ein Payload der Größe 63 nicht möglich, dadurch wurde die maximale
-Schlüssellänge auf 50 gesetzt.
-
-Mit dem neuen Protokoll ist die volle
-Schlüssellänge möglich. Da wir keine API brechen wollten, wurde diese
-Funktion zusätzlich hinzugefügt.
-*/
+        /**Sets a long Wi-Fi key (up to 63 chars, at least 8 chars) for WPA encryption.
+This key will be used
+if the key in `set_wifi_encryption` is set to "-". In the old protocol,
+a payload of size 63 was not possible, so the maximum key length was 50 chars.
+
+With the new protocol this is possible, since we didn't want to break API,
+this function was added additionally.*/
         pub async fn set_long_wifi_key(
             &mut self,
             request: [char; 64usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    41u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 64usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3681,32 +3840,35 @@ Funktion zusätzlich hinzugefügt.
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Verschlüsselungsschlüssel zurück, wie von
-:func:`Set Long Wifi Key` gesetzt.
+        /**Returns the encryption key as set by `set_long_wifi_key`.
 
-.. note::
- Seit Master Brick Firmware Version 2.4.4 wird der Schlüssel nicht mehr
- zurückgegeben.
-*/
+**Note:**
+Since Master Brick firmware version 2.4.4 the key is not returned anymore.*/
         pub async fn get_long_wifi_key(
             &mut self,
         ) -> Result<[char; 64usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    42u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(42u8, &payload).await?;
             Ok(<[char; 64usize]>::from_le_byte_slice(&result.body()[0..64usize]))
         }
-        /**
-Setzt den Hostnamen der WIFI Extension. Der Hostname wird von
-Access Points als Hostname in der DHCP Client Tabelle angezeigt.
+        /**Sets the hostname of the WIFI Extension. The hostname will be displayed
+by access points as the hostname in the DHCP clients table.
 
-Das Setzen eines leeren Strings stellt den voreingestellten Hostnamen
-wieder her.
-*/
+Setting an empty String will restore the default hostname.*/
         pub async fn set_wifi_hostname(
             &mut self,
             request: [char; 16usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    43u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let mut payload = [0; 16usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3717,30 +3879,35 @@ wieder her.
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Hostnamen zurück, wie von :func:`Set Wifi Hostname` gesetzt.
+        /**Returns the hostname as set by `set_wifi_hostname`.
 
-Ein leerer String bedeutet, dass der voreingestellte Hostname
-genutzt wird.
-*/
+An empty String means, that the default hostname is used.*/
         pub async fn get_wifi_hostname(
             &mut self,
         ) -> Result<[char; 16usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    44u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(44u8, &payload).await?;
             Ok(<[char; 16usize]>::from_le_byte_slice(&result.body()[0..16usize]))
         }
-        /**
-Setzt die Periode mit welcher der :cb:`Stack Current` Callback ausgelöst
-wird. Ein Wert von 0 deaktiviert den Callback.
+        /**Sets the period with which the `stack_current_stream` callback is triggered
+periodically. A value of 0 turns the callback off.
 
-Der :cb:`Stack Current` Callback wird nur ausgelöst, wenn sich die Stromstärke
-seit der letzten Auslösung geändert hat.
-*/
+The `stack_current_stream` callback is only triggered if the current has changed
+since the last triggering.*/
         pub async fn set_stack_current_callback_period(
             &mut self,
             request: u32,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    45u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let mut payload = [0; 4usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3751,27 +3918,33 @@ seit der letzten Auslösung geändert hat.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Periode zurück, wie von :func:`Set Stack Current Callback Period` gesetzt
-*/
+        ///Returns the period as set by `set_stack_current_callback_period`.
         pub async fn get_stack_current_callback_period(
             &mut self,
         ) -> Result<u32, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    46u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(46u8, &payload).await?;
             Ok(u32::from_le_byte_slice(&result.body()[0..4usize]))
         }
-        /**
-Setzt die Periode mit welcher der :cb:`Stack Voltage` Callback ausgelöst
-wird. Ein Wert von 0 deaktiviert den Callback.
+        /**Sets the period with which the `stack_voltage_stream` callback is triggered
+periodically. A value of 0 turns the callback off.
 
-Der :cb:`Stack Voltage` Callback wird nur ausgelöst, wenn sich die Spannung seit
-der letzten Auslösung geändert hat.
-*/
+The `stack_voltage_stream` callback is only triggered if the voltage has changed
+since the last triggering.*/
         pub async fn set_stack_voltage_callback_period(
             &mut self,
             request: u32,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    47u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let mut payload = [0; 4usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3782,27 +3955,33 @@ der letzten Auslösung geändert hat.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Periode zurück, wie von :func:`Set Stack Voltage Callback Period` gesetzt
-*/
+        ///Returns the period as set by `set_stack_voltage_callback_period`.
         pub async fn get_stack_voltage_callback_period(
             &mut self,
         ) -> Result<u32, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    48u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(48u8, &payload).await?;
             Ok(u32::from_le_byte_slice(&result.body()[0..4usize]))
         }
-        /**
-Setzt die Periode mit welcher der :cb:`USB Voltage` Callback ausgelöst
-wird. Ein Wert von 0 deaktiviert den Callback.
+        /**Sets the period with which the `usb_voltage_stream` callback is triggered
+periodically. A value of 0 turns the callback off.
 
-Der :cb:`USB Voltage` Callback wird nur ausgelöst, wenn sich die Spannung seit
-der letzten Auslösung geändert hat.
-*/
+The `usb_voltage_stream` callback is only triggered if the voltage has changed
+since the last triggering.*/
         pub async fn set_usb_voltage_callback_period(
             &mut self,
             request: u32,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    49u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let mut payload = [0; 4usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3813,35 +3992,39 @@ der letzten Auslösung geändert hat.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Periode zurück, wie von :func:`Set USB Voltage Callback Period` gesetzt
-*/
+        ///Returns the period as set by `set_usb_voltage_callback_period`.
         pub async fn get_usb_voltage_callback_period(
             &mut self,
         ) -> Result<u32, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    50u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(50u8, &payload).await?;
             Ok(u32::from_le_byte_slice(&result.body()[0..4usize]))
         }
-        /**
-Setzt den Schwellwert für den :cb:`Stack Current Reached` Callback.
+        /**Sets the thresholds for the `stack_current_reached_stream` callback.
 
-Die folgenden Optionen sind möglich:
+The following options are possible:
 
-.. csv-table::
- :header: "Option", "Beschreibung"
- :widths: 10, 100
-
- "'x'",    "Callback ist inaktiv"
- "'o'",    "Callback wird ausgelöst, wenn die Stromstärke *außerhalb* des min und max Wertes ist"
- "'i'",    "Callback wird ausgelöst, wenn die Stromstärke *innerhalb* des min und max Wertes ist"
- "'<'",    "Callback wird ausgelöst, wenn die Stromstärke kleiner als der min Wert ist (max wird ignoriert)"
- "'>'",    "Callback wird ausgelöst, wenn die Stromstärke größer als der min Wert ist (max wird ignoriert)"
-*/
+| Option | Description |
+| --- | --- |
+| 'x' | Callback is turned off |
+| 'o' | Callback is triggered when the current is *outside* the min and max values |
+| 'i' | Callback is triggered when the current is *inside* the min and max values |
+| '<' | Callback is triggered when the current is smaller than the min value (max is ignored) |
+| '>' | Callback is triggered when the current is greater than the min value (max is ignored) |*/
         pub async fn set_stack_current_callback_threshold(
             &mut self,
             request: crate::bindings::master::SetStackCurrentCallbackThresholdRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    51u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let mut payload = [0; 5usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3852,15 +4035,18 @@ Die folgenden Optionen sind möglich:
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Schwellwert zurück, wie von :func:`Set Stack Current Callback Threshold` gesetzt.
-*/
+        ///Returns the threshold as set by `set_stack_current_callback_threshold`.
         pub async fn get_stack_current_callback_threshold(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetStackCurrentCallbackThresholdResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    52u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(52u8, &payload).await?;
             Ok(
@@ -3869,25 +4055,26 @@ Gibt den Schwellwert zurück, wie von :func:`Set Stack Current Callback Threshol
                 ),
             )
         }
-        /**
-Setzt den Schwellwert für den :cb:`Stack Voltage Reached` Callback.
-
-Die folgenden Optionen sind möglich:
+        /**Sets the thresholds for the `stack_voltage_reached_stream` callback.
 
-.. csv-table::
- :header: "Option", "Beschreibung"
- :widths: 10, 100
+The following options are possible:
 
- "'x'",    "Callback ist inaktiv"
- "'o'",    "Callback wird ausgelöst, wenn die Spannung *außerhalb* des min und max Wertes ist"
- "'i'",    "Callback wird ausgelöst, wenn die Spannung *innerhalb* des min und max Wertes ist"
- "'<'",    "Callback wird ausgelöst, wenn die Spannung kleiner als der min Wert ist (max wird ignoriert)"
- "'>'",    "Callback wird ausgelöst, wenn die Spannung größer als der min Wert ist (max wird ignoriert)"
-*/
+| Option | Description |
+| --- | --- |
+| 'x' | Callback is turned off |
+| 'o' | Callback is triggered when the voltage is *outside* the min and max values |
+| 'i' | Callback is triggered when the voltage is *inside* the min and max values |
+| '<' | Callback is triggered when the voltage is smaller than the min value (max is ignored) |
+| '>' | Callback is triggered when the voltage is greater than the min value (max is ignored) |*/
         pub async fn set_stack_voltage_callback_threshold(
             &mut self,
             request: crate::bindings::master::SetStackVoltageCallbackThresholdRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    53u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let mut payload = [0; 5usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3898,15 +4085,18 @@ Die folgenden Optionen sind möglich:
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Schwellwert zurück, wie von :func:`Set Stack Voltage Callback Threshold` gesetzt.
-*/
+        ///Returns the threshold as set by `set_stack_voltage_callback_threshold`.
         pub async fn get_stack_voltage_callback_threshold(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetStackVoltageCallbackThresholdResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    54u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(54u8, &payload).await?;
             Ok(
@@ -3915,25 +4105,26 @@ Gibt den Schwellwert zurück, wie von :func:`Set Stack Voltage Callback Threshol
                 ),
             )
         }
-        /**
-Setzt den Schwellwert für den :cb:`USB Voltage Reached` Callback.
-
-Die folgenden Optionen sind möglich:
+        /**Sets the thresholds for the `usb_voltage_reached_stream` callback.
 
-.. csv-table::
- :header: "Option", "Beschreibung"
- :widths: 10, 100
+The following options are possible:
 
- "'x'",    "Callback ist inaktiv"
- "'o'",    "Callback wird ausgelöst, wenn die Spannung *außerhalb* des min und max Wertes ist"
- "'i'",    "Callback wird ausgelöst, wenn die Spannung *innerhalb* des min und max Wertes ist"
- "'<'",    "Callback wird ausgelöst, wenn die Spannung kleiner als der min Wert ist (max wird ignoriert)"
- "'>'",    "Callback wird ausgelöst, wenn die Spannung größer als der min Wert ist (max wird ignoriert)"
-*/
+| Option | Description |
+| --- | --- |
+| 'x' | Callback is turned off |
+| 'o' | Callback is triggered when the voltage is *outside* the min and max values |
+| 'i' | Callback is triggered when the voltage is *inside* the min and max values |
+| '<' | Callback is triggered when the voltage is smaller than the min value (max is ignored) |
+| '>' | Callback is triggered when the voltage is greater than the min value (max is ignored) |*/
         pub async fn set_usb_voltage_callback_threshold(
             &mut self,
             request: crate::bindings::master::SetUsbVoltageCallbackThresholdRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    55u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let mut payload = [0; 5usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3944,15 +4135,18 @@ Die folgenden Optionen sind möglich:
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Schwellwert zurück, wie von :func:`Set USB Voltage Callback Threshold` gesetzt.
-*/
+        ///Returns the threshold as set by `set_usb_voltage_callback_threshold`.
         pub async fn get_usb_voltage_callback_threshold(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetUsbVoltageCallbackThresholdResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    56u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(56u8, &payload).await?;
             Ok(
@@ -3961,25 +4155,28 @@ Gibt den Schwellwert zurück, wie von :func:`Set USB Voltage Callback Threshold`
                 ),
             )
         }
-        /**
-Setzt die Periode mit welcher die Schwellwert Callbacks
+        /**Sets the period with which the threshold callbacks
 
-* :cb:`Stack Current Reached`,
-* :cb:`Stack Voltage Reached`,
-* :cb:`USB Voltage Reached`
+* `stack_current_reached_stream`,
+* `stack_voltage_reached_stream`,
+* `usb_voltage_reached_stream`
 
-ausgelöst werden, wenn die Schwellwerte
+are triggered, if the thresholds
 
-* :func:`Set Stack Current Callback Threshold`,
-* :func:`Set Stack Voltage Callback Threshold`,
-* :func:`Set USB Voltage Callback Threshold`
+* `set_stack_current_callback_threshold`,
+* `set_stack_voltage_callback_threshold`,
+* `set_usb_voltage_callback_threshold`
 
-weiterhin erreicht bleiben.
-*/
+keep being reached.*/
         pub async fn set_debounce_period(
             &mut self,
             request: u32,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    57u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let mut payload = [0; 4usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -3990,24 +4187,25 @@ weiterhin erreicht bleiben.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Entprellperiode zurück, wie von :func:`Set Debounce Period` gesetzt.
-*/
+        ///Returns the debounce period as set by `set_debounce_period`.
         pub async fn get_debounce_period(
             &mut self,
         ) -> Result<u32, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    58u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 5u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(58u8, &payload).await?;
             Ok(u32::from_le_byte_slice(&result.body()[0..4usize]))
         }
-        /**
-Dieser Callback wird mit der Periode, wie gesetzt mit
-:func:`Set Stack Current Callback Period`, ausgelöst. Der :word:`parameter` ist
-die Stromstärke des Sensors.
+        /**This callback is triggered periodically with the period that is set by
+`set_stack_current_callback_period`. The parameter is the current
+of the sensor.
 
-Der :cb:`Stack Current` Callback wird nur ausgelöst, wenn sich die Stromstärke
-seit der letzten Auslösung geändert hat.
-*/
+The `stack_current_stream` callback is only triggered if the current has changed
+since the last triggering.*/
         pub async fn stack_current_stream(
             &mut self,
         ) -> impl futures_core::Stream<Item = u16> {
@@ -4016,14 +4214,12 @@ seit der letzten Auslösung geändert hat.
                 .await
                 .map(|p| u16::from_le_byte_slice(&p.body()[0..2usize]))
         }
-        /**
-Dieser Callback wird mit der Periode, wie gesetzt mit
-:func:`Set Stack Voltage Callback Period`, ausgelöst. Der :word:`parameter`
-ist die Spannung des Sensors.
+        /**This callback is triggered periodically with the period that is set by
+`set_stack_voltage_callback_period`. The parameter is the voltage
+of the sensor.
 
-Der :cb:`Stack Voltage` Callback wird nur ausgelöst, wenn sich die Spannung seit
-der letzten Auslösung geändert hat.
-*/
+The `stack_voltage_stream` callback is only triggered if the voltage has changed
+since the last triggering.*/
         pub async fn stack_voltage_stream(
             &mut self,
         ) -> impl futures_core::Stream<Item = u16> {
@@ -4032,16 +4228,14 @@ der letzten Auslösung geändert hat.
                 .await
                 .map(|p| u16::from_le_byte_slice(&p.body()[0..2usize]))
         }
-        /**
-Dieser Callback wird mit der Periode, wie gesetzt mit
-:func:`Set USB Voltage Callback Period`, ausgelöst. Der :word:`parameter` ist
-die USB Spannung.
+        /**This callback is triggered periodically with the period that is set by
+`set_usb_voltage_callback_period`. The parameter is the USB
+voltage.
 
-Der :cb:`USB Voltage` Callback wird nur ausgelöst, wenn sich die USB Spannung
-seit der letzten Auslösung geändert hat.
+The `usb_voltage_stream` callback is only triggered if the USB voltage has changed
+since the last triggering.
 
-Funktioniert nicht mit Hardware Version 2.1 oder neuer.
-*/
+Does not work with hardware version 2.1 or newer.*/
         pub async fn usb_voltage_stream(
             &mut self,
         ) -> impl futures_core::Stream<Item = u16> {
@@ -4050,14 +4244,12 @@ Funktioniert nicht mit Hardware Version 2.1 oder neuer.
                 .await
                 .map(|p| u16::from_le_byte_slice(&p.body()[0..2usize]))
         }
-        /**
-Dieser Callback wird ausgelöst, wenn der Schwellwert, wie von
-:func:`Set Stack Current Callback Threshold` gesetzt, erreicht wird.
-Der :word:`parameter` ist der Stromverbrauch des Stapels.
+        /**This callback is triggered when the threshold as set by
+`set_stack_current_callback_threshold` is reached.
+The parameter is the stack current.
 
-Wenn der Schwellwert erreicht bleibt, wird der Callback mit der Periode, wie
-mit :func:`Set Debounce Period` gesetzt, ausgelöst.
-*/
+If the threshold keeps being reached, the callback is triggered periodically
+with the period as set by `set_debounce_period`.*/
         pub async fn stack_current_reached_stream(
             &mut self,
         ) -> impl futures_core::Stream<Item = u16> {
@@ -4066,14 +4258,12 @@ mit :func:`Set Debounce Period` gesetzt, ausgelöst.
                 .await
                 .map(|p| u16::from_le_byte_slice(&p.body()[0..2usize]))
         }
-        /**
-Dieser Callback wird ausgelöst, wenn der Schwellwert, wie von
-:func:`Set Stack Voltage Callback Threshold` gesetzt, erreicht wird.
-Der :word:`parameter` ist die Spannung des Stapels.
+        /**This callback is triggered when the threshold as set by
+`set_stack_voltage_callback_threshold` is reached.
+The parameter is the stack voltage.
 
-Wenn der Schwellwert erreicht bleibt, wird der Callback mit der Periode, wie
-mit :func:`Set Debounce Period` gesetzt, ausgelöst.
-*/
+If the threshold keeps being reached, the callback is triggered periodically
+with the period as set by `set_debounce_period`.*/
         pub async fn stack_voltage_reached_stream(
             &mut self,
         ) -> impl futures_core::Stream<Item = u16> {
@@ -4082,14 +4272,12 @@ mit :func:`Set Debounce Period` gesetzt, ausgelöst.
                 .await
                 .map(|p| u16::from_le_byte_slice(&p.body()[0..2usize]))
         }
-        /**
-Dieser Callback wird ausgelöst, wenn der Schwellwert, wie von
-:func:`Set USB Voltage Callback Threshold` gesetzt, erreicht wird.
-Der :word:`parameter` ist die Spannung des Sensors.
+        /**This callback is triggered when the threshold as set by
+`set_usb_voltage_callback_threshold` is reached.
+The parameter is the voltage of the sensor.
 
-Wenn der Schwellwert erreicht bleibt, wird der Callback mit der Periode, wie
-mit :func:`Set Debounce Period` gesetzt, ausgelöst.
-*/
+If the threshold keeps being reached, the callback is triggered periodically
+with the period as set by `set_debounce_period`.*/
         pub async fn usb_voltage_reached_stream(
             &mut self,
         ) -> impl futures_core::Stream<Item = u16> {
@@ -4098,48 +4286,49 @@ mit :func:`Set Debounce Period` gesetzt, ausgelöst.
                 .await
                 .map(|p| u16::from_le_byte_slice(&p.body()[0..2usize]))
         }
-        /**
-Gibt *true* zurück, wenn der Master Brick an Position 0 im Stapel und eine
-Ethernet Extension verfügbar ist.
-*/
+        /**Returns *true* if the Master Brick is at position 0 in the stack and an Ethernet
+Extension is available.*/
         pub async fn is_ethernet_present(
             &mut self,
         ) -> Result<bool, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    65u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(65u8, &payload).await?;
             Ok(bool::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Setzt die Konfiguration der Ethernet Extension. Mögliche Werte für
-``connection`` sind:
-
-.. csv-table::
- :header: "Wert", "Beschreibung"
- :widths: 10, 90
+        /**Sets the configuration of the Ethernet Extension. Possible values for
+`connection` are:
 
- "0", "DHCP"
- "1", "Statische IP"
+| Value | Description |
+| --- | --- |
+| 0 | DHCP |
+| 1 | Static IP |
 
-Wenn ``connection`` auf die statische IP Option gesetzt wird, dann müssen
-``ip``, ``subnet_mask`` und ``gateway`` als ein Array der Größe 4 angegeben
-werden. Dabei ist das erste Element im Array das niederwertigste Byte. Falls
-``connection`` auf die DHCP Option gesetzt ist, werden ``ip``, ``subnet_mask``
-und ``gateway`` ignoriert.
+If you set `connection` to static IP options then you have to supply `ip`,
+`subnet_mask` and `gateway` as an array of size 4 (first element of the
+array is the least significant byte of the address). If `connection` is set
+to the DHCP options then `ip`, `subnet_mask` and `gateway` are ignored,
+you can set them to 0.
 
-Der letzte Parameter ist der Port auf den das Anwendungsprogramm sich
-verbindet.
+The last parameter is the port that your program will connect to.
 
-Die Werte sind im EEPROM gespeichert und werden nur beim Start angewandt.
-Das bedeutet der Master Brick muss nach einer Konfiguration neu gestartet
-werden.
+The values are stored in the EEPROM and only applied on startup. That means
+you have to restart the Master Brick after configuration.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die Ethernet Extension zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to set the Ethernet configuration.*/
         pub async fn set_ethernet_configuration(
             &mut self,
             request: crate::bindings::master::SetEthernetConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    66u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 1u8, 0u8),
+                )?;
             let mut payload = [0; 15usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4150,15 +4339,18 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Konfiguration zurück, wie von :func:`Set Ethernet Configuration` gesetzt.
-*/
+        ///Returns the configuration as set by `set_ethernet_configuration`.
         pub async fn get_ethernet_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetEthernetConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    67u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(67u8, &payload).await?;
             Ok(
@@ -4167,23 +4359,26 @@ Gibt die Konfiguration zurück, wie von :func:`Set Ethernet Configuration` geset
                 ),
             )
         }
-        /**
-Gibt den Status der Ethernet Extension zurück.
+        /**Returns the status of the Ethernet Extension.
 
-``mac_address``, ``ip``, ``subnet_mask`` und ``gateway`` werden als Array
-übergeben. Das erste Element des Arrays ist das niederwertigste Byte.
+`mac_address`, `ip`, `subnet_mask` and `gateway` are given as an array.
+The first element of the array is the least significant byte of the address.
 
-``rx_count`` und ``tx_count`` sind die Anzahl der Bytes die seit dem letzten
-Neustart empfangen/gesendet wurden.
+`rx_count` and `tx_count` are the number of bytes that have been
+received/send since last restart.
 
-``hostname`` ist der aktuell genutzte Hostname.
-*/
+`hostname` is the currently used hostname.*/
         pub async fn get_ethernet_status(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetEthernetStatusResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    68u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 1u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(68u8, &payload).await?;
             Ok(
@@ -4192,19 +4387,21 @@ Neustart empfangen/gesendet wurden.
                 ),
             )
         }
-        /**
-Setzt den Hostnamen der Ethernet Extension. Der Hostname wird von
-Access Points als Hostname in der DHCP Client Tabelle angezeigt.
+        /**Sets the hostname of the Ethernet Extension. The hostname will be displayed
+by access points as the hostname in the DHCP clients table.
 
-Das setzen eines leeren Strings stellt den voreingestellten Hostnamen
-wieder her.
+Setting an empty String will restore the default hostname.
 
-Der aktuelle Hostname kann mit :func:`Get Ethernet Status` herausgefunden werden.
-*/
+The current hostname can be discovered with `get_ethernet_status`.*/
         pub async fn set_ethernet_hostname(
             &mut self,
             request: [char; 32usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    69u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 1u8, 0u8),
+                )?;
             let mut payload = [0; 32usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4215,17 +4412,20 @@ Der aktuelle Hostname kann mit :func:`Get Ethernet Status` herausgefunden werden
                 .await?;
             Ok(())
         }
-        /**
-Setzt die MAC Adresse der Ethernet Extension. Die Ethernet Extension sollte
-mit einer vorkonfigurierten MAC Adresse ausgeliefert werden. Diese MAC Adresse
-steht auch auf einem Aufkleber auf der Ethernet Extension.
+        /**Sets the MAC address of the Ethernet Extension. The Ethernet Extension should
+come configured with a valid MAC address, that is also written on a
+sticker of the extension itself.
 
-Die MAC Adresse kann mit :func:`Get Ethernet Status` wieder ausgelesen werden.
-*/
+The MAC address can be read out again with `get_ethernet_status`.*/
         pub async fn set_ethernet_mac_address(
             &mut self,
             request: [u8; 6usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    70u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 1u8, 0u8),
+                )?;
             let mut payload = [0; 6usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4236,27 +4436,27 @@ Die MAC Adresse kann mit :func:`Get Ethernet Status` wieder ausgelesen werden.
                 .await?;
             Ok(())
         }
-        /**
-Setzt die Ethernet WebSocket-Konfiguration. Der erste Parameter setzt
-die Anzahl der Socket-Verbindungen die für WebSockets reserviert werden.
-Der mögliche Wertebereich ist 0-7. Die Verbindungen werden zwischen den
-normalen Sockets und den WebSockets aufgeteilt. Beispiel: Wenn die Socket-Verbindungen auf 3
-gesetzt werden, stehen 3 WebSockets und 4 normale Sockets zur Verfügung.
+        /**Sets the Ethernet WebSocket configuration. The first parameter sets the number of socket
+connections that are reserved for WebSockets. The range is 0-7. The connections
+are shared with the plain sockets. Example: If you set the connections to 3,
+there will be 3 WebSocket and 4 plain socket connections available.
 
-Der zweite Parameter ist der Port für die WebSocket-Verbindungen. Der Port
-kann nicht der gleiche sein wie der Port des normalen Sockets.
+The second parameter is the port for the WebSocket connections. The port can
+not be the same as the port for the plain socket connections.
 
-Die Werte sind im EEPROM gespeichert und werden nur beim Start angewandt.
-Das bedeutet der Master Brick muss nach einer Konfiguration neu gestartet
-werden.
+The values are stored in the EEPROM and only applied on startup. That means
+you have to restart the Master Brick after configuration.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die Ethernet Extension zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to set the Ethernet configuration.*/
         pub async fn set_ethernet_websocket_configuration(
             &mut self,
             request: crate::bindings::master::SetEthernetWebsocketConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    71u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 2u8, 0u8),
+                )?;
             let mut payload = [0; 3usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4267,15 +4467,18 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Konfiguration zurück, wie von :func:`Set Ethernet Configuration` gesetzt.
-*/
+        ///Returns the configuration as set by `set_ethernet_configuration`.
         pub async fn get_ethernet_websocket_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetEthernetWebsocketConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    72u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 2u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(72u8, &payload).await?;
             Ok(
@@ -4284,26 +4487,27 @@ Gibt die Konfiguration zurück, wie von :func:`Set Ethernet Configuration` geset
                 ),
             )
         }
-        /**
-Setzt das Authentifizierungsgeheimnis. Das Geheimnis ist ein String aus bis zu
-64 Buchstaben. Ein leerer String deaktiviert die Authentifizierung.
+        /**Sets the Ethernet authentication secret. The secret can be a string of up to 64
+characters. An empty string disables the authentication.
 
-Für mehr Informationen zur Authentifizierung siehe das dazugehörige
-:ref:`Tutorial <tutorial_authentication>`.
+See the authentication tutorial for more
+information.
 
-Das Authentifizierungsgehemnis wird im EEPROM gespeichert und nur beim Start angewandt.
-Das bedeutet der Master Brick muss nach einer Konfiguration neu gestartet
-werden.
+The secret is stored in the EEPROM and only applied on startup. That means
+you have to restart the Master Brick after configuration.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die Authentifizierung der Ethernet
-Extension einzurichten.
+It is recommended to use the Brick Viewer to set the Ethernet authentication secret.
 
-Der Standardwert ist ein leerer String (Authentifizierung deaktiviert).
-*/
+The default value is an empty string (authentication disabled).*/
         pub async fn set_ethernet_authentication_secret(
             &mut self,
             request: [char; 64usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    73u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 2u8, 0u8),
+                )?;
             let mut payload = [0; 64usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4314,37 +4518,41 @@ Der Standardwert ist ein leerer String (Authentifizierung deaktiviert).
                 .await?;
             Ok(())
         }
-        /**
-Gibt das Authentifizierungsgeheimnis zurück, wie von
-:func:`Set Ethernet Authentication Secret` gesetzt.
-*/
+        /**Returns the authentication secret as set by
+`set_ethernet_authentication_secret`.*/
         pub async fn get_ethernet_authentication_secret(
             &mut self,
         ) -> Result<[char; 64usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    74u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 2u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(74u8, &payload).await?;
             Ok(<[char; 64usize]>::from_le_byte_slice(&result.body()[0..64usize]))
         }
-        /**
-Setzt das Authentifizierungsgeheimnis. Das Geheimnis ist ein String aus bis zu
-64 Buchstaben. Ein leerer String deaktiviert die Authentifizierung.
+        /**Sets the WIFI authentication secret. The secret can be a string of up to 64
+characters. An empty string disables the authentication.
 
-Für mehr Informationen zur Authentifizierung siehe das dazugehörige
-:ref:`Tutorial <tutorial_authentication>`.
+See the authentication tutorial for more
+information.
 
-Das Authentifizierungsgehemnis wird im EEPROM gespeichert und nur beim Start
-angewandt. Das bedeutet der Master Brick muss nach einer Konfiguration neu
-gestartet werden.
+The secret is stored in the EEPROM and only applied on startup. That means
+you have to restart the Master Brick after configuration.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die Authentifizierung der WIFI
-Extension einzurichten.
+It is recommended to use the Brick Viewer to set the WIFI authentication secret.
 
-Der Standardwert ist ein leerer String (Authentifizierung deaktiviert).
-*/
+The default value is an empty string (authentication disabled).*/
         pub async fn set_wifi_authentication_secret(
             &mut self,
             request: [char; 64usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    75u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 2u8, 0u8),
+                )?;
             let mut payload = [0; 64usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4355,20 +4563,21 @@ Der Standardwert ist ein leerer String (Authentifizierung deaktiviert).
                 .await?;
             Ok(())
         }
-        /**
-Gibt das Authentifizierungsgeheimnis zurück, wie von
-:func:`Set Wifi Authentication Secret` gesetzt.
-*/
+        /**Returns the authentication secret as set by
+`set_wifi_authentication_secret`.*/
         pub async fn get_wifi_authentication_secret(
             &mut self,
         ) -> Result<[char; 64usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    76u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 2u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(76u8, &payload).await?;
             Ok(<[char; 64usize]>::from_le_byte_slice(&result.body()[0..64usize]))
         }
-        /**
-Gibt den Typ der Verbingung zurück, über welche diese Funktion aufgerufen wurde.
-*/
+        ///Returns the type of the connection over which this function was called.
         pub async fn get_connection_type(
             &mut self,
         ) -> Result<
@@ -4378,6 +4587,11 @@ Gibt den Typ der Verbingung zurück, über welche diese Funktion aufgerufen wurd
             >,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    77u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(77u8, &payload).await?;
             Ok(
@@ -4387,52 +4601,60 @@ Gibt den Typ der Verbingung zurück, über welche diese Funktion aufgerufen wurd
                 >::from_le_byte_slice(&result.body()[0..1usize]),
             )
         }
-        /**
-Gibt *true* zurück, wenn der Master Brick an Position 0 im Stapel und eine
-WIFI Extension 2.0 verfügbar ist.
-*/
+        /**Returns *true* if the Master Brick is at position 0 in the stack and a WIFI
+Extension 2.0 is available.*/
         pub async fn is_wifi_2_present(
             &mut self,
         ) -> Result<bool, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    78u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(78u8, &payload).await?;
             Ok(bool::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Startet den Bootloader der WIFI Extension 2.0. Gibt bei Erfolg 0 zurück.
-Danach können die :func:`Write Wifi2 Serial Port` und :func:`Read Wifi2 Serial Port`
-Funktionen zur Kommunikation mit dem Bootloader verwendet werden, um eine neue
-Firmware zu flashen.
+        /**Starts the bootloader of the WIFI Extension 2.0. Returns 0 on success.
+Afterwards the `write_wifi_2_serial_port` and `read_wifi_2_serial_port`
+functions can be used to communicate with the bootloader to flash a new
+firmware.
 
-Der Bootloader sollte nur über eine USB Verbindung gestartet werden. Er kann
-nicht über eine WIFI2 Verbindung gestartet werden, siehe die
-:func:`Get Connection Type` Funktion.
+The bootloader should only be started over a USB connection. It cannot be
+started over a WIFI2 connection, see the `get_connection_type` function.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die Firmware der WIFI
-Extension 2.0 zu aktualisieren.
-*/
+It is recommended to use the Brick Viewer to update the firmware of the WIFI
+Extension 2.0.*/
         pub async fn start_wifi_2_bootloader(
             &mut self,
         ) -> Result<i8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    79u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(79u8, &payload).await?;
             Ok(i8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Schreibt bis zu 60 Bytes (Anzahl zu schreibender Bytes mit ``length`` angeben)
-auf die serielle Schnittstelle des Bootloaders der WIFI Extension 2.0. Gibt
-bei Erfolg 0 zurück.
+        /**Writes up to 60 bytes (number of bytes to be written specified by `length`)
+to the serial port of the bootloader of the WIFI Extension 2.0. Returns 0 on
+success.
 
-Bevor diese Funktion genutzt werden kann muss der Bootloader mit der
-:func:`Start Wifi2 Bootloader` Funktion gestartet werden.
+Before this function can be used the bootloader has to be started using the
+`start_wifi_2_bootloader` function.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die Firmware der WIFI
-Extension 2.0 zu aktualisieren.
-*/
+It is recommended to use the Brick Viewer to update the firmware of the WIFI
+Extension 2.0.*/
         pub async fn write_wifi_2_serial_port(
             &mut self,
             request: crate::bindings::master::WriteWifi2SerialPortRequest,
         ) -> Result<i8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    80u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let mut payload = [0; 61usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4441,17 +4663,15 @@ Extension 2.0 zu aktualisieren.
             let result = self.device.get(80u8, &payload).await?;
             Ok(i8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Liest bis zu 60 Bytes (Anzahl zu lesender Bytes mit ``length`` angegeben) von
-der seriellen Schnittstelle des Bootloaders der WIFI Extension 2.0. Gibt die
-Anzahl der wirklich gelesenen Bytes zurück.
+        /**Reads up to 60 bytes (number of bytes to be read specified by `length`)
+from the serial port of the bootloader of the WIFI Extension 2.0.
+Returns the number of actually read bytes.
 
-Bevor diese Funktion genutzt werden kann muss der Bootloader mit der
-:func:`Start Wifi2 Bootloader` Funktion gestartet werden.
+Before this function can be used the bootloader has to be started using the
+`start_wifi_2_bootloader` function.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die Firmware der WIFI
-Extension 2.0 zu aktualisieren.
-*/
+It is recommended to use the Brick Viewer to update the firmware of the WIFI
+Extension 2.0.*/
         pub async fn read_wifi_2_serial_port(
             &mut self,
             request: u8,
@@ -4459,6 +4679,11 @@ Extension 2.0 zu aktualisieren.
             crate::bindings::master::ReadWifi2SerialPortResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    81u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4471,25 +4696,27 @@ Extension 2.0 zu aktualisieren.
                 ),
             )
         }
-        /**
-Setzt das WLAN-Authentifizierungsgeheimnis. Das Geheimnis ist ein String aus
-bis zu 64 Buchstaben. Ein leerer String deaktiviert die Authentifizierung. Der
-Standardwert ist ein leerer String (Authentifizierung deaktiviert).
+        /**Sets the WIFI authentication secret. The secret can be a string of up to 64
+characters. An empty string disables the authentication. The default value is
+an empty string (authentication disabled).
 
-Für mehr Informationen zur Authentifizierung siehe das dazugehörige
-:ref:`Tutorial <tutorial_authentication>`.
+See the authentication tutorial for more
+information.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_authentication_secret(
             &mut self,
             request: [char; 64usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    82u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let mut payload = [0; 64usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4500,45 +4727,58 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt das WLAN-Authentifizierungsgeheimnis zurück, wie von
-:func:`Set Wifi2 Authentication Secret` gesetzt.
-*/
+        /**Returns the WIFI authentication secret as set by
+`set_wifi_2_authentication_secret`.*/
         pub async fn get_wifi_2_authentication_secret(
             &mut self,
         ) -> Result<[char; 64usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    83u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(83u8, &payload).await?;
             Ok(<[char; 64usize]>::from_le_byte_slice(&result.body()[0..64usize]))
         }
-        /**
-Setzt die allgemeine Konfiguration der WIFI Extension 2.0.
+        /**Sets the general configuration of the WIFI Extension 2.0.
 
-Der ``port`` Parameter setzt die Portnummer auf die sich das Anwendungsprogramm
-verbindet.
+The `port` parameter sets the port number that your programm will connect
+to.
 
-Der ``websocket_port`` Parameter setzt die WebSocket-Portnummer auf die sich das
-JavaScript Anwendungsprogramm verbindet.
+The `websocket_port` parameter sets the WebSocket port number that your
+JavaScript programm will connect to.
 
-Der ``website_port`` Parameter setzt die Portnummer für die Webseite der
+The `website_port` parameter sets the port number for the website of the
 WIFI Extension 2.0.
 
-Der ``phy_mode`` Parameter setzt den zu verwendenden WLAN-Modus. Mögliche Werte
-sinf B, G und N.
+The `phy_mode` parameter sets the specific wireless network mode to be used.
+Possible values are B, G and N.
 
-Die ``sleep_mode`` und ``website`` Parameter werden momentan nicht verwendet.
+The `sleep_mode` parameter is currently unused.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+The `website` parameter is used to enable or disable the web interface of
+the WIFI Extension 2.0, which is available from firmware version 2.0.1. Note
+that, for firmware version 2.0.3 and older, to disable the the web interface
+the `website_port` parameter must be set to 1 and greater than 1 to enable
+the web interface. For firmware version 2.0.4 and later, setting this parameter
+to 1 will enable the web interface and setting it to 0 will disable the web
+interface.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
+
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_configuration(
             &mut self,
             request: crate::bindings::master::SetWifi2ConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    84u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let mut payload = [0; 9usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4549,15 +4789,18 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die allgemeine Konfiguration zurück, wie von :func:`Set Wifi2 Configuration` gesetzt.
-*/
+        ///Returns the general configuration as set by `set_wifi_2_configuration`.
         pub async fn get_wifi_2_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifi2ConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    85u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(85u8, &payload).await?;
             Ok(
@@ -4566,15 +4809,18 @@ Gibt die allgemeine Konfiguration zurück, wie von :func:`Set Wifi2 Configuratio
                 ),
             )
         }
-        /**
-Gibt den Client und Access Point Status der WIFI Extension 2.0 zurück.
-*/
+        ///Returns the client and access point status of the WIFI Extension 2.0.
         pub async fn get_wifi_2_status(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifi2StatusResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    86u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(86u8, &payload).await?;
             Ok(
@@ -4583,40 +4829,42 @@ Gibt den Client und Access Point Status der WIFI Extension 2.0 zurück.
                 ),
             )
         }
-        /**
-Setzt die Client-spezifische Konfiguration der WIFI Extension 2.0.
+        /**Sets the client specific configuration of the WIFI Extension 2.0.
 
-Der ``enable`` Parameter aktiviert oder deaktiviert den Client-Teil der
-WIFI Extension 2.0. Der Standardwert ist *true*.
+The `enable` parameter enables or disables the client part of the
+WIFI Extension 2.0.
 
-Der ``ssid`` Parameter die SSID (bis zu 32 Zeichen) des Access Points zu dem
-die WLAN Verbindung hergestellt werden soll.
+The `ssid` parameter sets the SSID (up to 32 characters) of the access point
+to connect to.
 
-Wenn die ``ip``, ``subnet_mask`` und ``gateway`` Parameter alle auf Null gesetzt
-sind, dann wird DHCP verwendet. Andernfalls kann mit diese drei Parametern eine
-statische IP Adresse eingestellt werden. Die Standardeinstellung ist DHCP.
+If the `ip` parameter is set to all zero then `subnet_mask` and `gateway`
+parameters are also set to all zero and DHCP is used for IP address configuration.
+Otherwise those three parameters can be used to configure a static IP address.
+The default configuration is DHCP.
 
-Wenn der ``mac_address`` Parameter auf Null gesetzt ist, dann wird die
-voreingestellt MAC Adresse verwendet. Andernfalls kann mit diesem Parameter
-eine eigene MAC Adresse eingestellt werden.
+If the `mac_address` parameter is set to all zero then the factory MAC
+address is used. Otherwise this parameter can be used to set a custom MAC
+address.
 
-Wenn der ``bssid`` Parameter auf Null gesetzt ist, dann verbindet sich die
-WIFI Extension 2.0 mit einem Access Point wenn die eingestellt SSID
-übereinstimmt. Andernfalls kann dieses Parameter verwendet werden, damit sich
-die WIFI Extension 2.0 nur dann mit einem Access Point verbindet, wenn SSID
-und BSSID übereinstimmen.
+If the `bssid` parameter is set to all zero then WIFI Extension 2.0 will
+connect to any access point that matches the configured SSID. Otherwise this
+parameter can be used to make the WIFI Extension 2.0 only connect to an
+access point if SSID and BSSID match.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_client_configuration(
             &mut self,
             request: crate::bindings::master::SetWifi2ClientConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    87u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let mut payload = [0; 57usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4627,16 +4875,18 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Client Konfiguration zurück, wie von
-:func:`Set Wifi2 Client Configuration` gesetzt.
-*/
+        ///Returns the client configuration as set by `set_wifi_2_client_configuration`.
         pub async fn get_wifi_2_client_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifi2ClientConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    88u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(88u8, &payload).await?;
             Ok(
@@ -4645,21 +4895,24 @@ Gibt die Client Konfiguration zurück, wie von
                 ),
             )
         }
-        /**
-Setzt den Client Hostnamen (bis zu 32 Zeichen) der WIFI Extension 2.0. Der
-Hostname wird von Access Points als Hostname in der DHCP Client Tabelle angezeigt.
+        /**Sets the client hostname (up to 32 characters) of the WIFI Extension 2.0. The
+hostname will be displayed by access points as the hostname in the DHCP clients
+table.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_client_hostname(
             &mut self,
             request: [char; 32usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    89u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let mut payload = [0; 32usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4670,30 +4923,35 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Client Hostnamen zurück, wie von :func:`Set Wifi2 Client Hostname` gesetzt.
-*/
+        ///Returns the client hostname as set by `set_wifi_2_client_hostname`.
         pub async fn get_wifi_2_client_hostname(
             &mut self,
         ) -> Result<[char; 32usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    90u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(90u8, &payload).await?;
             Ok(<[char; 32usize]>::from_le_byte_slice(&result.body()[0..32usize]))
         }
-        /**
-Setzt das Client-Passwort (bis zu 63 Zeichen) für WPA/WPA2 Verschlüsselung.
+        /**Sets the client password (up to 63 chars) for WPA/WPA2 encryption.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_client_password(
             &mut self,
             request: [char; 64usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    91u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let mut payload = [0; 64usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4704,58 +4962,62 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt das Client-Passwort zurück, wie von :func:`Set Wifi2 Client Password` gesetzt.
+        /**Returns the client password as set by `set_wifi_2_client_password`.
 
-.. note::
- Seit WIFI Extension 2.0 Firmware Version 2.1.3 wird das Passwort
- nicht mehr zurückgegeben.
-*/
+**Note:**
+Since WIFI Extension 2.0 firmware version 2.1.3 the password is not
+returned anymore.*/
         pub async fn get_wifi_2_client_password(
             &mut self,
         ) -> Result<[char; 64usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    92u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(92u8, &payload).await?;
             Ok(<[char; 64usize]>::from_le_byte_slice(&result.body()[0..64usize]))
         }
-        /**
-Setzt die Access-Point-spezifische Konfiguration der WIFI Extension 2.0.
+        /**Sets the access point specific configuration of the WIFI Extension 2.0.
 
-Der ``enable`` Parameter aktiviert oder deaktiviert den Access-Point-Teil der
-WIFI Extension 2.0. Der Standardwert ist *true*.
+The `enable` parameter enables or disables the access point part of the
+WIFI Extension 2.0.
 
-Der ``ssid`` Parameter die SSID (bis zu 32 Zeichen) des Access Points.
+The `ssid` parameter sets the SSID (up to 32 characters) of the access point.
 
-Wenn die ``ip``, ``subnet_mask`` und ``gateway`` Parameter alle auf Null gesetzt
-sind, dann wird ein DHCP Server aktiviert. Andernfalls kann mit diese drei
-Parametern eine statische IP Adresse eingestellt werden. Die Standardeinstellung
-ist DHCP.
+If the `ip` parameter is set to all zero then `subnet_mask` and `gateway`
+parameters are also set to all zero and DHCP is used for IP address configuration.
+Otherwise those three parameters can be used to configure a static IP address.
+The default configuration is DHCP.
 
-Der ``encryption`` Parameter legt den Verschlüsselungsmodus fest. Mögliche Werte
-sind Open (keine  Verschlüsselung), WEP oder WPA/WPA2 PSK.
-Mit der :func:`Set Wifi2 AP Password` Kann das
-Verschlüsselungspasswort gesetzt werden.
+The `encryption` parameter sets the encryption mode to be used. Possible
+values are Open (no encryption), WEP or WPA/WPA2 PSK.
+Use the `set_wifi_2_ap_password` function to set the encryption
+password.
 
-Der ``hidden`` Parameter legt fest, oder der Access Point seine SSID versteckt
-oder zeigt.
+The `hidden` parameter makes the access point hide or show its SSID.
 
-Der ``channel`` Parameter gibt den Kanal (1 to 13) des Access Points and.
+The `channel` parameter sets the channel (1 to 13) of the access point.
 
-Wenn der ``mac_address`` Parameter auf Null gesetzt ist, dann wird die
-voreingestellt MAC Adresse verwendet. Andernfalls kann mit diesem Parameter
-eine eigene MAC Adresse eingestellt werden.
+If the `mac_address` parameter is set to all zero then the factory MAC
+address is used. Otherwise this parameter can be used to set a custom MAC
+address.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_ap_configuration(
             &mut self,
             request: crate::bindings::master::SetWifi2ApConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    93u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let mut payload = [0; 54usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4766,16 +5028,18 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Access-Point-Konfiguration zurück, wie von
-:func:`Set Wifi2 AP Configuration` gesetzt.
-*/
+        ///Returns the access point configuration as set by `set_wifi_2_ap_configuration`.
         pub async fn get_wifi_2_ap_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifi2ApConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    94u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(94u8, &payload).await?;
             Ok(
@@ -4784,21 +5048,23 @@ Gibt die Access-Point-Konfiguration zurück, wie von
                 ),
             )
         }
-        /**
-Setzt das Access-Point-Passwort (mindestens 8 und bis zu 63 Zeichen) für den eingestellten
-Verschlüsselungsmodus, siehe :func:`Set Wifi2 AP Configuration`.
+        /**Sets the access point password (at least 8 and up to 63 chars) for the configured encryption
+mode, see `set_wifi_2_ap_configuration`.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_ap_password(
             &mut self,
             request: [char; 64usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    95u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let mut payload = [0; 64usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4809,45 +5075,53 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Gibt das Access-Point-Passwort zurück, wie von :func:`Set Wifi2 AP Password` gesetzt.
+        /**Returns the access point password as set by `set_wifi_2_ap_password`.
 
-.. note::
- Seit WIFI Extension 2.0 Firmware Version 2.1.3 wird das Passwort
- nicht mehr zurückgegeben.
-*/
+**Note:**
+Since WIFI Extension 2.0 firmware version 2.1.3 the password is not
+returned anymore.*/
         pub async fn get_wifi_2_ap_password(
             &mut self,
         ) -> Result<[char; 64usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    96u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(96u8, &payload).await?;
             Ok(<[char; 64usize]>::from_le_byte_slice(&result.body()[0..64usize]))
         }
-        /**
-Alle Konfigurationsfunktionen der WIFI Extension 2.0 ändern die Werte nicht
-dauerhaft. Nach einer Konfiguration muss diese Funktion aufgerufen werden, um
-die Werte dauerhaft zu speichern.
+        /**All configuration functions for the WIFI Extension 2.0 do not change the
+values permanently. After configuration this function has to be called to
+permanently store the values.
 
-Die Werte sind im EEPROM gespeichert und werden nur beim Start angewandt.
-Das bedeutet der Master Brick muss nach einer Konfiguration neu gestartet
-werden.
-*/
+The values are stored in the EEPROM and only applied on startup. That means
+you have to restart the Master Brick after configuration.*/
         pub async fn save_wifi_2_configuration(
             &mut self,
         ) -> Result<u8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    97u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(97u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Gibt die aktuelle Version der WIFI Extension 2.0 Firmware zurück.
-*/
+        ///Returns the current version of the WIFI Extension 2.0 firmware.
         pub async fn get_wifi_2_firmware_version(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifi2FirmwareVersionResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    98u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(98u8, &payload).await?;
             Ok(
@@ -4856,80 +5130,89 @@ Gibt die aktuelle Version der WIFI Extension 2.0 Firmware zurück.
                 ),
             )
         }
-        /**
-Aktiviert die grüne Status LED der WIFI Extension 2.0.
-*/
+        ///Turns the green status LED of the WIFI Extension 2.0 on.
         pub async fn enable_wifi_2_status_led(
             &mut self,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    99u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             self.device
                 .set(99u8, &payload, Some(std::time::Duration::from_secs(20)))
                 .await?;
             Ok(())
         }
-        /**
-Deaktiviert die grüne Status LED der WIFI Extension 2.0.
-*/
+        ///Turns the green status LED of the WIFI Extension 2.0 off.
         pub async fn disable_wifi_2_status_led(
             &mut self,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    100u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             self.device
                 .set(100u8, &payload, Some(std::time::Duration::from_secs(20)))
                 .await?;
             Ok(())
         }
-        /**
-Gibt *true* zurück falls die grüne Status LED der WIFI Extension 2.0 aktiviert ist.
-*/
+        ///Returns *true* if the green status LED of the WIFI Extension 2.0 is turned on.
         pub async fn is_wifi_2_status_led_enabled(
             &mut self,
         ) -> Result<bool, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    101u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(101u8, &payload).await?;
             Ok(bool::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Benötigt WIFI Extension 2.0 Firmware 2.1.0.
+        /**Requires WIFI Extension 2.0 firmware 2.1.0.
 
-Set die Mesh-Konfiguration der WIFI Extension 2.0.
+Sets the mesh specific configuration of the WIFI Extension 2.0.
 
-Der ``enable`` Parameter aktiviert oder deaktiviert den Mesh-Teil der
-WIFI Extension 2.0. Der Mesh-Teil kann nicht
-zusammen mit dem Client- und Access-Point-Teil aktiviert werden.
+The `enable` parameter enables or disables the mesh part of the
+WIFI Extension 2.0. The mesh part cannot be
+enabled together with the client and access-point part.
 
-Wenn die ``root_ip``, ``root_subnet_mask`` und ``root_gateway`` Parameter alle
-auf Null gesetzt sind, dann wird DHCP verwendet. Andernfalls kann mit diese
-drei Parametern eine statische IP Adresse eingestellt werden. Die
-Standardeinstellung ist DHCP.
+If the `root_ip` parameter is set to all zero then `root_subnet_mask`
+and `root_gateway` parameters are also set to all zero and DHCP is used for
+IP address configuration. Otherwise those three parameters can be used to
+configure a static IP address. The default configuration is DHCP.
 
-Wenn der ``router_bssid`` Parameter auf Null gesetzt ist, dann verbindet sich
-die WIFI Extension 2.0 mit einem Access Point wenn die eingestellt SSID
-übereinstimmt, siehe :func:`Set Wifi2 Mesh Router SSID`. Andernfalls kann dieses
-Parameter verwendet werden, damit sich die WIFI Extension 2.0 nur dann mit
-einem Access Point verbindet, wenn SSID und BSSID übereinstimmen. Die BSSID
-kann auch verwendet werden, um eine Verbindung mit einer verstecken SSID
-herzustellen.
+If the `router_bssid` parameter is set to all zero then the information is
+taken from Wi-Fi scan when connecting the SSID as set by
+`set_wifi_2_mesh_router_ssid`. This only works if the the SSID is not hidden.
+In case the router has hidden SSID this parameter must be specified, otherwise
+the node will not be able to reach the mesh router.
 
-Die ``group_id`` und ``group_ssid_prefix`` Parameter identifizieren in bestimmtes
-Mesh-Netzwerk und alle WIFI Extension 2.0 mit der gleichen Gruppeneinstellung
-gehören um gleichen Mesh-Netzwerk.
+The `group_id` and the `group_ssid_prefix` parameters identifies a
+particular mesh network and nodes configured with same `group_id` and the
+`group_ssid_prefix` are considered to be in the same mesh network.
 
-Die ``gateway_ip`` und ``gateway_port`` Parameter geben an, wie der Mesh-Gateway
-(brickd) erreicht werden kann.
+The `gateway_ip` and the `gateway_port` parameters specifies the location
+of the brickd that supports mesh feature.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_mesh_configuration(
             &mut self,
             request: crate::bindings::master::SetWifi2MeshConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    102u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 2u8),
+                )?;
             let mut payload = [0; 47usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4940,17 +5223,20 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Benötigt WIFI Extension 2.0 Firmware 2.1.0.
+        /**Requires WIFI Extension 2.0 firmware 2.1.0.
 
-Gibt das Mesh Konfiguration zurück, wie von :func:`Set Wifi2 Mesh Configuration` gesetzt.
-*/
+Returns the mesh configuration as set by `set_wifi_2_mesh_configuration`.*/
         pub async fn get_wifi_2_mesh_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifi2MeshConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    103u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(103u8, &payload).await?;
             Ok(
@@ -4959,27 +5245,29 @@ Gibt das Mesh Konfiguration zurück, wie von :func:`Set Wifi2 Mesh Configuration
                 ),
             )
         }
-        /**
-Benötigt WIFI Extension 2.0 Firmware 2.1.0.
+        /**Requires WIFI Extension 2.0 firmware 2.1.0.
 
-Setzt die Mesh-Router-SSID der WIFI Extension 2.0. Diese wird verwendet um den
-Mesh Router festzulegen.
+Sets the mesh router SSID of the WIFI Extension 2.0.
+It is used to specify the mesh router to connect to.
 
-Zu beachten ist, dass zwar 32 Zeichen als SSID übergeben werden können, aber im
-Moment davon nur die ersten 31 Zeichen genutzt werden bedingt durch einen Bug
-in der verwendeten Mesh-Bibliothek.
+Note that even though in the argument of this function a 32 characters long SSID
+is allowed, in practice valid SSID should have a maximum of 31 characters. This
+is due to a bug in the mesh library that we use in the firmware of the extension.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_mesh_router_ssid(
             &mut self,
             request: [char; 32usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    104u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 2u8),
+                )?;
             let mut payload = [0; 32usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -4990,35 +5278,40 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Benötigt WIFI Extension 2.0 Firmware 2.1.0.
+        /**Requires WIFI Extension 2.0 firmware 2.1.0.
 
-Gibt das Mesh-Router-SSID zurück, wie von :func:`Set Wifi2 Mesh Router SSID` gesetzt.
-*/
+Returns the mesh router SSID as set by `set_wifi_2_mesh_router_ssid`.*/
         pub async fn get_wifi_2_mesh_router_ssid(
             &mut self,
         ) -> Result<[char; 32usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    105u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(105u8, &payload).await?;
             Ok(<[char; 32usize]>::from_le_byte_slice(&result.body()[0..32usize]))
         }
-        /**
-Benötigt WIFI Extension 2.0 Firmware 2.1.0.
+        /**Requires WIFI Extension 2.0 firmware 2.1.0.
 
-Setzt das Mesh-Router-Passwort (bis zu 64 Zeichen) für WPA/WPA2 Verschlüsselung.
-Das Password wird für die Verbindung zum Mesh Router verwendet.
+Sets the mesh router password (up to 64 characters) for WPA/WPA2 encryption.
+The password will be used to connect to the mesh router.
 
-Um Konfigurationsänderungen für die WIFI Extension 2.0 zu übernehmen muss die
-:func:`Save Wifi2 Configuration` Funktion aufgerufen und der Master Brick
-danach neugestartet werden.
+To apply configuration changes to the WIFI Extension 2.0 the
+`save_wifi_2_configuration` function has to be called and the Master Brick
+has to be restarted afterwards.
 
-Wir empfehlen den Brick Viewer zu verwenden, um die WIFI Extension 2.0 zu
-konfigurieren.
-*/
+It is recommended to use the Brick Viewer to configure the WIFI Extension 2.0.*/
         pub async fn set_wifi_2_mesh_router_password(
             &mut self,
             request: [char; 64usize],
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    106u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 2u8),
+                )?;
             let mut payload = [0; 64usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5029,29 +5322,35 @@ konfigurieren.
                 .await?;
             Ok(())
         }
-        /**
-Benötigt WIFI Extension 2.0 Firmware 2.1.0.
+        /**Requires WIFI Extension 2.0 firmware 2.1.0.
 
-Gibt das Mesh-Router-Password zurück, wie von :func:`Set Wifi2 Mesh Router Password` gesetzt.
-*/
+Returns the mesh router password as set by `set_wifi_2_mesh_router_password`.*/
         pub async fn get_wifi_2_mesh_router_password(
             &mut self,
         ) -> Result<[char; 64usize], tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    107u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(107u8, &payload).await?;
             Ok(<[char; 64usize]>::from_le_byte_slice(&result.body()[0..64usize]))
         }
-        /**
-Benötigt WIFI Extension 2.0 Firmware 2.1.0.
+        /**Requires WIFI Extension 2.0 firmware 2.1.0.
 
-Gibt den allgemeinen Mesh-Status der WIFI Extension 2.0 zurück.
-*/
+Returns the common mesh status of the WIFI Extension 2.0.*/
         pub async fn get_wifi_2_mesh_common_status(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifi2MeshCommonStatusResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    108u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(108u8, &payload).await?;
             Ok(
@@ -5060,17 +5359,20 @@ Gibt den allgemeinen Mesh-Status der WIFI Extension 2.0 zurück.
                 ),
             )
         }
-        /**
-Benötigt WIFI Extension 2.0 Firmware 2.1.0.
+        /**Requires WIFI Extension 2.0 firmware 2.1.0.
 
-Gibt den Mesh-Client-Status der WIFI Extension 2.0 zurück.
-*/
+Returns the mesh client status of the WIFI Extension 2.0.*/
         pub async fn get_wifi_2_mesh_client_status(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifi2MeshClientStatusResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    109u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(109u8, &payload).await?;
             Ok(
@@ -5079,17 +5381,20 @@ Gibt den Mesh-Client-Status der WIFI Extension 2.0 zurück.
                 ),
             )
         }
-        /**
-Benötigt WIFI Extension 2.0 Firmware 2.1.0.
+        /**Requires WIFI Extension 2.0 firmware 2.1.0.
 
-Gibt den Mesh-AP-Status der WIFI Extension 2.0 zurück.
-*/
+Returns the mesh AP status of the WIFI Extension 2.0.*/
         pub async fn get_wifi_2_mesh_ap_status(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetWifi2MeshApStatusResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    110u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(110u8, &payload).await?;
             Ok(
@@ -5098,17 +5403,15 @@ Gibt den Mesh-AP-Status der WIFI Extension 2.0 zurück.
                 ),
             )
         }
-        /**
-Diese Funktion wird Tinkerforge-Intern genutzt um initial den
-Bootstrapper und Bootloader auf Bricklets zu flashen.
-
-Falls die Notwendigkeit besteht einen Bootstrapper/Bootloader
-zu flashen (zum Beispiel weil ein eigenes Bricklet entwickelet
-wurde) bitte nicht diese Funktion direkt benutzen.
+        /**This function is for internal use to flash the initial
+bootstrapper and bootloader to the Bricklets.
 
-Dafür kann unser Open Source Flash/Test-Tool genutzt werden:
+If you need to flash a boostrapper/bootloader (for exmaple
+because you made your own Bricklet from scratch) please
+take a look at our open source flash and test tool at
 `https://github.com/Tinkerforge/flash-test <https://github.com/Tinkerforge/flash-test>`__
-*/
+
+Don't use this function directly.*/
         pub async fn set_bricklet_xmc_flash_config(
             &mut self,
             request: crate::bindings::master::SetBrickletXmcFlashConfigRequest,
@@ -5116,6 +5419,11 @@ Dafür kann unser Open Source Flash/Test-Tool genutzt werden:
             crate::bindings::master::SetBrickletXmcFlashConfigResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    111u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 5u8, 0u8),
+                )?;
             let mut payload = [0; 64usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5128,21 +5436,24 @@ Dafür kann unser Open Source Flash/Test-Tool genutzt werden:
                 ),
             )
         }
-        /**
-Diese Funktion wird Tinkerforge-Intern genutzt um initial den
-Bootstrapper und Bootloader auf Bricklets zu flashen.
+        /**This function is for internal use to flash the initial
+bootstrapper and bootloader to the Bricklets.
 
-Falls die Notwendigkeit besteht einen Bootstrapper/Bootloader
-zu flashen (zum Beispiel weil ein eigenes Bricklet entwickelet
-wurde) bitte nicht diese Funktion direkt benutzen.
-
-Dafür kann unser Open Source Flash/Test-Tool genutzt werden:
+If you need to flash a boostrapper/bootloader (for exmaple
+because you made your own Bricklet from scratch) please
+take a look at our open source flash and test tool at
 `https://github.com/Tinkerforge/flash-test <https://github.com/Tinkerforge/flash-test>`__
-*/
+
+Don't use this function directly.*/
         pub async fn set_bricklet_xmc_flash_data(
             &mut self,
             request: [u8; 64usize],
         ) -> Result<u32, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    112u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 5u8, 0u8),
+                )?;
             let mut payload = [0; 64usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5151,11 +5462,21 @@ Dafür kann unser Open Source Flash/Test-Tool genutzt werden:
             let result = self.device.get(112u8, &payload).await?;
             Ok(u32::from_le_byte_slice(&result.body()[0..4usize]))
         }
-        #[doc = "\nDiese Funktion ist nur nur Master Brick Hardware Version >= 3.0 verfügbar.\n\nAktiviert/Deaktiviert alle vier Bricklets wenn auf *true*/*false* gesetzt.\n\nWenn die Bricklets deaktiviert werden, wird die Stromversorgung zu den Bricklets getrennt.\nDie Bricklets verlieren dabei ihre aktuelle konfiguration.\n"]
+        /**This function is only available in Master Brick hardware version >= 3.0.
+
+Enables/disables all four Bricklets if set to true/false.
+
+If you disable the Bricklets the power supply to the Bricklets will be disconnected.
+The Bricklets will lose all configurations if disabled.*/
         pub async fn set_bricklets_enabled(
             &mut self,
             request: bool,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    113u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 5u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5166,41 +5487,46 @@ Dafür kann unser Open Source Flash/Test-Tool genutzt werden:
                 .await?;
             Ok(())
         }
-        /**
-Gibt *true* zurück wenn die Bricklets aktiviert sind und *false* wenn sie deaktiviert sind.
-*/
+        ///Returns *true* if the Bricklets are enabled, *false* otherwise.
         pub async fn get_bricklets_enabled(
             &mut self,
         ) -> Result<bool, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    114u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 5u8, 0u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(114u8, &payload).await?;
             Ok(bool::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Das SPITF-Protokoll kann mit einer dynamischen Baudrate genutzt werden. Wenn die dynamische
-Baudrate aktiviert ist, versucht der Brick die Baudrate anhand des Datenaufkommens
-zwischen Brick und Bricklet anzupassen.
+        /**The SPITF protocol can be used with a dynamic baudrate. If the dynamic baudrate is
+enabled, the Brick will try to adapt the baudrate for the communication
+between Bricks and Bricklets according to the amount of data that is transferred.
 
-Die Baudrate wird exponentiell erhöht wenn viele Daten gesendet/empfangen werden
-und linear verringert wenn wenig Daten gesendet/empfangen werden.
+The baudrate will be increased exponentially if lots of data is sent/received and
+decreased linearly if little data is sent/received.
 
-Diese Vorgehensweise verringert die Baudrate in Anwendungen wo nur wenig Daten
-ausgetauscht werden müssen (z.B. eine Wetterstation) und erhöht die Robustheit.
-Wenn immer viele Daten ausgetauscht werden (z.B. Thermal Imaging Bricklet), wird
-die Baudrate automatisch erhöht.
+This lowers the baudrate in applications where little data is transferred (e.g.
+a weather station) and increases the robustness. If there is lots of data to transfer
+(e.g. Thermal Imaging Bricklet) it automatically increases the baudrate as needed.
 
-In Fällen wo wenige Daten all paar Sekunden so schnell wie Möglich übertragen werden
-sollen (z.B. RS485 Bricklet mit hoher Baudrate aber kleinem Payload) kann die
-dynamische Baudrate zum maximieren der Performance ausgestellt werden.
+In cases where some data has to transferred as fast as possible every few seconds
+(e.g. RS485 Bricklet with a high baudrate but small payload) you may want to turn
+the dynamic baudrate off to get the highest possible performance.
 
-Die maximale Baudrate kann pro Port mit der Funktion :func:`Set SPITFP Baudrate`.
-gesetzt werden. Falls die dynamische Baudrate nicht aktiviert ist, wird die Baudrate
-wie von :func:`Set SPITFP Baudrate` gesetzt statisch verwendet.
-*/
+The maximum value of the baudrate can be set per port with the function
+`set_spitfp_baudrate`. If the dynamic baudrate is disabled, the baudrate
+as set by `set_spitfp_baudrate` will be used statically.*/
         pub async fn set_spitfp_baudrate_config(
             &mut self,
             request: crate::bindings::master::SetSpitfpBaudrateConfigRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    231u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 6u8),
+                )?;
             let mut payload = [0; 5usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5211,15 +5537,18 @@ wie von :func:`Set SPITFP Baudrate` gesetzt statisch verwendet.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Baudratenkonfiguration zurück, siehe :func:`Set SPITFP Baudrate Config`.
-*/
+        ///Returns the baudrate config, see `set_spitfp_baudrate_config`.
         pub async fn get_spitfp_baudrate_config(
             &mut self,
         ) -> Result<
             crate::bindings::master::GetSpitfpBaudrateConfigResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    232u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 6u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(232u8, &payload).await?;
             Ok(
@@ -5228,18 +5557,21 @@ Gibt die Baudratenkonfiguration zurück, siehe :func:`Set SPITFP Baudrate Config
                 ),
             )
         }
-        /**
-Gibt den Timeout-Zähler für die verschiedenen Kommunikationsmöglichkeiten zurück
+        /**Returns the timeout count for the different communication methods.
 
-Die Kommunikationsmöglichkeiten 0-2 stehen auf allen Bricks zur verfügung, 3-7 nur auf Master Bricks.
+The methods 0-2 are available for all Bricks, 3-7 only for Master Bricks.
 
-Diese Funktion ist hauptsächlich zum debuggen während der Entwicklung gedacht.
-Im normalen Betrieb sollten alle Zähler fast immer auf 0 stehen bleiben.
-*/
+This function is mostly used for debugging during development, in normal operation
+the counters should nearly always stay at 0.*/
         pub async fn get_send_timeout_count(
             &mut self,
             request: crate::bindings::master::CommunicationMethod,
         ) -> Result<u32, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    233u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 3u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5248,24 +5580,28 @@ Im normalen Betrieb sollten alle Zähler fast immer auf 0 stehen bleiben.
             let result = self.device.get(233u8, &payload).await?;
             Ok(u32::from_le_byte_slice(&result.body()[0..4usize]))
         }
-        /**
-Setzt die Baudrate eines spezifischen Bricklet Ports .
+        /**Sets the baudrate for a specific Bricklet port.
 
-Für einen höheren Durchsatz der Bricklets kann die Baudrate erhöht werden.
-Wenn der Fehlerzähler auf Grund von lokaler Störeinstrahlung hoch ist
-(siehe :func:`Get SPITFP Error Count`) kann die Baudrate verringert werden.
+If you want to increase the throughput of Bricklets you can increase
+the baudrate. If you get a high error count because of high
+interference (see `get_spitfp_error_count`) you can decrease the
+baudrate.
 
-Wenn das Feature der dynamische Baudrate aktiviert ist, setzt diese Funktion
-die maximale Baudrate (siehe :func:`Set SPITFP Baudrate Config`).
+If the dynamic baudrate feature is enabled, the baudrate set by this
+function corresponds to the maximum baudrate (see `set_spitfp_baudrate_config`).
 
-EMV Tests werden mit der Standardbaudrate durchgeführt. Falls eine
-CE-Kompatibilität o.ä. in der Anwendung notwendig ist empfehlen wir die
-Baudrate nicht zu ändern.
-*/
+Regulatory testing is done with the default baudrate. If CE compatibility
+or similar is necessary in your applications we recommend to not change
+the baudrate.*/
         pub async fn set_spitfp_baudrate(
             &mut self,
             request: crate::bindings::master::SetSpitfpBaudrateRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    234u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 3u8),
+                )?;
             let mut payload = [0; 5usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5276,14 +5612,16 @@ Baudrate nicht zu ändern.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Baudrate für einen Bricklet Port zurück, siehe
-:func:`Set SPITFP Baudrate`.
-*/
+        ///Returns the baudrate for a given Bricklet port, see `set_spitfp_baudrate`.
         pub async fn get_spitfp_baudrate(
             &mut self,
             request: char,
         ) -> Result<u32, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    235u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 3u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5292,21 +5630,17 @@ Gibt die Baudrate für einen Bricklet Port zurück, siehe
             let result = self.device.get(235u8, &payload).await?;
             Ok(u32::from_le_byte_slice(&result.body()[0..4usize]))
         }
-        /**
-Gibt die Anzahl der Fehler die während der Kommunikation zwischen Brick und
-Bricklet aufgetreten sind zurück.
+        /**Returns the error count for the communication between Brick and Bricklet.
 
-Die Fehler sind aufgeteilt in
+The errors are divided into
 
-* ACK-Checksummen Fehler,
-* Message-Checksummen Fehler,
-* Framing Fehler und
-* Overflow Fehler.
+* ACK checksum errors,
+* message checksum errors,
+* framing errors and
+* overflow errors.
 
-Die Fehlerzähler sind für Fehler die auf der Seite des Bricks auftreten.
-Jedes Bricklet hat eine ähnliche Funktion welche die Fehler auf Brickletseite
-ausgibt.
-*/
+The errors counts are for errors that occur on the Brick side. All
+Bricklets have a similar function that returns the errors on the Bricklet side.*/
         pub async fn get_spitfp_error_count(
             &mut self,
             request: char,
@@ -5314,6 +5648,11 @@ ausgibt.
             crate::bindings::master::GetSpitfpErrorCountResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    237u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 4u8, 3u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5326,59 +5665,64 @@ ausgibt.
                 ),
             )
         }
-        /**
-Aktiviert die Status LED.
+        /**Enables the status LED.
 
-Die Status LED ist die blaue LED neben dem USB-Stecker. Wenn diese aktiviert
-ist, ist sie an und sie flackert wenn Daten transferiert werden. Wenn sie
-deaktiviert ist, ist sie immer aus.
+The status LED is the blue LED next to the USB connector. If enabled is is
+on and it flickers if data is transfered. If disabled it is always off.
 
-Der Standardzustand ist aktiviert.
-*/
+The default state is enabled.*/
         pub async fn enable_status_led(
             &mut self,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    238u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 3u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             self.device
                 .set(238u8, &payload, Some(std::time::Duration::from_secs(20)))
                 .await?;
             Ok(())
         }
-        /**
-Deaktiviert die Status LED.
+        /**Disables the status LED.
 
-Die Status LED ist die blaue LED neben dem USB-Stecker. Wenn diese aktiviert
-ist, ist sie an und sie flackert wenn Daten transferiert werden. Wenn sie
-deaktiviert ist, ist sie immer aus.
+The status LED is the blue LED next to the USB connector. If enabled is is
+on and it flickers if data is transfered. If disabled it is always off.
 
-Der Standardzustand ist aktiviert.
-*/
+The default state is enabled.*/
         pub async fn disable_status_led(
             &mut self,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    239u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 3u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             self.device
                 .set(239u8, &payload, Some(std::time::Duration::from_secs(20)))
                 .await?;
             Ok(())
         }
-        /**
-Gibt *true* zurück wenn die Status LED aktiviert ist, *false* sonst.
-*/
+        ///Returns *true* if the status LED is enabled, *false* otherwise.
         pub async fn is_status_led_enabled(
             &mut self,
         ) -> Result<bool, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    240u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 3u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(240u8, &payload).await?;
             Ok(bool::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Gibt die Firmware und Protokoll Version und den Namen des Bricklets für einen
-gegebenen Port zurück.
+        /**Returns the firmware and protocol version and the name of the Bricklet for a
+given port.
 
-Der einzige Zweck dieser Funktion ist es, automatischen Flashen von Bricklet
-v1.x.y Plugins zu ermöglichen.
-*/
+This functions sole purpose is to allow automatic flashing of v1.x.y Bricklet
+plugins.*/
         pub async fn get_protocol_1_bricklet_name(
             &mut self,
             request: char,
@@ -5386,6 +5730,11 @@ v1.x.y Plugins zu ermöglichen.
             crate::bindings::master::GetProtocol1BrickletNameResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    241u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 0u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -5398,44 +5747,49 @@ v1.x.y Plugins zu ermöglichen.
                 ),
             )
         }
-        /**
-Gibt die Temperatur, gemessen im Mikrocontroller, aus. Der
-Rückgabewert ist nicht die Umgebungstemperatur.
+        /**Returns the temperature as measured inside the microcontroller. The
+value returned is not the ambient temperature!
 
-Die Temperatur ist lediglich proportional zur echten Temperatur und hat eine
-Genauigkeit von ±15%. Daher beschränkt sich der praktische Nutzen auf die
-Indikation von Temperaturveränderungen.
-*/
+The temperature is only proportional to the real temperature and it has an
+accuracy of ±15%. Practically it is only useful as an indicator for
+temperature changes.*/
         pub async fn get_chip_temperature(
             &mut self,
         ) -> Result<i16, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    242u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 1u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(242u8, &payload).await?;
             Ok(i16::from_le_byte_slice(&result.body()[0..2usize]))
         }
-        /**
-Ein Aufruf dieser Funktion setzt den Brick zurück. Befindet sich der Brick
-innerhalb eines Stapels wird der gesamte Stapel zurück gesetzt.
+        /**Calling this function will reset the Brick. Calling this function
+on a Brick inside of a stack will reset the whole stack.
 
-Nach dem Zurücksetzen ist es notwendig neue Geräteobjekte zu erzeugen,
-Funktionsaufrufe auf bestehende führt zu undefiniertem Verhalten.
-*/
+After a reset you have to create new device objects,
+calling functions on the existing ones will result in
+undefined behavior!*/
         pub async fn reset(
             &mut self,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    243u8,
+                    tinkerforge_base::ip_connection::Version::new(1u8, 2u8, 1u8),
+                )?;
             let payload = [0; 0usize];
             self.device
                 .set(243u8, &payload, Some(std::time::Duration::from_secs(20)))
                 .await?;
             Ok(())
         }
-        /**
-Schreibt 32 Bytes Firmware auf das Bricklet, dass am gegebenen Port angeschlossen ist.
-Die Bytes werden an die Position offset * 32 geschrieben.
+        /**Writes 32 bytes of firmware to the bricklet attached at the given port.
+The bytes are written to the position offset * 32.
 
-Diese Funktion wird vom Brick Viewer während des Flashens benutzt. In einem
-normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
-*/
+This function is used by Brick Viewer during flashing. It should not be
+necessary to call it in a normal user program.*/
         pub async fn write_bricklet_plugin(
             &mut self,
             request: crate::bindings::master::WriteBrickletPluginRequest,
@@ -5450,13 +5804,11 @@ normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
                 .await?;
             Ok(())
         }
-        /**
-Liest 32 Bytes Firmware vom Bricklet, dass am gegebenen Port angeschlossen ist.
-Die Bytes werden ab der Position offset * 32 gelesen.
+        /**Reads 32 bytes of firmware from the bricklet attached at the given port.
+The bytes are read starting at the position offset * 32.
 
-Diese Funktion wird vom Brick Viewer während des Flashens benutzt. In einem
-normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
-*/
+This function is used by Brick Viewer during flashing. It should not be
+necessary to call it in a normal user program.*/
         pub async fn read_bricklet_plugin(
             &mut self,
             request: crate::bindings::master::ReadBrickletPluginRequest,
@@ -5469,16 +5821,13 @@ normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
             let result = self.device.get(247u8, &payload).await?;
             Ok(<[u8; 32usize]>::from_le_byte_slice(&result.body()[0..32usize]))
         }
-        /**
-Gibt die UID, die UID zu der der Brick verbunden ist, die
-Position, die Hard- und Firmware Version sowie den Device Identifier
-zurück.
+        /**Returns the UID, the UID where the Brick is connected to,
+the position, the hardware and firmware version as well as the
+device identifier.
 
-Die Position ist die Position im Stack von '0' (unterster Brick) bis '8' (oberster Brick).
+The position is the position in the stack from '0' (bottom) to '8' (top).
 
-Eine Liste der Device Identifier Werte ist :ref:`hier <device_identifier>` zu
-finden. |device_identifier_constant|
-*/
+The device identifier numbers can be found here.*/
         pub async fn get_identity(
             &mut self,
         ) -> Result<
@@ -5508,10 +5857,15 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct WritePixelsLowLevelRequest {
+        ///Range: 0 - 127
         pub x_start: u8,
+        ///Range: 0 - 63
         pub y_start: u8,
+        ///Range: 0 - 127
         pub x_end: u8,
+        ///Range: 0 - 63
         pub y_end: u8,
+        ///Range: 0 - 8192
         pub pixels_length: u16,
         pub pixels_chunk_offset: u16,
         pub pixels_chunk_data: [bool; 448usize],
@@ -5555,9 +5909,13 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct WritePixelsRequest<'d> {
+        ///Range: 0 - 127
         pub x_start: u8,
+        ///Range: 0 - 63
         pub y_start: u8,
+        ///Range: 0 - 127
         pub x_end: u8,
+        ///Range: 0 - 63
         pub y_end: u8,
         pub data: &'d [bool],
     }
@@ -5611,15 +5969,21 @@ pub mod lcd_128_x_64 {
             i += self.request.y_end.write_to_slice(&mut target[i..i + 1usize]);
             i += self.length.write_to_slice(&mut target[i..i + 2usize]);
             i += self.offset.write_to_slice(&mut target[i..i + 2usize]);
-            i += self.data.write_to_slice(&mut target[i..]);
+            target[i..i + 56usize].fill(0);
+            self.data.write_to_slice(&mut target[i..i + 56usize]);
+            i += 56usize;
             i
         }
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct ReadPixelsLowLevelRequest {
+        ///Range: 0 - 127
         pub x_start: u8,
+        ///Range: 0 - 63
         pub y_start: u8,
+        ///Range: 0 - 127
         pub x_end: u8,
+        ///Range: 0 - 63
         pub y_end: u8,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for ReadPixelsLowLevelRequest {
@@ -5650,6 +6014,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct ReadPixelsLowLevelResponse {
+        ///Range: 0 - 8192
         pub pixels_length: u16,
         pub pixels_chunk_offset: u16,
         pub pixels_chunk_data: [bool; 480usize],
@@ -5679,9 +6044,24 @@ pub mod lcd_128_x_64 {
             64usize
         }
     }
+    impl tinkerforge_base::low_level_traits::LowLevelRead<bool, ()>
+    for crate::bindings::lcd_128_x_64::ReadPixelsLowLevelResponse {
+        fn ll_message_length(&self) -> usize {
+            self.pixels_length as usize
+        }
+        fn ll_message_chunk_offset(&self) -> usize {
+            self.pixels_chunk_offset as usize
+        }
+        fn ll_message_chunk_data(&self) -> &[bool] {
+            &self.pixels_chunk_data
+        }
+        fn get_result(&self) {}
+    }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetDisplayConfigurationRequest {
+        ///Range: 0 - 63
         pub contrast: u8,
+        ///Range: 0 - 100
         pub backlight: u8,
         pub invert: bool,
         pub automatic_draw: bool,
@@ -5715,7 +6095,9 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetDisplayConfigurationResponse {
+        ///Range: 0 - 63
         pub contrast: u8,
+        ///Range: 0 - 100
         pub backlight: u8,
         pub invert: bool,
         pub automatic_draw: bool,
@@ -5749,7 +6131,9 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct WriteLineRequest {
+        ///Range: 0 - 7
         pub line: u8,
+        ///Range: 0 - 21
         pub position: u8,
         pub text: [char; 22usize],
     }
@@ -5774,9 +6158,13 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetTouchPositionResponse {
+        ///Range: 0 - 300
         pub pressure: u16,
+        ///Range: 0 - 127
         pub x: u16,
+        ///Range: 0 - 63
         pub y: u16,
+        ///Unit: 1/1000 s (Second)
         pub age: u32,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for GetTouchPositionResponse {
@@ -5802,6 +6190,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetTouchPositionCallbackConfigurationRequest {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -5829,6 +6218,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetTouchPositionCallbackConfigurationResponse {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -5856,9 +6246,13 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct TouchPositionCallback {
+        ///Range: 0 - 300
         pub pressure: u16,
+        ///Range: 0 - 127
         pub x: u16,
+        ///Range: 0 - 63
         pub y: u16,
+        ///Unit: 1/1000 s (Second)
         pub age: u32,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for TouchPositionCallback {
@@ -5930,12 +6324,19 @@ pub mod lcd_128_x_64 {
             crate::bindings::lcd_128_x_64::Gesture,
             u8,
         >,
+        ///Unit: 1/1000 s (Second)
         pub duration: u32,
+        ///Range: 0 - 300
         pub pressure_max: u16,
+        ///Range: 0 - 127
         pub x_start: u16,
+        ///Range: 0 - 63
         pub y_start: u16,
+        ///Range: 0 - 127
         pub x_end: u16,
+        ///Range: 0 - 63
         pub y_end: u16,
+        ///Unit: 1/1000 s (Second)
         pub age: u32,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for GetTouchGestureResponse {
@@ -5981,6 +6382,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetTouchGestureCallbackConfigurationRequest {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -6008,6 +6410,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetTouchGestureCallbackConfigurationResponse {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -6039,12 +6442,19 @@ pub mod lcd_128_x_64 {
             crate::bindings::lcd_128_x_64::Gesture,
             u8,
         >,
+        ///Unit: 1/1000 s (Second)
         pub duration: u32,
+        ///Range: 0 - 300
         pub pressure_max: u16,
+        ///Range: 0 - 127
         pub x_start: u16,
+        ///Range: 0 - 63
         pub y_start: u16,
+        ///Range: 0 - 127
         pub x_end: u16,
+        ///Range: 0 - 63
         pub y_end: u16,
+        ///Unit: 1/1000 s (Second)
         pub age: u32,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for TouchGestureCallback {
@@ -6120,15 +6530,18 @@ pub mod lcd_128_x_64 {
             match self {
                 false => Ok(Color::White),
                 true => Ok(Color::Black),
-                _ => Err(()),
             }
         }
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct DrawLineRequest {
+        ///Range: 0 - 127
         pub position_x_start: u8,
+        ///Range: 0 - 63
         pub position_y_start: u8,
+        ///Range: 0 - 127
         pub position_x_end: u8,
+        ///Range: 0 - 63
         pub position_y_end: u8,
         pub color: crate::bindings::lcd_128_x_64::Color,
     }
@@ -6165,9 +6578,13 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct DrawBoxRequest {
+        ///Range: 0 - 127
         pub position_x_start: u8,
+        ///Range: 0 - 63
         pub position_y_start: u8,
+        ///Range: 0 - 127
         pub position_x_end: u8,
+        ///Range: 0 - 63
         pub position_y_end: u8,
         pub fill: bool,
         pub color: crate::bindings::lcd_128_x_64::Color,
@@ -6268,7 +6685,9 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct DrawTextRequest {
+        ///Range: 0 - 127
         pub position_x: u8,
+        ///Range: 0 - 63
         pub position_y: u8,
         pub font: crate::bindings::lcd_128_x_64::Font,
         pub color: crate::bindings::lcd_128_x_64::Color,
@@ -6309,10 +6728,15 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiButtonRequest {
+        ///Range: 0 - 11
         pub index: u8,
+        ///Range: 0 - 127
         pub position_x: u8,
+        ///Range: 0 - 63
         pub position_y: u8,
+        ///Range: 1 - 128
         pub width: u8,
+        ///Range: 1 - 64
         pub height: u8,
         pub text: [char; 16usize],
     }
@@ -6351,9 +6775,13 @@ pub mod lcd_128_x_64 {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetGuiButtonResponse {
         pub active: bool,
+        ///Range: 0 - 127
         pub position_x: u8,
+        ///Range: 0 - 63
         pub position_y: u8,
+        ///Range: 1 - 128
         pub width: u8,
+        ///Range: 1 - 64
         pub height: u8,
         pub text: [char; 16usize],
     }
@@ -6391,6 +6819,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiButtonPressedCallbackConfigurationRequest {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -6418,6 +6847,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetGuiButtonPressedCallbackConfigurationResponse {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -6445,6 +6875,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GuiButtonPressedCallback {
+        ///Range: 0 - 11
         pub index: u8,
         pub pressed: bool,
     }
@@ -6503,11 +6934,16 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiSliderRequest {
+        ///Range: 0 - 5
         pub index: u8,
+        ///Range: 0 - 128
         pub position_x: u8,
+        ///Range: 0 - 64
         pub position_y: u8,
+        ///Range: 8 - 128
         pub length: u8,
         pub direction: crate::bindings::lcd_128_x_64::Direction,
+        ///Range: 0 - 120
         pub value: u8,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for SetGuiSliderRequest {
@@ -6547,13 +6983,17 @@ pub mod lcd_128_x_64 {
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetGuiSliderResponse {
         pub active: bool,
+        ///Range: 0 - 128
         pub position_x: u8,
+        ///Range: 0 - 64
         pub position_y: u8,
+        ///Range: 8 - 128
         pub length: u8,
         pub direction: tinkerforge_base::byte_converter::ParsedOrRaw<
             crate::bindings::lcd_128_x_64::Direction,
             u8,
         >,
+        ///Range: 0 - 120
         pub value: u8,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for GetGuiSliderResponse {
@@ -6593,6 +7033,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiSliderValueCallbackConfigurationRequest {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -6620,6 +7061,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetGuiSliderValueCallbackConfigurationResponse {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -6647,7 +7089,9 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GuiSliderValueCallback {
+        ///Range: 0 - 5
         pub index: u8,
+        ///Range: 0 - 120
         pub value: u8,
     }
     impl tinkerforge_base::byte_converter::FromByteSlice for GuiSliderValueCallback {
@@ -6768,6 +7212,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiTabTextRequest {
+        ///Range: 0 - 9
         pub index: u8,
         pub text: [char; 5usize],
     }
@@ -6812,6 +7257,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiTabIconRequest {
+        ///Range: 0 - 9
         pub index: u8,
         pub icon: [bool; 168usize],
     }
@@ -6856,6 +7302,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiTabSelectedCallbackConfigurationRequest {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -6883,6 +7330,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetGuiTabSelectedCallbackConfigurationResponse {
+        ///Unit: 1/1000 s (Second)
         pub period: u32,
         pub value_has_to_change: bool,
     }
@@ -6949,11 +7397,16 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiGraphConfigurationRequest {
+        ///Range: 0 - 3
         pub index: u8,
         pub graph_type: crate::bindings::lcd_128_x_64::GraphType,
+        ///Range: 0 - 127
         pub position_x: u8,
+        ///Range: 0 - 63
         pub position_y: u8,
+        ///Range: 0 - 118
         pub width: u8,
+        ///Range: 0 - 63
         pub height: u8,
         pub text_x: [char; 4usize],
         pub text_y: [char; 4usize],
@@ -7006,9 +7459,13 @@ pub mod lcd_128_x_64 {
             crate::bindings::lcd_128_x_64::GraphType,
             u8,
         >,
+        ///Range: 0 - 127
         pub position_x: u8,
+        ///Range: 0 - 63
         pub position_y: u8,
+        ///Range: 0 - 118
         pub width: u8,
+        ///Range: 0 - 63
         pub height: u8,
         pub text_x: [char; 4usize],
         pub text_y: [char; 4usize],
@@ -7057,7 +7514,9 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiGraphDataLowLevelRequest {
+        ///Range: 0 - 3
         pub index: u8,
+        ///Range: 0 - 118
         pub data_length: u16,
         pub data_chunk_offset: u16,
         pub data_chunk_data: [u8; 59usize],
@@ -7093,6 +7552,7 @@ pub mod lcd_128_x_64 {
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct SetGuiGraphDataRequest<'d> {
+        ///Range: 0 - 3
         pub index: u8,
         pub data: &'d [u8],
     }
@@ -7143,12 +7603,15 @@ pub mod lcd_128_x_64 {
             i += self.request.index.write_to_slice(&mut target[i..i + 1usize]);
             i += self.length.write_to_slice(&mut target[i..i + 2usize]);
             i += self.offset.write_to_slice(&mut target[i..i + 2usize]);
-            i += self.data.write_to_slice(&mut target[i..]);
+            target[i..i + 59usize].fill(0);
+            self.data.write_to_slice(&mut target[i..i + 59usize]);
+            i += 59usize;
             i
         }
     }
     #[derive(Copy, Clone, PartialEq, Debug)]
     pub struct GetGuiGraphDataLowLevelResponse {
+        ///Range: 0 - 118
         pub data_length: u16,
         pub data_chunk_offset: u16,
         pub data_chunk_data: [u8; 59usize],
@@ -7179,6 +7642,19 @@ pub mod lcd_128_x_64 {
             63usize
         }
     }
+    impl tinkerforge_base::low_level_traits::LowLevelRead<u8, ()>
+    for crate::bindings::lcd_128_x_64::GetGuiGraphDataLowLevelResponse {
+        fn ll_message_length(&self) -> usize {
+            self.data_length as usize
+        }
+        fn ll_message_chunk_offset(&self) -> usize {
+            self.data_chunk_offset as usize
+        }
+        fn ll_message_chunk_data(&self) -> &[u8] {
+            &self.data_chunk_data
+        }
+        fn get_result(&self) {}
+    }
     #[derive(Copy, Clone, Eq, PartialEq, Debug)]
     pub enum TouchLedConfig {
         Off,
@@ -7399,6 +7875,7 @@ pub mod lcd_128_x_64 {
     pub struct GetIdentityResponse {
         pub uid: [char; 8usize],
         pub connected_uid: [char; 8usize],
+        ///Range: 'a' - 'h', 'z' - 'z'
         pub position: char,
         pub hardware_version_major: u8,
         pub hardware_version_minor: u8,
@@ -7482,24 +7959,44 @@ pub mod lcd_128_x_64 {
         pub fn uid(&self) -> tinkerforge_base::base58::Uid {
             self.device.uid()
         }
-        /**
-Schreibt Pixel in das angegebene Fenster.
+        /// The firmware version functions are checked against before sending, `None` if the check is disabled.
+        pub fn firmware_version(
+            &self,
+        ) -> Option<tinkerforge_base::ip_connection::Version> {
+            self.device.firmware_version()
+        }
+        /// Enables the firmware check with the version reported by an enumeration, `None` disables it again.
+        pub fn set_firmware_version(
+            &mut self,
+            firmware_version: Option<tinkerforge_base::ip_connection::Version>,
+        ) {
+            self.device.set_firmware_version(firmware_version)
+        }
+        /// Queries the firmware version of the device and enables the firmware check with it.
+        pub async fn load_firmware_version(
+            &mut self,
+        ) -> Result<
+            tinkerforge_base::ip_connection::Version,
+            tinkerforge_base::error::TinkerforgeError,
+        > {
+            self.device.load_firmware_version().await
+        }
+        /**Writes pixels to the specified window.
 
-Die Pixel werden zeilenweise von oben nach unten geschrieben
-und die Zeilen werden jeweils von links nach rechts geschrieben.
+The pixels are written into the window line by line top to bottom
+and each line is written from left to right.
 
-Wenn Automatic Draw aktiviert ist (Standard), dann werden die Pixel direkt auf
-den Display geschrieben. Nur Pixel die sich wirklich verändert haben werden
-auf dem Display aktualisiert.
+If automatic draw is enabled (default) the pixels are directly written to
+the screen. Only pixels that have actually changed are updated on the screen,
+the rest stays the same.
 
-Wenn Automatic Draw deaktiviert ist, dann werden die Pixel in einen internen
-Buffer geschrieben der dann durch einen Aufruf von :func:`Draw Buffered Frame`
-auf dem Display angezeigt werden kann. Dadurch kann Flicker vermieden werden,
-wenn ein komplexes Bild in mehreren Schritten aufgebaut wird.
+If automatic draw is disabled the pixels are written to an internal buffer and
+the buffer is transferred to the display only after `draw_buffered_frame`
+is called. This can be used to avoid flicker when drawing a complex frame in
+multiple steps.
 
-Automatic Draw kann über die :func:`Set Display Configuration` Funktion
-eingestellt werden.
-*/
+Automatic draw can be configured with the `set_display_configuration`
+function.*/
         pub async fn write_pixels(
             &mut self,
             request: WritePixelsRequest<'_>,
@@ -7514,21 +8011,19 @@ eingestellt werden.
             }
             Ok(())
         }
-        /**
-Liest Pixel aus dem angegebenen Fenster.
+        /**Reads pixels from the specified window.
 
-Die Pixel werden zeilenweise von oben nach unten
-und die Zeilen werden jeweils von links nach rechts gelesen.
+The pixels are read from the window line by line top to bottom
+and each line is read from left to right.
 
-Wenn Automatic Draw aktiviert ist (Standard), dann werden die Pixel direkt vom
-Display gelesen.
+If automatic draw is enabled (default) the pixels that are read are always the
+same that are shown on the display.
 
-Wenn Automatic Draw deaktiviert ist, dann werden die Pixel aus einen internen
-Buffer gelesen (siehe :func:`Draw Buffered Frame`).
+If automatic draw is disabled the pixels are read from the internal buffer
+(see `draw_buffered_frame`).
 
-Automatic Draw kann über die :func:`Set Display Configuration` Funktion
-eingestellt werden.
-*/
+Automatic draw can be configured with the `set_display_configuration`
+function.*/
         pub async fn read_pixels_low_level(
             &mut self,
             request: crate::bindings::lcd_128_x_64::ReadPixelsLowLevelRequest,
@@ -7548,20 +8043,17 @@ eingestellt werden.
                 ),
             )
         }
-        /**
-Löscht den kompletten aktuellen Inhalt des Displays.
+        /**Clears the complete content of the display.
 
-Wenn Automatic Draw aktiviert ist (Standard), dann werden die Pixel direkt
-gelöscht.
+If automatic draw is enabled (default) the pixels are directly cleared.
 
-Wenn Automatic Draw deaktiviert ist, dann werden die Pixel im internen
-Buffer gelöscht der dann durch einen Aufruf von :func:`Draw Buffered Frame`
-auf dem Display angezeigt werden kann. Dadurch kann Flicker vermieden werden,
-wenn ein komplexes Bild in mehreren Schritten aufgebaut wird.
+If automatic draw is disabled the the internal buffer is cleared and
+the buffer is transferred to the display only after `draw_buffered_frame`
+is called. This can be used to avoid flicker when drawing a complex frame in
+multiple steps.
 
-Automatic Draw kann über die :func:`Set Display Configuration` Funktion
-eingestellt werden.
-*/
+Automatic draw can be configured with the `set_display_configuration`
+function.*/
         pub async fn clear_display(
             &mut self,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
@@ -7571,15 +8063,12 @@ eingestellt werden.
                 .await?;
             Ok(())
         }
-        /**
-Setzt die Konfiguration des Displays.
+        /**Sets the configuration of the display.
 
-Wenn Automatic Draw aktiviert (*true*) ist dann wird das Display bei jedem
-Aufruf von :func:`Write Pixels` und :func:`Write Line` aktualisiert. Wenn
-Automatic Draw deaktiviert (*false*) ist, dann werden Änderungen in einen
-internen Buffer geschrieben, der dann bei bei einem Aufruf von
-:func:`Draw Buffered Frame` auf dem Display angezeigt wird.
-*/
+If automatic draw is set to *true*, the display is automatically updated with every
+call of `write_pixels` and `write_line`. If it is set to false, the
+changes are written into an internal buffer and only shown on the display after
+a call of `draw_buffered_frame`.*/
         pub async fn set_display_configuration(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetDisplayConfigurationRequest,
@@ -7594,9 +8083,7 @@ internen Buffer geschrieben, der dann bei bei einem Aufruf von
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Konfiguration zurück, wie von :func:`Set Display Configuration` gesetzt.
-*/
+        ///Returns the configuration as set by `set_display_configuration`.
         pub async fn get_display_configuration(
             &mut self,
         ) -> Result<
@@ -7611,31 +8098,29 @@ Gibt die Konfiguration zurück, wie von :func:`Set Display Configuration` gesetz
                 ),
             )
         }
-        /**
-Schreibt einen Text in die angegebene Zeile mit einer vorgegebenen Position.
+        /**Writes text to a specific line with a specific position.
 
-Beispiel: (1, 10, "Hallo") schreibt *Hallo* in die Mitte der zweiten Zeile
-des Displays.
+For example: (1, 10, "Hello") will write *Hello* in the middle of the
+second line of the display.
 
-Das Display nutzt einen speziellen 5x7 Pixel Zeichensatz. Der Zeichensatz
-kann mit Hilfe von Brick Viewer angezeigt werden.
+The display uses a special 5x7 pixel charset. You can view the characters
+of the charset in Brick Viewer.
 
-Wenn Automatic Draw aktiviert ist (Standard), dann wird der Text direkt auf
-den Display geschrieben. Nur Pixel die sich wirklich verändert haben werden
-auf dem Display aktualisiert.
+If automatic draw is enabled (default) the text is directly written to
+the screen. Only pixels that have actually changed are updated on the screen,
+the rest stays the same.
 
-Wenn Automatic Draw deaktiviert ist, dann wird der Text in einen internen
-Buffer geschrieben der dann durch einen Aufruf von :func:`Draw Buffered Frame`
-auf dem Display angezeigt werden kann. Dadurch kann Flicker vermieden werden,
-wenn ein komplexes Bild in mehreren Schritten aufgebaut wird.
+If automatic draw is disabled the text is written to an internal buffer and
+the buffer is transferred to the display only after `draw_buffered_frame`
+is called. This can be used to avoid flicker when drawing a complex frame in
+multiple steps.
 
-Automatic Draw kann über die :func:`Set Display Configuration` Funktion
-eingestellt werden.
+Automatic draw can be configured with the `set_display_configuration`
+function.
 
-Diese Funktion ist ein 1:1-Ersatz für die Funktion mit dem gleichen Namen
-im LCD 20x4 Bricklet. Mit der Funktion :func:`Draw Text` kann Text Pixelgenau
-und mit unterschiedlichen Font-Größen gezeichnet werden.
-*/
+This function is a 1:1 replacement for the function with the same name
+in the LCD 20x4 Bricklet. You can draw text at a specific pixel position
+and with different font sizes with the `draw_text` function.*/
         pub async fn write_line(
             &mut self,
             request: crate::bindings::lcd_128_x_64::WriteLineRequest,
@@ -7650,19 +8135,15 @@ und mit unterschiedlichen Font-Größen gezeichnet werden.
                 .await?;
             Ok(())
         }
-        /**
-Stellt den aktuell Inhalt des internen Buffers auf dem Display dar. Normalerweise
-schreibt jeder Aufruf von :func:`Write Pixels` und :func:`Write Line` direkt auf
-den Display. Wenn jedoch Automatic Draw deaktiviert ist (:func:`Set Display Configuration`),
-dann werden Änderungen in einen internen Buffer anstatt auf den
-Display geschrieben. Der internen Buffer kann dann durch einen Aufruf dieser
-Funktion auf den Display geschrieben werden. Dadurch kann Flicker vermieden werden,
-wenn ein komplexes Bild in mehreren Schritten aufgebaut wird.
-
-Wenn `Force Complete Redraw` auf *true* gesetzt ist, dann wird der gesamte Display
-aktualisiert, anstatt nur die Pixel die sich wirklich verändert haben. Normalerweise
-sollte dies nicht notwendig sein, außer bei hängenden Pixeln bedingt durch Fehler.
-*/
+        /**Draws the currently buffered frame. Normally each call of `write_pixels` and
+`write_line` draws directly onto the display. If you turn automatic draw off
+(`set_display_configuration`), the data is written in an internal buffer and
+only transferred to the display by calling this function. This can be used to
+avoid flicker when drawing a complex frame in multiple steps.
+
+Set the `force complete redraw` to *true* to redraw the whole display
+instead of only the changed parts. Normally it should not be necessary to set this to
+*true*. It may only become necessary in case of stuck pixels because of errors.*/
         pub async fn draw_buffered_frame(
             &mut self,
             request: bool,
@@ -7677,14 +8158,12 @@ sollte dies nicht notwendig sein, außer bei hängenden Pixeln bedingt durch Feh
                 .await?;
             Ok(())
         }
-        /**
-Gibt die letzte gültige Touch-Position zurück:
+        /**Returns the last valid touch position:
 
-* Pressure: Anpressdruck des Touches
-* X: Touch-Position auf der X-Achse
-* Y: Touch-Position auf der Y-Achse
-* Age: Alter des Touches (wie lange ist die Erkennung des Touches her)
-*/
+* Pressure: Amount of pressure applied by the user
+* X: Touch position on x-axis
+* Y: Touch position on y-axis
+* Age: Age of touch press (how long ago it was)*/
         pub async fn get_touch_position(
             &mut self,
         ) -> Result<
@@ -7699,18 +8178,15 @@ Gibt die letzte gültige Touch-Position zurück:
                 ),
             )
         }
-        /**
-Die Periode ist die Periode mit der der :cb:`Touch Position` Callback
-ausgelöst wird. Ein Wert von 0 schaltet den Callback ab.
+        /**The period is the period with which the `touch_position_stream` callback
+is triggered periodically. A value of 0 turns the callback off.
 
-Wenn der `value has to change`-Parameter auf True gesetzt wird, wird der
-Callback nur ausgelöst, wenn der Wert sich im Vergleich zum letzten mal geändert
-hat. Ändert der Wert sich nicht innerhalb der Periode, so wird der Callback
-sofort ausgelöst, wenn der Wert sich das nächste mal ändert.
+If the `value has to change`-parameter is set to true, the callback is only
+triggered after the value has changed. If the value didn't change within the
+period, the callback is triggered immediately on change.
 
-Wird der Parameter auf False gesetzt, so wird der Callback dauerhaft mit der
-festen Periode ausgelöst unabhängig von den Änderungen des Werts.
-*/
+If it is set to false, the callback is continuously triggered with the period,
+independent of the value.*/
         pub async fn set_touch_position_callback_configuration(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetTouchPositionCallbackConfigurationRequest,
@@ -7725,10 +8201,8 @@ festen Periode ausgelöst unabhängig von den Änderungen des Werts.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Callback-Konfiguration zurück, wie mittels
-:func:`Set Touch Position Callback Configuration` gesetzt.
-*/
+        /**Returns the callback configuration as set by
+`set_touch_position_callback_configuration`.*/
         pub async fn get_touch_position_callback_configuration(
             &mut self,
         ) -> Result<
@@ -7743,11 +8217,9 @@ Gibt die Callback-Konfiguration zurück, wie mittels
                 ),
             )
         }
-        /**
-Dieser Callback wird mit der Periode, wie gesetzt mit
-:func:`Set Touch Position Callback Configuration`, ausgelöst. Die :word:`parameters` sind
-die gleichen wie die von :func:`Get Touch Position`.
-*/
+        /**This callback is triggered periodically with the period that is set by
+`set_touch_position_callback_configuration`. The parameters are the
+same as for `get_touch_position`.*/
         pub async fn touch_position_stream(
             &mut self,
         ) -> impl futures_core::Stream<
@@ -7758,20 +8230,15 @@ die gleichen wie die von :func:`Get Touch Position`.
                 .await
                 .map(|p| TouchPositionCallback::from_le_byte_slice(p.body()))
         }
-        /**
-Gibt eine der vier Touch-Gesten zurück, die das Bricklet automatisch erkennen kann.
+        /**Returns one of four touch gestures that can be automatically detected by the Bricklet.
 
-Die Gesten umfassen Wischen von links nach rechts, rechts nach links, oben nach
-unten und unten nach oben.
+The gestures are swipes from left to right, right to left, top to bottom and bottom to top.
 
-Zusätzlich zu Geste wird der Vektor von Start- nach Endposition des Wischens
-angegeben. Dieser kann genutzt werden um die genaue Position der Geste zu
-ermitteln (z.B. ob ein Wischen von oben nach unten auf der linken oder rechten
-des Bildschirms erkannt wurde).
+Additionally to the gestures a vector with a start and end position of the gesture is
+provided. You can use this vector do determine a more exact location of the gesture (e.g.
+the swipe from top to bottom was on the left or right part of the screen).
 
-Der Age Parameter gibt das Alter der Geste an (wie lange ist die Erkennung
-der Geste her).
-*/
+The age parameter corresponds to the age of gesture (how long ago it was).*/
         pub async fn get_touch_gesture(
             &mut self,
         ) -> Result<
@@ -7786,18 +8253,15 @@ der Geste her).
                 ),
             )
         }
-        /**
-Die Periode ist die Periode mit der der :cb:`Touch Gesture` Callback
-ausgelöst wird. Ein Wert von 0 schaltet den Callback ab.
+        /**The period is the period with which the `touch_gesture_stream` callback
+is triggered periodically. A value of 0 turns the callback off.
 
-Wenn der `value has to change`-Parameter auf True gesetzt wird, wird der
-Callback nur ausgelöst, wenn der Wert sich im Vergleich zum letzten mal geändert
-hat. Ändert der Wert sich nicht innerhalb der Periode, so wird der Callback
-sofort ausgelöst, wenn der Wert sich das nächste mal ändert.
+If the `value has to change`-parameter is set to true, the callback is only
+triggered after the value has changed. If the value didn't change within the
+period, the callback is triggered immediately on change.
 
-Wird der Parameter auf False gesetzt, so wird der Callback dauerhaft mit der
-festen Periode ausgelöst unabhängig von den Änderungen des Werts.
-*/
+If it is set to false, the callback is continuously triggered with the period,
+independent of the value.*/
         pub async fn set_touch_gesture_callback_configuration(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetTouchGestureCallbackConfigurationRequest,
@@ -7812,10 +8276,8 @@ festen Periode ausgelöst unabhängig von den Änderungen des Werts.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Callback-Konfiguration zurück, wie mittels
-:func:`Set Touch Gesture Callback Configuration` gesetzt.
-*/
+        /**Returns the callback configuration as set by
+`set_touch_gesture_callback_configuration`.*/
         pub async fn get_touch_gesture_callback_configuration(
             &mut self,
         ) -> Result<
@@ -7830,11 +8292,9 @@ Gibt die Callback-Konfiguration zurück, wie mittels
                 ),
             )
         }
-        /**
-Dieser Callback wird mit der Periode, wie gesetzt mit
-:func:`Set Touch Gesture Callback Configuration`, ausgelöst. Die :word:`parameters` sind
-die gleichen wie die von :func:`Get Touch Gesture`.
-*/
+        /**This callback is triggered periodically with the period that is set by
+`set_touch_gesture_callback_configuration`. The parameters are the
+same as for `get_touch_gesture`.*/
         pub async fn touch_gesture_stream(
             &mut self,
         ) -> impl futures_core::Stream<
@@ -7845,14 +8305,16 @@ die gleichen wie die von :func:`Get Touch Gesture`.
                 .await
                 .map(|p| TouchGestureCallback::from_le_byte_slice(p.body()))
         }
-        /**
-Zeichnet eine weiße oder schwarze Linie von (x, y)-start nach
-(x, y)-end.
-*/
+        ///Draws a white or black line from (x, y)-start to (x, y)-end.
         pub async fn draw_line(
             &mut self,
             request: crate::bindings::lcd_128_x_64::DrawLineRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    16u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 5usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -7863,18 +8325,19 @@ Zeichnet eine weiße oder schwarze Linie von (x, y)-start nach
                 .await?;
             Ok(())
         }
-        /**
-Zeichnet ein weißes oder schwarzes Rechteck von (x, y)-start nach
-(x, y)-end.
+        /**Draws a white or black box from (x, y)-start to (x, y)-end.
 
-Wenn fill auf true gesetzt wird, wird das Rechteck mit
-der angegebenen Farbe ausgefüllt. Ansonsten wird nur der Umriss
-gezeichnet.
-*/
+If you set fill to true, the box will be filled with the
+color. Otherwise only the outline will be drawn.*/
         pub async fn draw_box(
             &mut self,
             request: crate::bindings::lcd_128_x_64::DrawBoxRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    17u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 6usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -7885,18 +8348,20 @@ gezeichnet.
                 .await?;
             Ok(())
         }
-        /**
-Zeichnet einen Text an die Pixelposition (x, y).
+        /**Draws a text at the pixel position (x, y).
 
-Es können 9 unterschiedliche Font-Größen genutzt werden und der Text
-kann in weiß oder schwarz gezeichnet werden.
+You can use one of 9 different font sizes and draw the text in white or black.
 
-Der der Zeichensatz entspricht Codepage 437.
-*/
+The font conforms to code page 437.*/
         pub async fn draw_text(
             &mut self,
             request: crate::bindings::lcd_128_x_64::DrawTextRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    18u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 26usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -7907,32 +8372,34 @@ Der der Zeichensatz entspricht Codepage 437.
                 .await?;
             Ok(())
         }
-        /**
-Zeichnet einen klickbaren Button an Position (x, y) mit dem gegebenem Text.
+        /**Draws a clickable button at position (x, y) with the given text.
 
-Es können bis zu 12 Buttons genutzt werden.
+You can use up to 12 buttons.
 
-Die x-Position + Width muss im Wertebereich von 1 bis 128 liegen und die
-y-Position+Height muss im Wertebereich von 1 bis 64 liegen.
+The x position + width has to be within the range of 1 to 128 and the y
+position + height has to be within the range of 1 to 64.
 
-Die minimale nützliche Breite/Höhe eines Buttons ist 3.
+The minimum useful width/height of a button is 3.
 
-Der Callback für Button-Events kann mit der Funktion
-:func:`Set GUI Button Pressed Callback Configuration` eingestellt werden.
-Der Callback wird sowohl für gedrückt als auch losgelassen Events ausgelöst.
+You can enable a callback for a button press with
+`set_gui_button_pressed_callback_configuration`. The callback will
+be triggered for press and release-events.
 
-Der Button wird in einem separaten GUI-Buffer gezeichnet und der Rahmen des
-Buttons wird immer über den Grafiken bleiben die mit :func:`Write Pixels`
-gezeichnet werden. Um einen Button zu entfernen kann die Funktion
-:func:`Remove GUI Button` genutzt werden.
+The button is drawn in a separate GUI buffer and the button-frame will
+always stay on top of the graphics drawn with `write_pixels`. To
+remove the button use `remove_gui_button`.
 
-Wenn anstatt des Textes ein Icon verwendet werden soll, kann dieses innerhalb
-des Buttons mit per :func:`Write Pixels` gezeichnet werden.
-*/
+If you want an icon instead of text, you can draw the icon inside of the
+button with `write_pixels`.*/
         pub async fn set_gui_button(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetGuiButtonRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    19u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 21usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -7943,13 +8410,10 @@ des Buttons mit per :func:`Write Pixels` gezeichnet werden.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Button-Eigenschaften für den gegebenen `Index` zurück, wie von
-:func:`Set GUI Button` gesetzt.
+        /**Returns the button properties for a given `Index` as set by `set_gui_button`.
 
-Zusätzlich gibt der `Active`-Parameter an ob der Button aktuell aktiv/sichtbar ist
-oder nicht.
-*/
+Additionally the `Active` parameter shows if a button is currently active/visible
+or not.*/
         pub async fn get_gui_button(
             &mut self,
             request: u8,
@@ -7957,6 +8421,11 @@ oder nicht.
             crate::bindings::lcd_128_x_64::GetGuiButtonResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    20u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -7969,15 +8438,18 @@ oder nicht.
                 ),
             )
         }
-        /**
-Entfernt den Button mit dem gegebenen Index.
+        /**Removes the button with the given index.
 
-Index 255 kann genutzt werden um alle Buttons zu entfernen.
-*/
+You can use index 255 to remove all buttons.*/
         pub async fn remove_gui_button(
             &mut self,
             request: u8,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    21u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -7988,22 +8460,24 @@ Index 255 kann genutzt werden um alle Buttons zu entfernen.
                 .await?;
             Ok(())
         }
-        /**
-Die Periode ist die Periode mit der der :cb:`GUI Button Pressed` Callback
-ausgelöst wird. Ein Wert von 0 schaltet den Callback ab.
+        /**The period is the period with which the `gui_button_pressed_stream` callback
+is triggered periodically. A value of 0 turns the callback off.
 
-Wenn der `value has to change`-Parameter auf True gesetzt wird, wird der
-Callback nur ausgelöst, wenn der Wert sich im Vergleich zum letzten mal geändert
-hat. Ändert der Wert sich nicht innerhalb der Periode, so wird der Callback
-sofort ausgelöst, wenn der Wert sich das nächste mal ändert.
+If the `value has to change`-parameter is set to true, the callback is only
+triggered after the value has changed. If the value didn't change within the
+period, the callback is triggered immediately on change.
 
-Wird der Parameter auf False gesetzt, so wird der Callback dauerhaft mit der
-festen Periode ausgelöst unabhängig von den Änderungen des Werts.
-*/
+If it is set to false, the callback is continuously triggered with the period,
+independent of the value.*/
         pub async fn set_gui_button_pressed_callback_configuration(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetGuiButtonPressedCallbackConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    22u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 5usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8014,16 +8488,19 @@ festen Periode ausgelöst unabhängig von den Änderungen des Werts.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Callback-Konfiguration zurück, wie mittels
-:func:`Set GUI Button Pressed Callback Configuration` gesetzt.
-*/
+        /**Returns the callback configuration as set by
+`set_gui_button_pressed_callback_configuration`.*/
         pub async fn get_gui_button_pressed_callback_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::lcd_128_x_64::GetGuiButtonPressedCallbackConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    23u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(23u8, &payload).await?;
             Ok(
@@ -8032,15 +8509,18 @@ Gibt die Callback-Konfiguration zurück, wie mittels
                 ),
             )
         }
-        /**
-Gibt den aktuellen Button-Zustand für einen gegebenen Index zurück.
+        /**Returns the state of the button for the given index.
 
-Der Zustand kann entweder gedrückt (true) oder losgelassen (false) sein.
-*/
+The state can either be pressed (true) or released (false).*/
         pub async fn get_gui_button_pressed(
             &mut self,
             request: u8,
         ) -> Result<bool, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    24u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8049,11 +8529,9 @@ Der Zustand kann entweder gedrückt (true) oder losgelassen (false) sein.
             let result = self.device.get(24u8, &payload).await?;
             Ok(bool::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Dieser Callback wird mit der Periode, wie gesetzt mit
-:func:`Set GUI Button Pressed Callback Configuration`, ausgelöst. Die :word:`parameters` sind
-die gleichen wie die von :func:`Get GUI Button Pressed`.
-*/
+        /**This callback is triggered periodically with the period that is set by
+`set_gui_button_pressed_callback_configuration`. The parameters are the
+same as for `get_gui_button_pressed`.*/
         pub async fn gui_button_pressed_stream(
             &mut self,
         ) -> impl futures_core::Stream<
@@ -8064,36 +8542,38 @@ die gleichen wie die von :func:`Get GUI Button Pressed`.
                 .await
                 .map(|p| GuiButtonPressedCallback::from_le_byte_slice(p.body()))
         }
-        /**
-Zeichnet einen Slider an Position (x, y) mit der gegebenen Länge.
+        /**Draws a slider at position (x, y) with the given length.
 
-Es können bis zu 6 Slider genutzt werden.
+You can use up to 6 sliders.
 
-Wenn eine horizontale Richtung verwendet wird muss Die x-Position + Länge
-im Wertebereich von 1 bis 128 und die y-Position im Wertebereich von
-0 bis 46 liegen.
+If you use the horizontal direction, the x position + length has to be
+within the range of 1 to 128 and the y position has to be within
+the range of 0 to 46.
 
-Wenn eine vertikale Richtung verwendet wird muss Die y-Position + Länge
-im Wertebereich von 1 bis 64 und die x-Position im Wertebereich von
-0 bis 110 liegen.
+If you use the vertical direction, the y position + length has to be
+within the range of 1 to 64 and the x position has to be within
+the range of 0 to 110.
 
-Die minimale Länge des Sliders ist 8.
+The minimum length of a slider is 8.
 
-Der :word:`parameter` value ist die Startposition des Sliders. Diese kann
-zwischen 0 und length-8 liegen.
+The parameter value is the start-position of the slider, it can
+be between 0 and length-8.
 
-Der Callback für Slider-Events kann mit der Funktion
-:func:`Set GUI Slider Value Callback Configuration` eingestellt werden.
+You can enable a callback for the slider value with
+`set_gui_slider_value_callback_configuration`.
 
-Der Slider wird in einem separaten GUI-Buffer gezeichnet und der Rahmen des
-Buttons wrd immer über den Grafiken bleiben die mit :func:`Write Pixels`
-gezeichnet werden. Um einen Button zu entfernen kann die Funktion
-:func:`Remove GUI Slider` genutzt werden.
-*/
+The slider is drawn in a separate GUI buffer and it will
+always stay on top of the graphics drawn with `write_pixels`. To
+remove the button use `remove_gui_slider`.*/
         pub async fn set_gui_slider(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetGuiSliderRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    26u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 6usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8104,13 +8584,10 @@ gezeichnet werden. Um einen Button zu entfernen kann die Funktion
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Slider-Eigenschaften für den gegebenen `Index` zurück, wie von
-:func:`Set GUI Slider` gesetzt.
+        /**Returns the slider properties for a given `Index` as set by `set_gui_slider`.
 
-Zusätzlich gibt der `Active`-Parameter an ob der Button aktuell aktiv/sichtbar ist
-oder nicht.
-*/
+Additionally the `Active` parameter shows if a button is currently active/visible
+or not.*/
         pub async fn get_gui_slider(
             &mut self,
             request: u8,
@@ -8118,6 +8595,11 @@ oder nicht.
             crate::bindings::lcd_128_x_64::GetGuiSliderResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    27u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8130,15 +8612,18 @@ oder nicht.
                 ),
             )
         }
-        /**
-Entfernt den Slider mit dem gegebenen Index.
+        /**Removes the slider with the given index.
 
-Index 255 kann genutzt werden um alle Slider zu entfernen.
-*/
+You can use index 255 to remove all slider.*/
         pub async fn remove_gui_slider(
             &mut self,
             request: u8,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    28u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8149,22 +8634,24 @@ Index 255 kann genutzt werden um alle Slider zu entfernen.
                 .await?;
             Ok(())
         }
-        /**
-Die Periode ist die Periode mit der der :cb:`GUI Slider Value` Callback
-ausgelöst wird. Ein Wert von 0 schaltet den Callback ab.
+        /**The period is the period with which the `gui_slider_value_stream` callback
+is triggered periodically. A value of 0 turns the callback off.
 
-Wenn der `value has to change`-Parameter auf True gesetzt wird, wird der
-Callback nur ausgelöst, wenn der Wert sich im Vergleich zum letzten mal geändert
-hat. Ändert der Wert sich nicht innerhalb der Periode, so wird der Callback
-sofort ausgelöst, wenn der Wert sich das nächste mal ändert.
+If the `value has to change`-parameter is set to true, the callback is only
+triggered after the value has changed. If the value didn't change within the
+period, the callback is triggered immediately on change.
 
-Wird der Parameter auf False gesetzt, so wird der Callback dauerhaft mit der
-festen Periode ausgelöst unabhängig von den Änderungen des Werts.
-*/
+If it is set to false, the callback is continuously triggered with the period,
+independent of the value.*/
         pub async fn set_gui_slider_value_callback_configuration(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetGuiSliderValueCallbackConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    29u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 5usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8175,16 +8662,19 @@ festen Periode ausgelöst unabhängig von den Änderungen des Werts.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Callback-Konfiguration zurück, wie mittels
-:func:`Set GUI Slider Value Callback Configuration` gesetzt.
-*/
+        /**Returns the callback configuration as set by
+`set_gui_slider_value_callback_configuration`.*/
         pub async fn get_gui_slider_value_callback_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::lcd_128_x_64::GetGuiSliderValueCallbackConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    30u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(30u8, &payload).await?;
             Ok(
@@ -8193,13 +8683,16 @@ Gibt die Callback-Konfiguration zurück, wie mittels
                 ),
             )
         }
-        /**
-Gibt den aktuellen Wert des Slider mit dem gegebenen Index zurück.
-*/
+        ///Returns the current slider value for the given index.
         pub async fn get_gui_slider_value(
             &mut self,
             request: u8,
         ) -> Result<u8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    31u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8208,11 +8701,9 @@ Gibt den aktuellen Wert des Slider mit dem gegebenen Index zurück.
             let result = self.device.get(31u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Dieser Callback wird mit der Periode, wie gesetzt mit
-:func:`Set GUI Slider Value Callback Configuration`, ausgelöst. Die :word:`parameters` sind
-die gleichen wie die von :func:`Get GUI Slider Value`.
-*/
+        /**This callback is triggered periodically with the period that is set by
+`set_gui_slider_value_callback_configuration`. The parameters are the
+same as for `get_gui_slider_value`.*/
         pub async fn gui_slider_value_stream(
             &mut self,
         ) -> impl futures_core::Stream<
@@ -8223,18 +8714,20 @@ die gleichen wie die von :func:`Get GUI Slider Value`.
                 .await
                 .map(|p| GuiSliderValueCallback::from_le_byte_slice(p.body()))
         }
-        /**
-Setzt die generelle Konfiguration für Tabs. Tabs können auf klicken, wischen
-(links/rechts und rechts/links) oder beides reagieren.
+        /**Sets the general configuration for tabs. You can configure the tabs to only
+accept clicks or only swipes (gesture left/right and right/left) or both.
 
-Zusätzlich kann `Clear GUI` auf true gesetzt werden. In diesem Fall werden
-bei einem wechsel der Tabs automatisch alle GUI Elemente (Buttons, Slider,
-Graphen) gelöscht.
-*/
+Additionally, if you set `Clear GUI` to true, all of the GUI elements (buttons,
+slider, graphs) will automatically be removed on every tab change.*/
         pub async fn set_gui_tab_configuration(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetGuiTabConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    33u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 2usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8245,15 +8738,18 @@ Graphen) gelöscht.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Tab-Konfiguration zurück, wie von :func:`Set GUI Tab Configuration` gesetzt.
-*/
+        ///Returns the tab configuration as set by `set_gui_tab_configuration`.
         pub async fn get_gui_tab_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::lcd_128_x_64::GetGuiTabConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    34u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(34u8, &payload).await?;
             Ok(
@@ -8262,17 +8758,20 @@ Gibt die Tab-Konfiguration zurück, wie von :func:`Set GUI Tab Configuration` ge
                 ),
             )
         }
-        /**
-Fügt einen Text-Tab mit dem gegebenen Index hinzu.
+        /**Adds a text-tab with the given index.
 
-Es können bis zu 10 Tabs verwendet werden.
+You can use up to 10 tabs.
 
-Ein Text-Tab mit dem gleichen Index wie ein Icon-Tab überschreibt diesen.
-*/
+A text-tab with the same index as a icon-tab will overwrite the icon-tab.*/
         pub async fn set_gui_tab_text(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetGuiTabTextRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    35u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 6usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8283,13 +8782,10 @@ Ein Text-Tab mit dem gleichen Index wie ein Icon-Tab überschreibt diesen.
                 .await?;
             Ok(())
         }
-        /**
-Gibt den Text für den gegebenen Index zurück, wie von :func:`Set GUI Tab Text`
-gesetzt.
+        /**Returns the text for a given index as set by `set_gui_tab_text`.
 
-Zusätzlich gibt der `Active`-Parameter an ob der Tab aktuell aktiv/sichtbar ist
-oder nicht.
-*/
+Additionally the `Active` parameter shows if the tab is currently active/visible
+or not.*/
         pub async fn get_gui_tab_text(
             &mut self,
             request: u8,
@@ -8297,6 +8793,11 @@ oder nicht.
             crate::bindings::lcd_128_x_64::GetGuiTabTextResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    36u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8309,19 +8810,21 @@ oder nicht.
                 ),
             )
         }
-        /**
-Fügt einen Icon-Tab mit dem gegebenen Index hinzu. Das Icon kann eine Breite von
-28 Pixel bei einer Höhe von 6 Pixel haben. Es wird Zeile für Zeile von links
-nach rechts gezeichnet.
+        /**Adds a icon-tab with the given index. The icon can have a width of 28 pixels
+with a height of 6 pixels. It is drawn line-by-line from left to right.
 
-Es können bis zu 10 Tabs verwendet werden.
+You can use up to 10 tabs.
 
-Ein Icon-Tab mit dem gleichen Index wie ein Text-Tab überschreibt diesen.
-*/
+A icon-tab with the same index as a text-tab will overwrite the text-tab.*/
         pub async fn set_gui_tab_icon(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetGuiTabIconRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    37u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 22usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8332,13 +8835,10 @@ Ein Icon-Tab mit dem gleichen Index wie ein Text-Tab überschreibt diesen.
                 .await?;
             Ok(())
         }
-        /**
-Gibt das Icon für den gegebenen Index zurück, wie von :func:`Set GUI Tab Icon`
-gesetzt.
+        /**Returns the icon for a given index as set by `set_gui_tab_icon`.
 
-Zusätzlich gibt der `Active`-Parameter an ob der Tab aktuell aktiv/sichtbar ist
-oder nicht.
-*/
+Additionally the `Active` parameter shows if the tab is currently active/visible
+or not.*/
         pub async fn get_gui_tab_icon(
             &mut self,
             request: u8,
@@ -8346,6 +8846,11 @@ oder nicht.
             crate::bindings::lcd_128_x_64::GetGuiTabIconResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    38u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8358,15 +8863,18 @@ oder nicht.
                 ),
             )
         }
-        /**
-Entfernt den Tab mit dem gegebenen Index.
+        /**Removes the tab with the given index.
 
-Index 255 kann genutzt werden um alle Tabs zu entfernen.
-*/
+You can use index 255 to remove all tabs.*/
         pub async fn remove_gui_tab(
             &mut self,
             request: u8,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    39u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8377,14 +8885,16 @@ Index 255 kann genutzt werden um alle Tabs zu entfernen.
                 .await?;
             Ok(())
         }
-        /**
-Setzt den Tab mit dem gegebenen Index als "selected" (wird auf dem Display als
-ausgewählt gezeichnet)
-*/
+        ///Sets the tab with the given index as selected (drawn as selected on the display).
         pub async fn set_gui_tab_selected(
             &mut self,
             request: u8,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    40u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8395,22 +8905,24 @@ ausgewählt gezeichnet)
                 .await?;
             Ok(())
         }
-        /**
-Die Periode ist die Periode mit der der :cb:`GUI Tab Selected` Callback
-ausgelöst wird. Ein Wert von 0 schaltet den Callback ab.
+        /**The period is the period with which the `gui_tab_selected_stream` callback
+is triggered periodically. A value of 0 turns the callback off.
 
-Wenn der `value has to change`-Parameter auf True gesetzt wird, wird der
-Callback nur ausgelöst, wenn der Wert sich im Vergleich zum letzten mal geändert
-hat. Ändert der Wert sich nicht innerhalb der Periode, so wird der Callback
-sofort ausgelöst, wenn der Wert sich das nächste mal ändert.
+If the `value has to change`-parameter is set to true, the callback is only
+triggered after the value has changed. If the value didn't change within the
+period, the callback is triggered immediately on change.
 
-Wird der Parameter auf False gesetzt, so wird der Callback dauerhaft mit der
-festen Periode ausgelöst unabhängig von den Änderungen des Werts.
-*/
+If it is set to false, the callback is continuously triggered with the period,
+independent of the value.*/
         pub async fn set_gui_tab_selected_callback_configuration(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetGuiTabSelectedCallbackConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    41u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 5usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8421,16 +8933,19 @@ festen Periode ausgelöst unabhängig von den Änderungen des Werts.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Callback-Konfiguration zurück, wie mittels
-:func:`Set GUI Tab Selected Callback Configuration` gesetzt.
-*/
+        /**Returns the callback configuration as set by
+`set_gui_tab_selected_callback_configuration`.*/
         pub async fn get_gui_tab_selected_callback_configuration(
             &mut self,
         ) -> Result<
             crate::bindings::lcd_128_x_64::GetGuiTabSelectedCallbackConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    42u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(42u8, &payload).await?;
             Ok(
@@ -8439,22 +8954,23 @@ Gibt die Callback-Konfiguration zurück, wie mittels
                 ),
             )
         }
-        /**
-Gibt den Index des aktuell ausgewählten Tabs zurück.
-Wenn es keine Tabs gibt, wird -1 als Index zurückgegeben.
-*/
+        /**Returns the index of the currently selected tab.
+If there are not tabs, the returned index is -1.*/
         pub async fn get_gui_tab_selected(
             &mut self,
         ) -> Result<i8, tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    43u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(43u8, &payload).await?;
             Ok(i8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Dieser Callback wird mit der Periode, wie gesetzt mit
-:func:`Set GUI Tab Selected Callback Configuration`, ausgelöst. Die :word:`parameters` sind
-die gleichen wie die von :func:`Get GUI Tab Selected`.
-*/
+        /**This callback is triggered periodically with the period that is set by
+`set_gui_tab_selected_callback_configuration`. The parameters are the
+same as for `get_gui_tab_selected`.*/
         pub async fn gui_tab_selected_stream(
             &mut self,
         ) -> impl futures_core::Stream<Item = i8> {
@@ -8463,31 +8979,32 @@ die gleichen wie die von :func:`Get GUI Tab Selected`.
                 .await
                 .map(|p| i8::from_le_byte_slice(&p.body()[0..1usize]))
         }
-        /**
-Setzt die Konfiguration für bis zu vier Graphen.
+        /**Sets the configuration for up to four graphs.
 
-Der Graph kann vom Typ Dot-, Line- oder Bar-Graph sein.
+The graph type can be dot-, line- or bar-graph.
 
-Die x- und y-Positionen sind Pixel-Positionen.
+The x and y position are pixel positions.
 
-Es können bis zu 4 Buchstaben Text zur Beschreibung der x- und y-Achse
-genutzt werden. Der Text wird auf die Innenseite des Graphen gezeichnet und
-er kann Datenpunkte des Graphen überschreiben. Wenn der Text außerhalb des
-Graphen benötigt wird kann die Beschriftung hier leer gelassen werden. Der
-Text kann im Nachhinein mit :func:`Draw Text` hinzugefügt werden.
+You can add a text for the x and y axis.
+The text is drawn at the inside of the graph and it can overwrite some
+of the graph data. If you need the text outside of the graph you can
+leave this text here empty and use `draw_text` to draw the caption
+outside of the graph.
 
-Die Datenpunkte des Graphen können mit der Funktion :func:`Set GUI Graph Data`
-gesetzt und aktualisiert werden.
+The data of the graph can be set and updated with `set_gui_graph_data`.
 
-Der Graph wird in einem separaten GUI-Buffer gezeichnet und der Rahmen sowie die
-Datenpunkte des Graphen werden immer über den Grafiken bleiben die mit
-:func:`Write Pixels` gezeichnet werden. Um einen Graphen zu entfernen kann die
-Funktion :func:`Remove GUI Graph` genutzt werden.
-*/
+The graph is drawn in a separate GUI buffer and the graph-frame and data will
+always stay on top of the graphics drawn with `write_pixels`. To
+remove the graph use `remove_gui_graph`.*/
         pub async fn set_gui_graph_configuration(
             &mut self,
             request: crate::bindings::lcd_128_x_64::SetGuiGraphConfigurationRequest,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    45u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 14usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8498,13 +9015,10 @@ Funktion :func:`Remove GUI Graph` genutzt werden.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Graph-Eigenschaften für den gegebenen `Index` zurück, wie von
-:func:`Set GUI Graph Configuration` gesetzt.
+        /**Returns the graph properties for a given `Index` as set by `set_gui_graph_configuration`.
 
-Zusätzlich gibt der `Active`-Parameter an ob der Button aktuell aktiv/sichtbar ist
-oder nicht.
-*/
+Additionally the `Active` parameter shows if a graph is currently active/visible
+or not.*/
         pub async fn get_gui_graph_configuration(
             &mut self,
             request: u8,
@@ -8512,6 +9026,11 @@ oder nicht.
             crate::bindings::lcd_128_x_64::GetGuiGraphConfigurationResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    46u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8524,25 +9043,27 @@ oder nicht.
                 ),
             )
         }
-        /**
-Setzt die Datenpukte für den Graph mit dem gegebenen Index. Der Graph muss mit
-:func:`Set GUI Graph Configuration` konfiguriert werden bevor die ersten Daten
-gesetzt werden können.
+        /**Sets the data for a graph with the given index. You have to configure the graph with
+`set_gui_graph_configuration` before you can set the first data.
 
-Der Graph zeigt die ersten n Werte der gesetzten Daten an, wobei n die Breite (width)
-ist die mit :func:`Set GUI Graph Configuration` gesetzt wurde. Wenn weniger als
-n Werte gesetzt werden, werden die restlichen Datenpunkte als 0 angezeigt.
+The graph will show the first n values of the data that you set, where
+n is the width set with `set_gui_graph_configuration`. If you set
+less then n values it will show the rest of the values as zero.
 
-Die maximale Anzahl an Datenpunkte die gesetzt werden kann ist 118 (dies entspricht
-auch der maximalen Breite des Graphen).
+The maximum number of data-points you can set is 118 (which also corresponds to the
+maximum width of the graph).
 
-Die gesetzten Werte müssen zwischen 0 und 255 skaliert werden. 0 wird unten und
-255 wird oben im Graph gezeichnet.
-*/
+You have to scale your values to be between 0 and 255. 0 will be shown
+at the bottom of the graph and 255 at the top.*/
         pub async fn set_gui_graph_data(
             &mut self,
             request: SetGuiGraphDataRequest<'_>,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    47u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut buffer = [0; 64];
             for slice in request.write_to_slices() {
                 let length = slice.write_to_slice(&mut buffer);
@@ -8553,10 +9074,7 @@ Die gesetzten Werte müssen zwischen 0 und 255 skaliert werden. 0 wird unten und
             }
             Ok(())
         }
-        /**
-Gibt die Datenpunkte des Graphen mit dem gegebenen Index zurück, wie von
-:func:`Set GUI Graph Data` gesetzt.
-*/
+        ///Returns the graph data for a given index as set by `set_gui_graph_data`.
         pub async fn get_gui_graph_data_low_level(
             &mut self,
             request: u8,
@@ -8564,6 +9082,11 @@ Gibt die Datenpunkte des Graphen mit dem gegebenen Index zurück, wie von
             crate::bindings::lcd_128_x_64::GetGuiGraphDataLowLevelResponse,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    48u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8576,15 +9099,18 @@ Gibt die Datenpunkte des Graphen mit dem gegebenen Index zurück, wie von
                 ),
             )
         }
-        /**
-Entfernt den Graph mit dem gegebenen Index.
+        /**Removes the graph with the given index.
 
-Index 255 kann genutzt werden um alle Graphen zu entfernen.
-*/
+You can use index 255 to remove all graphs.*/
         pub async fn remove_gui_graph(
             &mut self,
             request: u8,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    49u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8595,30 +9121,36 @@ Index 255 kann genutzt werden um alle Graphen zu entfernen.
                 .await?;
             Ok(())
         }
-        /**
-Entfernt alle GUI-Elemente (Buttons, Slider, Graphen, Tabs).
-*/
+        ///Removes all GUI elements (buttons, slider, graphs, tabs).
         pub async fn remove_all_gui(
             &mut self,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    50u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             self.device
                 .set(50u8, &payload, Some(std::time::Duration::from_secs(20)))
                 .await?;
             Ok(())
         }
-        /**
-Setzt die Konfiguration der Kommunikations-LED. Standardmäßig ist die
-LED an wenn das LCD berührt wird.
+        /**Sets the touch LED configuration. By default the LED is on if the
+LCD is touched.
 
-Die LED kann auch permanent an/aus gestellt werden oder einen Herzschlag anzeigen.
+You can also turn the LED permanently on/off or show a heartbeat.
 
-Wenn das Bricklet sich im Bootloadermodus befindet ist die LED aus.
-*/
+If the Bricklet is in bootloader mode, the LED is off.*/
         pub async fn set_touch_led_config(
             &mut self,
             request: crate::bindings::lcd_128_x_64::TouchLedConfig,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
+            self.device
+                .check_firmware(
+                    51u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let mut payload = [0; 1usize];
             tinkerforge_base::byte_converter::ToBytes::write_to_slice(
                 &request,
@@ -8629,9 +9161,7 @@ Wenn das Bricklet sich im Bootloadermodus befindet ist die LED aus.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Konfiguration zurück, wie von :func:`Set Touch LED Config` gesetzt.
-*/
+        ///Returns the configuration as set by `set_touch_led_config`
         pub async fn get_touch_led_config(
             &mut self,
         ) -> Result<
@@ -8641,6 +9171,11 @@ Gibt die Konfiguration zurück, wie von :func:`Set Touch LED Config` gesetzt.
             >,
             tinkerforge_base::error::TinkerforgeError,
         > {
+            self.device
+                .check_firmware(
+                    52u8,
+                    tinkerforge_base::ip_connection::Version::new(2u8, 0u8, 2u8),
+                )?;
             let payload = [0; 0usize];
             let result = self.device.get(52u8, &payload).await?;
             Ok(
@@ -8650,21 +9185,17 @@ Gibt die Konfiguration zurück, wie von :func:`Set Touch LED Config` gesetzt.
                 >::from_le_byte_slice(&result.body()[0..1usize]),
             )
         }
-        /**
-Gibt die Anzahl der Fehler die während der Kommunikation zwischen Brick und
-Bricklet aufgetreten sind zurück.
+        /**Returns the error count for the communication between Brick and Bricklet.
 
-Die Fehler sind aufgeteilt in
+The errors are divided into
 
-* ACK-Checksummen Fehler,
-* Message-Checksummen Fehler,
-* Framing Fehler und
-* Overflow Fehler.
+* ACK checksum errors,
+* message checksum errors,
+* framing errors and
+* overflow errors.
 
-Die Fehlerzähler sind für Fehler die auf der Seite des Bricklets auftreten.
-Jedes Brick hat eine ähnliche Funktion welche die Fehler auf Brickseite
-ausgibt.
-*/
+The errors counts are for errors that occur on the Bricklet side. All
+Bricks have a similar function that returns the errors on the Brick side.*/
         pub async fn get_spitfp_error_count(
             &mut self,
         ) -> Result<
@@ -8679,18 +9210,15 @@ ausgibt.
                 ),
             )
         }
-        /**
-Setzt den Bootloader-Modus und gibt den Status zurück nachdem die
-Modusänderungsanfrage bearbeitet wurde.
+        /**Sets the bootloader mode and returns the status after the requested
+mode change was instigated.
 
-Mit dieser Funktion ist es möglich vom Bootloader- in den Firmware-Modus zu
-wechseln und umgekehrt. Ein Welchsel vom Bootloader- in der den Firmware-Modus
-ist nur möglich wenn Entry-Funktion, Device Identifier und CRC vorhanden und
-korrekt sind.
+You can change from bootloader mode to firmware mode and vice versa. A change
+from bootloader mode to firmware mode will only take place if the entry function,
+device identifier and CRC are present and correct.
 
-Diese Funktion wird vom Brick Viewer während des Flashens benutzt. In einem
-normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
-*/
+This function is used by Brick Viewer during flashing. It should not be
+necessary to call it in a normal user program.*/
         pub async fn set_bootloader_mode(
             &mut self,
             request: crate::bindings::lcd_128_x_64::BootloaderMode,
@@ -8714,9 +9242,7 @@ normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
                 >::from_le_byte_slice(&result.body()[0..1usize]),
             )
         }
-        /**
-Gibt den aktuellen Bootloader-Modus zurück, siehe :func:`Set Bootloader Mode`.
-*/
+        ///Returns the current bootloader mode, see `set_bootloader_mode`.
         pub async fn get_bootloader_mode(
             &mut self,
         ) -> Result<
@@ -8735,14 +9261,12 @@ Gibt den aktuellen Bootloader-Modus zurück, siehe :func:`Set Bootloader Mode`.
                 >::from_le_byte_slice(&result.body()[0..1usize]),
             )
         }
-        /**
-Setzt den Firmware-Pointer für :func:`Write Firmware`. Der Pointer
-muss um je 64 Byte erhöht werden. Die Daten werden alle 4 Datenblöcke
-in den Flash geschrieben (4 Datenblöcke entsprechen einer Page mit 256 Byte).
+        /**Sets the firmware pointer for `write_firmware`. The pointer has
+to be increased by chunks of size 64. The data is written to flash
+every 4 chunks (which equals to one page of size 256).
 
-Diese Funktion wird vom Brick Viewer während des Flashens benutzt. In einem
-normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
-*/
+This function is used by Brick Viewer during flashing. It should not be
+necessary to call it in a normal user program.*/
         pub async fn set_write_firmware_pointer(
             &mut self,
             request: u32,
@@ -8757,16 +9281,14 @@ normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
                 .await?;
             Ok(())
         }
-        /**
-Schreibt 64 Bytes Firmware an die Position die vorher von
-:func:`Set Write Firmware Pointer` gesetzt wurde. Die Firmware wird
-alle 4 Datenblöcke in den Flash geschrieben.
+        /**Writes 64 Bytes of firmware at the position as written by
+`set_write_firmware_pointer` before. The firmware is written
+to flash every 4 chunks.
 
-Eine Firmware kann nur im Bootloader-Mode geschrieben werden.
+You can only write firmware in bootloader mode.
 
-Diese Funktion wird vom Brick Viewer während des Flashens benutzt. In einem
-normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
-*/
+This function is used by Brick Viewer during flashing. It should not be
+necessary to call it in a normal user program.*/
         pub async fn write_firmware(
             &mut self,
             request: [u8; 64usize],
@@ -8779,15 +9301,13 @@ normalem Nutzerprogramm sollte diese Funktion nicht benötigt werden.
             let result = self.device.get(238u8, &payload).await?;
             Ok(u8::from_le_byte_slice(&result.body()[0..1usize]))
         }
-        /**
-Setzt die Konfiguration der Status-LED. Standardmäßig zeigt
-die LED die Kommunikationsdatenmenge an. Sie blinkt einmal auf pro 10 empfangenen
-Datenpaketen zwischen Brick und Bricklet.
+        /**Sets the status LED configuration. By default the LED shows
+communication traffic between Brick and Bricklet, it flickers once
+for every 10 received data packets.
 
-Die LED kann auch permanent an/aus gestellt werden oder einen Herzschlag anzeigen.
+You can also turn the LED permanently on/off or show a heartbeat.
 
-Wenn das Bricklet sich im Bootlodermodus befindet ist die LED aus.
-*/
+If the Bricklet is in bootloader mode, the LED is will show heartbeat by default.*/
         pub async fn set_status_led_config(
             &mut self,
             request: crate::bindings::lcd_128_x_64::StatusLedConfig,
@@ -8802,9 +9322,7 @@ Wenn das Bricklet sich im Bootlodermodus befindet ist die LED aus.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die Konfiguration zurück, wie von :func:`Set Status LED Config` gesetzt.
-*/
+        ///Returns the configuration as set by `set_status_led_config`
         pub async fn get_status_led_config(
             &mut self,
         ) -> Result<
@@ -8823,14 +9341,12 @@ Gibt die Konfiguration zurück, wie von :func:`Set Status LED Config` gesetzt.
                 >::from_le_byte_slice(&result.body()[0..1usize]),
             )
         }
-        /**
-Gibt die Temperatur, gemessen im Mikrocontroller, aus. Der
-Rückgabewert ist nicht die Umgebungstemperatur.
+        /**Returns the temperature as measured inside the microcontroller. The
+value returned is not the ambient temperature!
 
-Die Temperatur ist lediglich proportional zur echten Temperatur und hat eine
-hohe Ungenauigkeit. Daher beschränkt sich der praktische Nutzen auf die
-Indikation von Temperaturveränderungen.
-*/
+The temperature is only proportional to the real temperature and it has bad
+accuracy. Practically it is only useful as an indicator for
+temperature changes.*/
         pub async fn get_chip_temperature(
             &mut self,
         ) -> Result<i16, tinkerforge_base::error::TinkerforgeError> {
@@ -8838,13 +9354,12 @@ Indikation von Temperaturveränderungen.
             let result = self.device.get(242u8, &payload).await?;
             Ok(i16::from_le_byte_slice(&result.body()[0..2usize]))
         }
-        /**
-Ein Aufruf dieser Funktion setzt das Bricklet zurück. Nach einem
-Neustart sind alle Konfiguration verloren.
+        /**Calling this function will reset the Bricklet. All configurations
+will be lost.
 
-Nach dem Zurücksetzen ist es notwendig neue Objekte zu erzeugen,
-Funktionsaufrufe auf bestehenden führen zu undefiniertem Verhalten.
-*/
+After a reset you have to create new device objects,
+calling functions on the existing ones will result in
+undefined behavior!*/
         pub async fn reset(
             &mut self,
         ) -> Result<(), tinkerforge_base::error::TinkerforgeError> {
@@ -8854,14 +9369,11 @@ Funktionsaufrufe auf bestehenden führen zu undefiniertem Verhalten.
                 .await?;
             Ok(())
         }
-        /**
-Schreibt eine neue UID in den Flash. Die UID muss zuerst
-vom Base58 encodierten String in einen Integer decodiert
-werden.
+        /**Writes a new UID into flash. If you want to set a new UID
+you have to decode the Base58 encoded UID string into an
+integer first.
 
-Wir empfehlen die Nutzung des Brick Viewers zum ändern
-der UID.
-*/
+We recommend that you use Brick Viewer to change the UID.*/
         pub async fn write_uid(
             &mut self,
             request: u32,
@@ -8876,11 +9388,8 @@ der UID.
                 .await?;
             Ok(())
         }
-        /**
-Gibt die aktuelle UID als Integer zurück. Dieser Integer
-kann als Base58 encodiert werden um an den üblichen
-UID-String zu gelangen.
-*/
+        /**Returns the current UID as an integer. Encode as
+Base58 to get the usual string version.*/
         pub async fn read_uid(
             &mut self,
         ) -> Result<u32, tinkerforge_base::error::TinkerforgeError> {
@@ -8888,18 +9397,15 @@ UID-String zu gelangen.
             let result = self.device.get(249u8, &payload).await?;
             Ok(u32::from_le_byte_slice(&result.body()[0..4usize]))
         }
-        /**
-Gibt die UID, die UID zu der das Bricklet verbunden ist, die
-Position, die Hard- und Firmware Version sowie den Device Identifier
-zurück.
+        /**Returns the UID, the UID where the Bricklet is connected to,
+the position, the hardware and firmware version as well as the
+device identifier.
 
-Die Position ist 'a', 'b', 'c', 'd', 'e', 'f', 'g' oder 'h' (Bricklet Anschluss).
-Ein Bricklet hinter einem :ref:`Isolator Bricklet <isolator_bricklet>` ist immer an
-Position 'z'.
+The position can be 'a', 'b', 'c', 'd', 'e', 'f', 'g' or 'h' (Bricklet Port).
+A Bricklet connected to an Isolator Bricklet is always at
+position 'z'.
 
-Eine Liste der Device Identifier Werte ist :ref:`hier <device_identifier>` zu
-finden. |device_identifier_constant|
-*/
+The device identifier numbers can be found here.*/
         pub async fn get_identity(
             &mut self,
         ) -> Result<
@@ -8914,6 +9420,44 @@ finden. |device_identifier_constant|
                 ),
             )
         }
+        /**Reads pixels from the specified window.
+
+The pixels are read from the window line by line top to bottom
+and each line is read from left to right.
+
+If automatic draw is enabled (default) the pixels that are read are always the
+same that are shown on the display.
+
+If automatic draw is disabled the pixels are read from the internal buffer
+(see `draw_buffered_frame`).
+
+Automatic draw can be configured with the `set_display_configuration`
+function.*/
+        pub async fn read_pixels(
+            &mut self,
+            request: crate::bindings::lcd_128_x_64::ReadPixelsLowLevelRequest,
+        ) -> Result<Vec<bool>, tinkerforge_base::error::TinkerforgeError> {
+            let mut buffer = tinkerforge_base::low_level_traits::LowLevelReadBuffer::new();
+            loop {
+                let chunk = self.read_pixels_low_level(request).await?;
+                if let Some(data) = buffer.push(&chunk)? {
+                    return Ok(data);
+                }
+            }
+        }
+        ///Returns the graph data for a given index as set by `set_gui_graph_data`.
+        pub async fn get_gui_graph_data(
+            &mut self,
+            request: u8,
+        ) -> Result<Vec<u8>, tinkerforge_base::error::TinkerforgeError> {
+            let mut buffer = tinkerforge_base::low_level_traits::LowLevelReadBuffer::new();
+            loop {
+                let chunk = self.get_gui_graph_data_low_level(request).await?;
+                if let Some(data) = buffer.push(&chunk)? {
+                    return Ok(data);
+                }
+            }
+        }
     }
 }
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Ord, PartialOrd)]
@@ -8947,3 +9491,46 @@ impl std::convert::TryInto<DeviceIdentifier> for u16 {
         }
     }
 }
+/// Binding of any of the generated devices, see [`AnyDevice::new`].
+#[derive(Clone, Debug)]
+pub enum AnyDevice {
+    MasterBrick(master::MasterBrick),
+    Lcd128X64Bricklet(lcd_128_x_64::Lcd128X64Bricklet),
+}
+impl AnyDevice {
+    /// Creates the binding matching the device identifier of an enumeration, `None` for devices without binding.
+    /// The firmware check is enabled with the firmware version of the enumeration.
+    pub fn new(
+        response: &tinkerforge_base::ip_connection::EnumerateResponse,
+        connection: tinkerforge_base::ip_connection::async_io::AsyncIpConnection,
+    ) -> Option<AnyDevice> {
+        match response.device_identifier {
+            13u16 => {
+                let mut device = master::MasterBrick::new(response.uid, connection);
+                device.set_firmware_version(Some(response.firmware_version));
+                Some(AnyDevice::MasterBrick(device))
+            }
+            298u16 => {
+                let mut device = lcd_128_x_64::Lcd128X64Bricklet::new(
+                    response.uid,
+                    connection,
+                );
+                device.set_firmware_version(Some(response.firmware_version));
+                Some(AnyDevice::Lcd128X64Bricklet(device))
+            }
+            _ => None,
+        }
+    }
+    pub fn uid(&self) -> tinkerforge_base::base58::Uid {
+        match self {
+            AnyDevice::MasterBrick(device) => device.uid(),
+            AnyDevice::Lcd128X64Bricklet(device) => device.uid(),
+        }
+    }
+    pub fn device_identifier(&self) -> DeviceIdentifier {
+        match self {
+            AnyDevice::MasterBrick(_) => DeviceIdentifier::MasterBrick,
+            AnyDevice::Lcd128X64Bricklet(_) => DeviceIdentifier::Lcd128X64Bricklet,
+        }
+    }
+}